@@ -0,0 +1,130 @@
+use std::{
+    collections::BTreeMap,
+    convert::{TryFrom, TryInto},
+    str::FromStr,
+};
+
+use casper_types::{ContractPackageHash, Key, PublicKey, U128, U256, U512};
+
+// positional arguments followed by `--name value` flags
+pub struct Args {
+    positional: Vec<String>,
+    flags: BTreeMap<String, String>,
+}
+
+impl Args {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut positional = vec![];
+        let mut flags = BTreeMap::new();
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| format!("missing value for --{}", name))?;
+                    flags.insert(name.to_string(), value);
+                }
+                None => positional.push(arg),
+            }
+        }
+        Ok(Self { positional, flags })
+    }
+
+    pub fn positional(&self, index: usize, name: &str) -> Result<&str, String> {
+        self.positional
+            .get(index)
+            .map(String::as_str)
+            .ok_or_else(|| format!("missing <{}>", name))
+    }
+
+    pub fn has(&self, name: &str) -> bool {
+        self.flags.contains_key(name)
+    }
+
+    fn raw(&self, name: &str) -> Result<&str, String> {
+        self.flags
+            .get(name)
+            .map(String::as_str)
+            .ok_or_else(|| format!("missing --{}", name))
+    }
+
+    pub fn value<T: FromStr>(&self, name: &str) -> Result<T, String> {
+        self.raw(name)?
+            .parse()
+            .map_err(|_| format!("invalid --{}", name))
+    }
+
+    pub fn value_or<T: FromStr>(&self, name: &str, default: T) -> Result<T, String> {
+        if self.has(name) {
+            self.value(name)
+        } else {
+            Ok(default)
+        }
+    }
+
+    pub fn list<T: FromStr>(&self, name: &str) -> Result<Vec<T>, String> {
+        self.raw(name)?
+            .split(',')
+            .map(|item| {
+                item.trim()
+                    .parse()
+                    .map_err(|_| format!("invalid --{}", name))
+            })
+            .collect()
+    }
+
+    pub fn key(&self, name: &str) -> Result<Key, String> {
+        parse_key(self.raw(name)?).ok_or_else(|| format!("invalid --{}", name))
+    }
+
+    pub fn keys(&self, name: &str) -> Result<Vec<Key>, String> {
+        self.raw(name)?
+            .split(',')
+            .map(|item| parse_key(item.trim()).ok_or_else(|| format!("invalid --{}", name)))
+            .collect()
+    }
+
+    pub fn u128(&self, name: &str) -> Result<U128, String> {
+        U128::from_dec_str(self.raw(name)?).map_err(|_| format!("invalid --{}", name))
+    }
+
+    pub fn u256(&self, name: &str) -> Result<U256, String> {
+        U256::from_dec_str(self.raw(name)?).map_err(|_| format!("invalid --{}", name))
+    }
+
+    pub fn u512(&self, name: &str) -> Result<U512, String> {
+        U512::from_dec_str(self.raw(name)?).map_err(|_| format!("invalid --{}", name))
+    }
+
+    pub fn public_key(&self, name: &str) -> Result<PublicKey, String> {
+        PublicKey::from_hex(self.raw(name)?).map_err(|_| format!("invalid --{}", name))
+    }
+
+    pub fn package_hash(&self, name: &str) -> Result<ContractPackageHash, String> {
+        let value = self.raw(name)?;
+        let hash = value.strip_prefix("hash-").unwrap_or(value);
+        ContractPackageHash::from_formatted_str(hash)
+            .ok()
+            .or_else(|| {
+                let bytes = hex::decode(hash).ok()?;
+                ContractPackageHash::try_from(bytes.as_slice()).ok()
+            })
+            .ok_or_else(|| format!("invalid --{}", name))
+    }
+
+    // `(decimals0, decimals1)` when both are given
+    pub fn decimals(&self) -> Result<Option<(u8, u8)>, String> {
+        match (self.has("decimals0"), self.has("decimals1")) {
+            (false, false) => Ok(None),
+            _ => Ok(Some((self.value("decimals0")?, self.value("decimals1")?))),
+        }
+    }
+}
+
+// tokens are given as `hash-<hex>`, `account-hash-<hex>` or a bare contract package hash
+pub fn parse_key(value: &str) -> Option<Key> {
+    Key::from_formatted_str(value).ok().or_else(|| {
+        let bytes = hex::decode(value).ok()?;
+        Some(Key::Hash(bytes.as_slice().try_into().ok()?))
+    })
+}
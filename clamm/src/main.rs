@@ -0,0 +1,204 @@
+mod args;
+
+use std::process;
+
+use casper_types::{bytesrepr::Bytes, ExecutableDeployItem, TimeDiff, U128, U256};
+use sdk::{
+    events::{ContractEvent, PoolEvent, RouterEvent},
+    factory, keys, price, router, DeployConfig,
+};
+use serde_json::json;
+use types::{CollectParams, ExactInputParams, MintParams};
+
+use crate::args::Args;
+
+const USAGE: &str = "usage: clamm <command> [--flag value ...]
+
+  pool-key --token0 <key> --token1 <key> --fee <fee>
+  position-key --owner <key> --tick-lower <tick> --tick-upper <tick>
+  encode-path --tokens <key,key,..> --fees <fee,..>
+  decode-path <hex>
+  price (--price <price> | --tick <tick> | --sqrt-price-x96 <value>) [--decimals0 <n> --decimals1 <n>]
+  deploy <create-pool|mint|swap|collect> --chain-name <name> --public-key <hex> --payment <motes>
+         --package <package hash> [--ttl <duration>] <call flags>
+    create-pool --token0 <key> --token1 <key> --fee <fee>
+    mint --token0 <key> --token1 <key> --fee <fee> --tick-lower <tick> --tick-upper <tick>
+         --amount0 <amount> --amount1 <amount> [--amount0-min <amount>] [--amount1-min <amount>]
+         --recipient <key> --deadline <ms>
+    swap (--path <hex> | --tokens <key,key,..> --fees <fee,..>) --amount-in <amount>
+         [--amount-out-min <amount>] --recipient <key> --deadline <ms>
+         [--fee-recipient <key> --fee-bips <bips>]
+    collect --token-id <id> --recipient <key> [--amount0-max <amount>] [--amount1-max <amount>]
+  decode-event <pool|router> <hex>";
+
+fn main() {
+    let result = Args::parse(std::env::args().skip(1)).and_then(|args| run(&args));
+    match result {
+        Ok(output) => println!("{}", output),
+        Err(error) => {
+            eprintln!("error: {}\n\n{}", error, USAGE);
+            process::exit(1);
+        }
+    }
+}
+
+fn run(args: &Args) -> Result<String, String> {
+    match args.positional(0, "command")? {
+        "pool-key" => {
+            // the factory sorts the tokens before computing the key
+            let (token0, token1) = keys::sort_tokens(args.key("token0")?, args.key("token1")?);
+            Ok(hex::encode(keys::pool_key(
+                token0,
+                token1,
+                args.value("fee")?,
+            )))
+        }
+        "position-key" => Ok(keys::position_key(
+            args.key("owner")?,
+            args.value("tick-lower")?,
+            args.value("tick-upper")?,
+        )),
+        "encode-path" => Ok(hex::encode(path(args)?)),
+        "decode-path" => {
+            let bytes = decode_hex(args.positional(1, "hex")?)?;
+            let (tokens, fees) = keys::decode_path(&bytes).map_err(|e| e.to_string())?;
+            let tokens: Vec<String> = tokens
+                .iter()
+                .map(|token| token.to_formatted_string())
+                .collect();
+            to_json(&json!({ "tokens": tokens, "fees": fees }))
+        }
+        "price" => convert_price(args),
+        "deploy" => deploy(args),
+        "decode-event" => {
+            let bytes = decode_hex(args.positional(2, "hex")?)?;
+            match args.positional(1, "pool|router")? {
+                "pool" => to_json(&PoolEvent::from_bytes(&bytes).map_err(|e| e.to_string())?),
+                "router" => to_json(&RouterEvent::from_bytes(&bytes).map_err(|e| e.to_string())?),
+                other => Err(format!("unknown contract: {}", other)),
+            }
+        }
+        "help" => Ok(USAGE.to_string()),
+        other => Err(format!("unknown command: {}", other)),
+    }
+}
+
+fn convert_price(args: &Args) -> Result<String, String> {
+    let decimals = args.decimals()?;
+    let sqrt_price_x96 = if args.has("price") {
+        price::sqrt_price_x96_from_price(args.value("price")?, decimals)
+    } else if args.has("tick") {
+        price::sqrt_price_x96_at_tick(args.value("tick")?)
+    } else {
+        Ok(args.u256("sqrt-price-x96")?)
+    }
+    .map_err(|e| e.to_string())?;
+    let tick = price::tick_at_sqrt_price_x96(sqrt_price_x96).map_err(|e| e.to_string())?;
+    to_json(&json!({
+        "price": price::price_from_sqrt_price_x96(sqrt_price_x96, decimals),
+        "tick": tick,
+        "sqrt_price_x96": sqrt_price_x96.to_string(),
+    }))
+}
+
+fn deploy(args: &Args) -> Result<String, String> {
+    let package = args.package_hash("package")?;
+    let session: ExecutableDeployItem = match args.positional(1, "call")? {
+        "create-pool" => factory::create_pool(
+            package,
+            args.key("token0")?,
+            args.key("token1")?,
+            args.value("fee")?,
+        ),
+        "mint" => router::mint(
+            package,
+            &MintParams {
+                token0: args.key("token0")?,
+                token1: args.key("token1")?,
+                fee: args.value("fee")?,
+                tick_lower: args.value("tick-lower")?,
+                tick_upper: args.value("tick-upper")?,
+                amount0_desired: args.u256("amount0")?,
+                amount1_desired: args.u256("amount1")?,
+                amount0_min: u256_or_zero(args, "amount0-min")?,
+                amount1_min: u256_or_zero(args, "amount1-min")?,
+                recipient: args.key("recipient")?,
+                deadline: args.value("deadline")?,
+            },
+        )
+        .map_err(|e| e.to_string())?,
+        "swap" => {
+            let recipient = args.key("recipient")?;
+            let path = if args.has("path") {
+                Bytes::from(decode_hex(args.value::<String>("path")?.as_str())?)
+            } else {
+                path(args)?
+            };
+            router::exact_input(
+                package,
+                &ExactInputParams {
+                    path,
+                    recipient,
+                    deadline: args.value("deadline")?,
+                    amount_in: args.u256("amount-in")?,
+                    amount_out_minimum: u256_or_zero(args, "amount-out-min")?,
+                    fee_recipient: if args.has("fee-recipient") {
+                        args.key("fee-recipient")?
+                    } else {
+                        recipient
+                    },
+                    fee_bips: args.value_or("fee-bips", 0)?,
+                },
+            )
+            .map_err(|e| e.to_string())?
+        }
+        "collect" => router::collect(
+            package,
+            &CollectParams {
+                token_id: args.u256("token-id")?,
+                recipient: args.key("recipient")?,
+                amount0_max: u128_or_max(args, "amount0-max")?,
+                amount1_max: u128_or_max(args, "amount1-max")?,
+            },
+        )
+        .map_err(|e| e.to_string())?,
+        other => return Err(format!("unknown call: {}", other)),
+    };
+    let mut config = DeployConfig::new(
+        &args.value::<String>("chain-name")?,
+        args.public_key("public-key")?,
+        args.u512("payment")?,
+    );
+    if args.has("ttl") {
+        config.ttl = Some(args.value::<TimeDiff>("ttl")?);
+    }
+    config.build_json(session).map_err(|e| e.to_string())
+}
+
+fn path(args: &Args) -> Result<Bytes, String> {
+    keys::encode_path(&args.keys("tokens")?, &args.list("fees")?).map_err(|e| e.to_string())
+}
+
+fn u256_or_zero(args: &Args, name: &str) -> Result<U256, String> {
+    if args.has(name) {
+        args.u256(name)
+    } else {
+        Ok(U256::zero())
+    }
+}
+
+fn u128_or_max(args: &Args, name: &str) -> Result<U128, String> {
+    if args.has(name) {
+        args.u128(name)
+    } else {
+        Ok(U128::MAX)
+    }
+}
+
+fn decode_hex(value: &str) -> Result<Vec<u8>, String> {
+    hex::decode(value.trim_start_matches("0x")).map_err(|_| format!("invalid hex: {}", value))
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<String, String> {
+    serde_json::to_string_pretty(value).map_err(|e| e.to_string())
+}
@@ -1,4 +1,5 @@
 use casper_types::ApiError;
+#[cfg(target_arch = "wasm32")]
 use contract_utilities::helpers;
 
 #[repr(u16)]
@@ -67,6 +68,51 @@ pub enum Error {
     ErrInvalidTokenOrder,
     ErrInvalidLiquiditySessionParams,
     ErrInsufficientBalanceWCSPR,
+    ErrInvalidRebalanceParams,
+    ErrInvalidZapParams,
+    ErrInsufficientToken,
+    ErrInvalidWCSPR,
+    ErrInvalidSplitParams,
+    ErrInvalidIncentive,
+    ErrIncentiveNotStarted,
+    ErrIncentiveEnded,
+    ErrIncentiveNotEnded,
+    ErrIncentiveHasStakes,
+    ErrNoRefund,
+    ErrNotDepositOwner,
+    ErrTokenAlreadyStaked,
+    ErrTokenNotStaked,
+    ErrTokenStillStaked,
+    ErrInvalidStakePool,
+    ErrInvalidVaultParams,
+    ErrOnlyManager,
+    ErrPriceDeviation,
+    ErrInsufficientBalance,
+    ErrInsufficientAllowance,
+    ErrMaxTotalSupply,
+    ErrInvalidTrigger,
+    ErrTriggerNotFound,
+    ErrTriggerNotMet,
+    ErrNotTriggerOwner,
+    ErrKeeperFeeTooHigh,
+    ErrInvalidMigrateParams,
+    ErrInvalidLock,
+    ErrLockNotFound,
+    ErrStillLocked,
+    ErrNotBeneficiary,
+    ErrInvalidCompositeParams,
+    ErrInvalidPositionSplit,
+    ErrInvalidMergeParams,
+    ErrPartnerFeeTooHigh,
+    ErrUnsupportedSessionVersion,
+    ErrInvalidSessionCommand,
+    ErrInvalidLegacyTick,
+    ErrInvalidPath,
+    ErrPoolNotFound,
+    ErrInvalidUnwrapRecipient,
+    ErrTicksNotMigrated,
+    ErrInvalidRecipient,
+    ErrEmptyVault,
 }
 
 impl From<Error> for ApiError {
@@ -81,6 +127,9 @@ pub fn as_u16(err: Error) -> u16 {
 
 pub fn require(v: bool, e: Error) {
     if !v {
+        #[cfg(target_arch = "wasm32")]
         helpers::require(v, e.into());
+        #[cfg(not(target_arch = "wasm32"))]
+        panic!("{:?}", ApiError::from(e));
     }
 }
@@ -1,7 +1,13 @@
-use alloc::vec::Vec;
-use casper_contract::contract_api::runtime;
-use casper_types::{runtime_args, Key, RuntimeArgs, U128, U256};
-use types::{i256::I256, PoolKey};
+use alloc::{string::String, vec, vec::Vec};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, Key, RuntimeArgs, U128, U256,
+};
+use types::{
+    i256::I256, CollectParams, CollectResult, DecreaseLiquidityParams, DecreaseLiquidityResult,
+    ExactInputParams, MintParams, MintResult, PoolKey, Position, PositionInfo, Slot0,
+};
 
 pub fn initialize_pool_price(pool: Key, price: &U256) {
     runtime::call_versioned_contract::<()>(
@@ -27,6 +33,18 @@ pub fn get_pool_address(factory: Key, pool_key: &PoolKey) -> Key {
     )
 }
 
+// zero when the fee tier is not enabled
+pub fn get_fee_amount_tick_spacing(factory: Key, fee: u32) -> i32 {
+    runtime::call_versioned_contract(
+        factory.into_hash().unwrap().into(),
+        None,
+        "get_fee_amount_tick_spacing",
+        runtime_args! {
+            "fee" => fee,
+        },
+    )
+}
+
 pub fn get_pool_key(token0: Key, token1: Key, fee: u32) -> PoolKey {
     let (token0, token1) = if token0.into_hash().unwrap() < token1.into_hash().unwrap() {
         (token0, token1)
@@ -100,7 +118,7 @@ pub fn mint(
     tick_upper: i32,
     amount: U128,
     data: Vec<u8>,
-) {
+) -> (U256, U256) {
     runtime::call_versioned_contract::<(U256, U256)>(
         pool.into_hash().unwrap().into(),
         None,
@@ -112,5 +130,247 @@ pub fn mint(
             "amount" => amount,
             "data" => data,
         },
+    )
+}
+
+pub fn snapshot_cumulatives_inside(
+    pool: Key,
+    tick_lower: i32,
+    tick_upper: i32,
+) -> (i64, U256, u32) {
+    runtime::call_versioned_contract(
+        pool.into_hash().unwrap().into(),
+        None,
+        "snapshot_cumulatives_inside",
+        runtime_args! {
+            "tick_lower" => tick_lower,
+            "tick_upper" => tick_upper,
+        },
+    )
+}
+
+pub fn get_position(router: Key, token_id: U256) -> Position {
+    runtime::call_versioned_contract(
+        router.into_hash().unwrap().into(),
+        None,
+        "get_position",
+        runtime_args! {
+            "token_id" => token_id,
+        },
+    )
+}
+
+pub fn nft_transfer(router: Key, recipient: Key, token_id: U256) {
+    runtime::call_versioned_contract::<()>(
+        router.into_hash().unwrap().into(),
+        None,
+        "transfer",
+        runtime_args! {
+            "recipient" => recipient,
+            "token_ids" => vec![token_id],
+        },
+    );
+}
+
+pub fn nft_transfer_from(router: Key, sender: Key, recipient: Key, token_id: U256) {
+    runtime::call_versioned_contract::<()>(
+        router.into_hash().unwrap().into(),
+        None,
+        "transfer_from",
+        runtime_args! {
+            "sender" => sender,
+            "recipient" => recipient,
+            "token_ids" => vec![token_id],
+        },
     );
 }
+
+pub fn burn(pool: Key, tick_lower: i32, tick_upper: i32, amount: U128) -> (U256, U256) {
+    runtime::call_versioned_contract(
+        pool.into_hash().unwrap().into(),
+        None,
+        "burn",
+        runtime_args! {
+            "tick_lower" => tick_lower,
+            "tick_upper" => tick_upper,
+            "amount" => amount,
+        },
+    )
+}
+
+pub fn collect(
+    pool: Key,
+    recipient: Key,
+    tick_lower: i32,
+    tick_upper: i32,
+    amount0_requested: U128,
+    amount1_requested: U128,
+) -> (U128, U128) {
+    runtime::call_versioned_contract(
+        pool.into_hash().unwrap().into(),
+        None,
+        "collect",
+        runtime_args! {
+            "recipient" => recipient,
+            "tick_lower" => tick_lower,
+            "tick_upper" => tick_upper,
+            "amount0_requested" => amount0_requested,
+            "amount1_requested" => amount1_requested,
+        },
+    )
+}
+
+pub fn get_slot0(pool: Key) -> Slot0 {
+    runtime::call_versioned_contract(
+        pool.into_hash().unwrap().into(),
+        None,
+        "get_slot0",
+        runtime_args! {},
+    )
+}
+
+pub fn get_tick_spacing(pool: Key) -> i32 {
+    runtime::call_versioned_contract(
+        pool.into_hash().unwrap().into(),
+        None,
+        "get_tick_spacing",
+        runtime_args! {},
+    )
+}
+
+pub fn get_pool_position(pool: Key, position_key: String) -> PositionInfo {
+    runtime::call_versioned_contract(
+        pool.into_hash().unwrap().into(),
+        None,
+        "get_position",
+        runtime_args! {
+            "position_key" => position_key,
+        },
+    )
+}
+
+pub fn observe(pool: Key, seconds_agos: Vec<u32>) -> (Vec<i64>, Vec<U256>) {
+    runtime::call_versioned_contract(
+        pool.into_hash().unwrap().into(),
+        None,
+        "observe",
+        runtime_args! {
+            "seconds_agos" => seconds_agos,
+        },
+    )
+}
+
+pub fn decrease_liquidity(
+    router: Key,
+    params: &DecreaseLiquidityParams,
+) -> DecreaseLiquidityResult {
+    runtime::call_versioned_contract(
+        router.into_hash().unwrap().into(),
+        None,
+        "decrease_liquidity",
+        runtime_args! {
+            "data" => Bytes::from(params.to_bytes().unwrap_or_revert()),
+        },
+    )
+}
+
+pub fn collect_position(router: Key, params: &CollectParams) -> CollectResult {
+    runtime::call_versioned_contract(
+        router.into_hash().unwrap().into(),
+        None,
+        "collect",
+        runtime_args! {
+            "data" => Bytes::from(params.to_bytes().unwrap_or_revert()),
+        },
+    )
+}
+
+pub fn exact_input(router: Key, params: &ExactInputParams) -> U256 {
+    runtime::call_versioned_contract(
+        router.into_hash().unwrap().into(),
+        None,
+        "exact_input",
+        runtime_args! {
+            "data" => Bytes::from(params.to_bytes().unwrap_or_revert()),
+        },
+    )
+}
+
+pub fn create_and_initialize_pool_if_necessary(
+    router: Key,
+    token0: Key,
+    token1: Key,
+    fee: u32,
+    sqrt_price_x96: U256,
+) -> Key {
+    runtime::call_versioned_contract(
+        router.into_hash().unwrap().into(),
+        None,
+        "create_and_initialize_pool_if_necessary",
+        runtime_args! {
+            "token0" => token0,
+            "token1" => token1,
+            "fee" => fee,
+            "sqrt_price_x96" => sqrt_price_x96,
+        },
+    )
+}
+
+pub fn mint_position(router: Key, params: &MintParams) -> MintResult {
+    runtime::call_versioned_contract(
+        router.into_hash().unwrap().into(),
+        None,
+        "mint",
+        runtime_args! {
+            "data" => Bytes::from(params.to_bytes().unwrap_or_revert()),
+        },
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+// the null key when the v2 factory has no pair for the tokens
+pub fn get_pair_v2(v2_factory: Key, token_a: Key, token_b: Key) -> Key {
+    runtime::call_versioned_contract(
+        v2_factory.into_hash().unwrap().into(),
+        None,
+        "get_pair",
+        runtime_args! {
+            "token_a" => token_a,
+            "token_b" => token_b,
+        },
+    )
+}
+
+pub fn get_pair_tokens_v2(pair: Key) -> (Key, Key) {
+    let pair_hash = pair.into_hash().unwrap();
+    (
+        runtime::call_versioned_contract(pair_hash.into(), None, "token0", runtime_args! {}),
+        runtime::call_versioned_contract(pair_hash.into(), None, "token1", runtime_args! {}),
+    )
+}
+
+pub fn remove_liquidity_v2(
+    v2_router: Key,
+    token_a: Key,
+    token_b: Key,
+    liquidity: U256,
+    amount_a_min: U256,
+    amount_b_min: U256,
+    to: Key,
+    deadline: u64,
+) -> (U256, U256) {
+    runtime::call_versioned_contract(
+        v2_router.into_hash().unwrap().into(),
+        None,
+        "remove_liquidity",
+        runtime_args! {
+            "token_a" => token_a,
+            "token_b" => token_b,
+            "liquidity" => liquidity,
+            "amount_a_min" => amount_a_min,
+            "amount_b_min" => amount_b_min,
+            "to" => to,
+            "deadline" => deadline,
+        },
+    )
+}
@@ -0,0 +1,96 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+
+extern crate alloc;
+use casper_event_standard::Event;
+use casper_types::{Key, U256};
+use contract_utilities::helpers::current_block_timestamp;
+use types::Trigger;
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TriggerRegistered {
+    pub token_id: U256,
+    pub owner: Key,
+    pub pool: Key,
+    pub trigger_tick: i32,
+    pub trigger_below: bool,
+    pub twap_duration: u32,
+    pub swap_to: Key,
+    pub keeper_fee_bips: u32,
+    pub timestamp: u64,
+}
+
+impl TriggerRegistered {
+    pub fn new(token_id: U256, trigger: &Trigger) -> Self {
+        Self {
+            token_id,
+            owner: trigger.owner,
+            pool: trigger.pool,
+            trigger_tick: trigger.trigger_tick,
+            trigger_below: trigger.trigger_below,
+            twap_duration: trigger.twap_duration,
+            swap_to: trigger.swap_to,
+            keeper_fee_bips: trigger.keeper_fee_bips,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TriggerCancelled {
+    pub token_id: U256,
+    pub owner: Key,
+    pub timestamp: u64,
+}
+
+impl TriggerCancelled {
+    pub fn new(token_id: U256, owner: Key) -> Self {
+        Self {
+            token_id,
+            owner,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TriggerExecuted {
+    pub token_id: U256,
+    pub owner: Key,
+    pub keeper: Key,
+    pub twap_tick: i32,
+    pub amount0: U256,
+    pub amount1: U256,
+    pub keeper_fee0: U256,
+    pub keeper_fee1: U256,
+    pub timestamp: u64,
+}
+
+impl TriggerExecuted {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        token_id: U256,
+        owner: Key,
+        keeper: Key,
+        twap_tick: i32,
+        amount0: U256,
+        amount1: U256,
+        keeper_fee0: U256,
+        keeper_fee1: U256,
+    ) -> Self {
+        Self {
+            token_id,
+            owner,
+            keeper,
+            twap_tick,
+            amount0,
+            amount1,
+            keeper_fee0,
+            keeper_fee1,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
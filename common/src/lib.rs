@@ -6,13 +6,19 @@ pub mod console;
 pub mod erc20_helpers;
 pub mod error;
 pub mod intf;
+pub mod keeper_events;
 pub mod lock;
+pub mod locker_events;
 pub mod macros;
+pub mod migrator_events;
 pub mod owner;
 pub mod path;
 pub mod pausable;
 pub mod pool_events;
 pub mod router_events;
+pub mod session;
+pub mod staker_events;
 pub mod timestamp_testing;
 pub mod upgrade;
 pub mod utils;
+pub mod vault_events;
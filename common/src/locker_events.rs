@@ -0,0 +1,104 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+
+extern crate alloc;
+use casper_event_standard::Event;
+use casper_types::{Key, U128, U256};
+use contract_utilities::helpers::current_block_timestamp;
+use types::Lock;
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Locked {
+    pub token_id: U256,
+    pub owner: Key,
+    pub beneficiary: Key,
+    pub unlock_time: u64,
+    pub vesting: bool,
+    pub liquidity: U128,
+    pub timestamp: u64,
+}
+
+impl Locked {
+    pub fn new(token_id: U256, lock: &Lock) -> Self {
+        Self {
+            token_id,
+            owner: lock.owner,
+            beneficiary: lock.beneficiary,
+            unlock_time: lock.unlock_time,
+            vesting: lock.vesting,
+            liquidity: lock.initial_liquidity,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LockedFeesCollected {
+    pub token_id: U256,
+    pub recipient: Key,
+    pub amount0: U256,
+    pub amount1: U256,
+    pub timestamp: u64,
+}
+
+impl LockedFeesCollected {
+    pub fn new(token_id: U256, recipient: Key, amount0: U256, amount1: U256) -> Self {
+        Self {
+            token_id,
+            recipient,
+            amount0,
+            amount1,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VestedLiquidityWithdrawn {
+    pub token_id: U256,
+    pub recipient: Key,
+    pub liquidity: U128,
+    pub amount0: U256,
+    pub amount1: U256,
+    pub timestamp: u64,
+}
+
+impl VestedLiquidityWithdrawn {
+    pub fn new(
+        token_id: U256,
+        recipient: Key,
+        liquidity: U128,
+        amount0: U256,
+        amount1: U256,
+    ) -> Self {
+        Self {
+            token_id,
+            recipient,
+            liquidity,
+            amount0,
+            amount1,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Unlocked {
+    pub token_id: U256,
+    pub beneficiary: Key,
+    pub timestamp: u64,
+}
+
+impl Unlocked {
+    pub fn new(token_id: U256, beneficiary: Key) -> Self {
+        Self {
+            token_id,
+            beneficiary,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+
+extern crate alloc;
+use casper_event_standard::Event;
+use casper_types::{Key, U128, U256};
+use contract_utilities::helpers::current_block_timestamp;
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Migrated {
+    pub pair: Key,
+    pub owner: Key,
+    pub liquidity_v2: U256,
+    pub token_id: U256,
+    pub liquidity: U128,
+    pub amount0: U256,
+    pub amount1: U256,
+    pub refund0: U256,
+    pub refund1: U256,
+    pub timestamp: u64,
+}
+
+impl Migrated {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        pair: Key,
+        owner: Key,
+        liquidity_v2: U256,
+        token_id: U256,
+        liquidity: U128,
+        amount0: U256,
+        amount1: U256,
+        refund0: U256,
+        refund1: U256,
+    ) -> Self {
+        Self {
+            pair,
+            owner,
+            liquidity_v2,
+            token_id,
+            liquidity,
+            amount0,
+            amount1,
+            refund0,
+            refund1,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
@@ -1,9 +1,10 @@
-use casper_types::{
-    bytesrepr::Bytes,
-    Key,
-};
+use alloc::{vec, vec::Vec};
+use casper_types::{bytesrepr::Bytes, Key};
 
-use crate::utils::{set_size_32, set_size_4};
+use crate::{
+    error::Error,
+    utils::{set_size_32, set_size_4, sort_tokens},
+};
 
 pub const ADDR_SIZE: usize = 32;
 pub const FEE_SIZE: usize = 4;
@@ -12,6 +13,7 @@ pub const NEXT_OFFSET: usize = ADDR_SIZE + FEE_SIZE;
 // The offset of an encoded pool key
 pub const POP_OFFSET: usize = NEXT_OFFSET + ADDR_SIZE;
 pub const MULTIPLE_POOLS_MIN_LENGTH: usize = POP_OFFSET + NEXT_OFFSET;
+pub const MAX_HOPS: usize = 4;
 
 pub fn has_multiple_pools(path: &[u8]) -> bool {
     path.len() >= MULTIPLE_POOLS_MIN_LENGTH
@@ -34,6 +36,14 @@ pub fn decode_first_pool(path: &[u8]) -> (Key, Key, u32) {
     (token_a, token_b, fee)
 }
 
+pub fn first_token(path: &[u8]) -> &[u8] {
+    &path[0..ADDR_SIZE]
+}
+
+pub fn last_token(path: &[u8]) -> &[u8] {
+    &path[path.len() - ADDR_SIZE..path.len()]
+}
+
 pub fn get_first_pool(path: &[u8]) -> Bytes {
     path[0..POP_OFFSET].into()
 }
@@ -41,3 +51,130 @@ pub fn get_first_pool(path: &[u8]) -> Bytes {
 pub fn skip_token(path: &[u8]) -> Bytes {
     path[NEXT_OFFSET..path.len()].into()
 }
+
+pub fn encode_pool(token_a: Key, fee: u32, token_b: Key) -> Bytes {
+    let mut path: Vec<u8> = Vec::with_capacity(POP_OFFSET);
+    path.extend_from_slice(&token_a.into_hash().unwrap());
+    path.extend_from_slice(&fee.to_le_bytes());
+    path.extend_from_slice(&token_b.into_hash().unwrap());
+    path.into()
+}
+
+// a decoded path, tokens[i] and tokens[i + 1] are swapped in the pool with fees[i]. The encoding
+// has no room for a key tag, tokens are the `Key::Hash` of their contract package like everywhere
+// else in the router and other key variants are rejected on purpose
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path {
+    tokens: Vec<Key>,
+    fees: Vec<u32>,
+}
+
+impl Path {
+    pub fn new(token: Key) -> Self {
+        Self {
+            tokens: vec![token],
+            fees: vec![],
+        }
+    }
+
+    pub fn hop(mut self, fee: u32, token: Key) -> Self {
+        self.fees.push(fee);
+        self.tokens.push(token);
+        self
+    }
+
+    pub fn decode(path: &[u8]) -> Result<Self, Error> {
+        if path.len() < POP_OFFSET || (path.len() - ADDR_SIZE) % NEXT_OFFSET != 0 {
+            return Err(Error::ErrInvalidPath);
+        }
+        let mut decoded = Path::new(Key::Hash(set_size_32(&path[..ADDR_SIZE])));
+        for hop in path[ADDR_SIZE..].chunks(NEXT_OFFSET) {
+            let fee = u32::from_le_bytes(set_size_4(&hop[..FEE_SIZE]));
+            decoded = decoded.hop(fee, Key::Hash(set_size_32(&hop[FEE_SIZE..])));
+        }
+        Ok(decoded)
+    }
+
+    pub fn encode(&self) -> Result<Bytes, Error> {
+        let mut path: Vec<u8> = Vec::with_capacity(ADDR_SIZE + self.fees.len() * NEXT_OFFSET);
+        for (i, token) in self.tokens.iter().enumerate() {
+            path.extend_from_slice(&token.into_hash().ok_or(Error::ErrInvalidPath)?);
+            if let Some(fee) = self.fees.get(i) {
+                path.extend_from_slice(&fee.to_le_bytes());
+            }
+        }
+        Ok(path.into())
+    }
+
+    // exact output paths go from the output token back to the input token
+    pub fn reverse(&self) -> Self {
+        Self {
+            tokens: self.tokens.iter().rev().cloned().collect(),
+            fees: self.fees.iter().rev().cloned().collect(),
+        }
+    }
+
+    pub fn num_pools(&self) -> usize {
+        self.fees.len()
+    }
+
+    pub fn first_token(&self) -> Key {
+        self.tokens[0]
+    }
+
+    pub fn last_token(&self) -> Key {
+        self.tokens[self.tokens.len() - 1]
+    }
+
+    pub fn first_pool(&self) -> (Key, Key, u32) {
+        (self.tokens[0], self.tokens[1], self.fees[0])
+    }
+
+    // the path left once the first pool was swapped through
+    pub fn skip_token(&self) -> Self {
+        Self {
+            tokens: self.tokens[1..].to_vec(),
+            fees: self.fees[1..].to_vec(),
+        }
+    }
+
+    // (token_a, token_b, fee) of every pool, in swap order
+    pub fn pools(&self) -> Vec<(Key, Key, u32)> {
+        self.fees
+            .iter()
+            .enumerate()
+            .map(|(i, fee)| (self.tokens[i], self.tokens[i + 1], *fee))
+            .collect()
+    }
+
+    // everything but the fee tiers, which only the factory knows
+    pub fn validate_hops(&self) -> Result<(), Error> {
+        if self.fees.is_empty()
+            || self.fees.len() > MAX_HOPS
+            || self.tokens.iter().any(|token| token.into_hash().is_none())
+        {
+            return Err(Error::ErrInvalidPath);
+        }
+        let mut seen: Vec<(Key, Key, u32)> = Vec::with_capacity(self.fees.len());
+        for (token_a, token_b, fee) in self.pools() {
+            if token_a == token_b {
+                return Err(Error::ErrInvalidPath);
+            }
+            let (token0, token1) = sort_tokens(token_a, token_b);
+            if seen.contains(&(token0, token1, fee)) {
+                return Err(Error::ErrInvalidPath);
+            }
+            seen.push((token0, token1, fee));
+        }
+        Ok(())
+    }
+
+    // `is_fee_enabled` tells whether the factory has a tick spacing for the fee
+    pub fn validate<F: Fn(u32) -> bool>(&self, is_fee_enabled: F) -> Result<(), Error> {
+        self.validate_hops()?;
+        if self.fees.iter().any(|fee| !is_fee_enabled(*fee)) {
+            return Err(Error::ErrInvalidPath);
+        }
+        Ok(())
+    }
+}
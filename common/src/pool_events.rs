@@ -4,13 +4,14 @@
 
 extern crate alloc;
 use alloc::string::{String, ToString};
-use casper_event_standard::Event;
+use casper_event_standard::{Event, Schemas};
 use casper_types::U128;
 use casper_types::{Key, U256};
 use contract_utilities::helpers;
 use contract_utilities::helpers::current_block_timestamp;
 use types::i256::I256;
 #[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Initialize {
     pub sqrt_price_x96: U256,
     pub tick: i32,
@@ -27,15 +28,16 @@ impl Initialize {
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Mint {
-    sender: Key,
-    owner: Key,
-    tick_lower: i32,
-    tick_upper: i32,
-    amount: U128,
-    amount0: U256,
-    amount1: U256,
-    timestamp: u64,
+    pub sender: Key,
+    pub owner: Key,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub amount: U128,
+    pub amount0: U256,
+    pub amount1: U256,
+    pub timestamp: u64,
 }
 
 impl Mint {
@@ -62,6 +64,7 @@ impl Mint {
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Collect {
     pub owner: Key,
     pub recipient: Key,
@@ -94,14 +97,15 @@ impl Collect {
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Burn {
-    owner: Key,
-    tick_lower: i32,
-    tick_upper: i32,
-    amount: U128,
-    amount0: U256,
-    amount1: U256,
-    timestamp: u64,
+    pub owner: Key,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub amount: U128,
+    pub amount0: U256,
+    pub amount1: U256,
+    pub timestamp: u64,
 }
 
 impl Burn {
@@ -126,15 +130,16 @@ impl Burn {
 }
 
 #[derive(Event, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Swap {
-    sender: Key,
-    recipient: Key,
-    amount0: String,
-    amount1: String,
-    sqrt_price_x96: U256,
-    liquidity: U128,
-    tick: i32,
-    timestamp: u64,
+    pub sender: Key,
+    pub recipient: Key,
+    pub amount0: String,
+    pub amount1: String,
+    pub sqrt_price_x96: U256,
+    pub liquidity: U128,
+    pub tick: i32,
+    pub timestamp: u64,
 }
 
 impl Swap {
@@ -161,14 +166,15 @@ impl Swap {
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Flash {
-    sender: Key,
-    recipient: Key,
-    amount0: U256,
-    amount1: U256,
-    paid0: U256,
-    paid1: U256,
-    timestamp: u64,
+    pub sender: Key,
+    pub recipient: Key,
+    pub amount0: U256,
+    pub amount1: U256,
+    pub paid0: U256,
+    pub paid1: U256,
+    pub timestamp: u64,
 }
 
 impl Flash {
@@ -193,10 +199,11 @@ impl Flash {
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IncreaseObservationCardinalityNext {
     pub observation_cardinality_next_old: u32,
     pub observation_cardinality_next_new: u32,
-    timestamp: u64,
+    pub timestamp: u64,
 }
 
 impl IncreaseObservationCardinalityNext {
@@ -213,12 +220,13 @@ impl IncreaseObservationCardinalityNext {
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetFeeProtocol {
     pub fee_protocol0_old: u8,
     pub fee_protocol1_old: u8,
     pub fee_protocol0_new: u8,
     pub fee_protocol1_new: u8,
-    timestamp: u64,
+    pub timestamp: u64,
 }
 
 impl SetFeeProtocol {
@@ -239,6 +247,7 @@ impl SetFeeProtocol {
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CollectProtocol {
     pub sender: Key,
     pub recipient: Key,
@@ -260,6 +269,7 @@ impl CollectProtocol {
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SnapshotCumulativesInside {
     pub tick_cumulative_inside: i64,
     pub seconds_per_liquidity_insideX128: U256,
@@ -281,13 +291,14 @@ impl SnapshotCumulativesInside {
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PoolCreated {
-    token0: Key,
-    token1: Key,
-    fee: u32,
-    tick_spacing: i32,
-    pool: Key,
-    timestamp: u64,
+    pub token0: Key,
+    pub token1: Key,
+    pub fee: u32,
+    pub tick_spacing: i32,
+    pub pool: Key,
+    pub timestamp: u64,
 }
 
 impl PoolCreated {
@@ -302,3 +313,18 @@ impl PoolCreated {
         }
     }
 }
+
+pub fn event_schemas() -> Schemas {
+    Schemas::new()
+        .with::<Initialize>()
+        .with::<Mint>()
+        .with::<Collect>()
+        .with::<Burn>()
+        .with::<Swap>()
+        .with::<Flash>()
+        .with::<IncreaseObservationCardinalityNext>()
+        .with::<SetFeeProtocol>()
+        .with::<CollectProtocol>()
+        .with::<PoolCreated>()
+        .with::<SnapshotCumulativesInside>()
+}
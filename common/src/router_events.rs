@@ -3,11 +3,13 @@
 // #![allow(dead_code)]
 
 extern crate alloc;
-use casper_event_standard::Event;
+use alloc::vec::Vec;
+use casper_event_standard::{Event, Schemas};
 use casper_types::U128;
 use casper_types::{Key, U256};
 use contract_utilities::helpers::current_block_timestamp;
 #[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IncreaseLiquidity {
     pub token_id: U256,
     pub liquidity: U128,
@@ -29,6 +31,7 @@ impl IncreaseLiquidity {
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DecreaseLiquidity {
     pub token_id: U256,
     pub liquidity: U128,
@@ -50,6 +53,7 @@ impl DecreaseLiquidity {
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Collect {
     pub token_id: U256,
     pub recipient: Key,
@@ -69,3 +73,242 @@ impl Collect {
         }
     }
 }
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rebalance {
+    pub token_id: U256,
+    pub new_token_id: U256,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub liquidity: U128,
+    pub amount0: U256,
+    pub amount1: U256,
+    pub timestamp: u64,
+}
+
+impl Rebalance {
+    pub fn new(
+        token_id: U256,
+        new_token_id: U256,
+        tick_lower: i32,
+        tick_upper: i32,
+        liquidity: U128,
+        amount0: U256,
+        amount1: U256,
+    ) -> Self {
+        Self {
+            token_id,
+            new_token_id,
+            tick_lower,
+            tick_upper,
+            liquidity,
+            amount0,
+            amount1,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Split {
+    pub token_id: U256,
+    pub new_token_id: U256,
+    pub liquidity: U128,
+    pub tokens_owed0: U128,
+    pub tokens_owed1: U128,
+    pub timestamp: u64,
+}
+
+impl Split {
+    pub fn new(
+        token_id: U256,
+        new_token_id: U256,
+        liquidity: U128,
+        tokens_owed0: U128,
+        tokens_owed1: U128,
+    ) -> Self {
+        Self {
+            token_id,
+            new_token_id,
+            liquidity,
+            tokens_owed0,
+            tokens_owed1,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Merge {
+    pub token_id: U256,
+    pub merged_token_ids: Vec<U256>,
+    pub liquidity: U128,
+    pub timestamp: u64,
+}
+
+impl Merge {
+    pub fn new(token_id: U256, merged_token_ids: Vec<U256>, liquidity: U128) -> Self {
+        Self {
+            token_id,
+            merged_token_ids,
+            liquidity,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PositionClosed {
+    pub token_id: U256,
+    pub recipient: Key,
+    pub amount0: U256,
+    pub amount1: U256,
+    pub unwrap_cspr: bool,
+    pub timestamp: u64,
+}
+
+impl PositionClosed {
+    pub fn new(
+        token_id: U256,
+        recipient: Key,
+        amount0: U256,
+        amount1: U256,
+        unwrap_cspr: bool,
+    ) -> Self {
+        Self {
+            token_id,
+            recipient,
+            amount0,
+            amount1,
+            unwrap_cspr,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwapExactInput {
+    pub payer: Key,
+    pub recipient: Key,
+    pub path: Bytes,
+    pub amount_in: U256,
+    pub amount_out: U256,
+    pub referrer: Key,
+    pub timestamp: u64,
+}
+
+impl SwapExactInput {
+    pub fn new(
+        payer: Key,
+        recipient: Key,
+        path: Bytes,
+        amount_in: U256,
+        amount_out: U256,
+        referrer: Key,
+    ) -> Self {
+        Self {
+            payer,
+            recipient,
+            path,
+            amount_in,
+            amount_out,
+            referrer,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwapExactOutput {
+    pub payer: Key,
+    pub recipient: Key,
+    pub path: Bytes,
+    pub amount_in: U256,
+    pub amount_out: U256,
+    pub referrer: Key,
+    pub timestamp: u64,
+}
+
+impl SwapExactOutput {
+    pub fn new(
+        payer: Key,
+        recipient: Key,
+        path: Bytes,
+        amount_in: U256,
+        amount_out: U256,
+        referrer: Key,
+    ) -> Self {
+        Self {
+            payer,
+            recipient,
+            path,
+            amount_in,
+            amount_out,
+            referrer,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartnerFeeCharged {
+    pub fee_recipient: Key,
+    pub token: Key,
+    pub amount: U256,
+    pub timestamp: u64,
+}
+
+impl PartnerFeeCharged {
+    pub fn new(fee_recipient: Key, token: Key, amount: U256) -> Self {
+        Self {
+            fee_recipient,
+            token,
+            amount,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartnerFeesClaimed {
+    pub fee_recipient: Key,
+    pub token: Key,
+    pub recipient: Key,
+    pub amount: U256,
+    pub timestamp: u64,
+}
+
+impl PartnerFeesClaimed {
+    pub fn new(fee_recipient: Key, token: Key, recipient: Key, amount: U256) -> Self {
+        Self {
+            fee_recipient,
+            token,
+            recipient,
+            amount,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+pub fn event_schemas() -> Schemas {
+    Schemas::new()
+        .with::<IncreaseLiquidity>()
+        .with::<DecreaseLiquidity>()
+        .with::<Collect>()
+        .with::<Rebalance>()
+        .with::<Split>()
+        .with::<Merge>()
+        .with::<PositionClosed>()
+        .with::<SwapExactInput>()
+        .with::<SwapExactOutput>()
+        .with::<PartnerFeeCharged>()
+        .with::<PartnerFeesClaimed>()
+}
@@ -0,0 +1,107 @@
+use crate::{
+    erc20_helpers,
+    error::{require, Error},
+    utils::{new_purse, u256_to_u512, u512_to_u256, unwrap_wcspr_to_purse, wrap_cspr},
+};
+use alloc::vec::Vec;
+use casper_contract::{contract_api::account, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    Key, U256, U512,
+};
+use contract_utilities::helpers::{self, get_self_key};
+use types::session::{SessionCommand, SessionCommandError, SessionResult, SessionStepResult};
+
+pub const SESSION_RESULT: &str = "session_result";
+
+// decodes one entry of the sessions' `datas` argument, reverting on an unknown version or a malformed command
+pub fn decode_command(data: &[u8]) -> SessionCommand {
+    let command = SessionCommand::from_versioned_bytes(data);
+    require(
+        !matches!(command, Err(SessionCommandError::UnsupportedVersion(_))),
+        Error::ErrUnsupportedSessionVersion,
+    );
+    command
+        .ok()
+        .unwrap_or_revert_with(Error::ErrInvalidSessionCommand)
+}
+
+// stored as raw bytes like the `result` of the get session, overwritten by every session
+pub fn save_session_result(steps: Vec<SessionStepResult>) {
+    helpers::set_key(
+        SESSION_RESULT,
+        Bytes::from(SessionResult { steps }.to_bytes().unwrap_or_revert()),
+    );
+}
+
+// allowances and wrapped CSPR granted by a session, settled once every command ran so that
+// nothing is left approved to the router or stuck as wCSPR in the account
+#[derive(Default)]
+pub struct SessionFunds {
+    approvals: Vec<(Key, Key)>,
+    wrapped: Option<WrappedCspr>,
+}
+
+// the wrapped amount is consumed before any wCSPR the account already held, decreases are
+// measured at each checkpoint so wCSPR received by later commands is not counted against it
+struct WrappedCspr {
+    wcspr: Key,
+    balance: U256,
+    wrapped: U256,
+    spent: U256,
+}
+
+impl WrappedCspr {
+    fn checkpoint(&mut self) {
+        let balance = erc20_helpers::get_balance(self.wcspr, get_self_key());
+        self.spent += self.balance.saturating_sub(balance);
+        self.balance = balance;
+    }
+}
+
+impl SessionFunds {
+    pub fn approve(&mut self, token: Key, spender: Key, amount: U256) {
+        erc20_helpers::approve(token, spender, amount);
+        if !self.approvals.contains(&(token, spender)) {
+            self.approvals.push((token, spender));
+        }
+    }
+
+    pub fn wrap_cspr(&mut self, wcspr: Key, amount: U512) {
+        self.checkpoint();
+        let funds = self.wrapped.get_or_insert_with(|| WrappedCspr {
+            wcspr,
+            balance: erc20_helpers::get_balance(wcspr, get_self_key()),
+            wrapped: U256::zero(),
+            spent: U256::zero(),
+        });
+        wrap_cspr(wcspr, new_purse(amount), amount);
+        funds.balance += u512_to_u256(amount);
+        funds.wrapped += u512_to_u256(amount);
+    }
+
+    // called after every command, a command that both spends and receives wCSPR only counts
+    // its net change
+    pub fn checkpoint(&mut self) {
+        if let Some(funds) = self.wrapped.as_mut() {
+            funds.checkpoint();
+        }
+    }
+
+    pub fn settle(mut self) {
+        for (token, spender) in self.approvals.iter() {
+            if !erc20_helpers::get_allowance(*token, get_self_key(), *spender).is_zero() {
+                erc20_helpers::approve(*token, *spender, U256::zero());
+            }
+        }
+        self.checkpoint();
+        if let Some(funds) = self.wrapped {
+            // only the part of the wrapped amount no command consumed goes back, wCSPR received
+            // from the commands stays
+            let unused = funds.wrapped.saturating_sub(funds.spent).min(funds.balance);
+            if !unused.is_zero() {
+                unwrap_wcspr_to_purse(funds.wcspr, account::get_main_purse(), u256_to_u512(unused));
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+
+extern crate alloc;
+use casper_event_standard::Event;
+use casper_types::U128;
+use casper_types::{Key, U256};
+use contract_utilities::helpers::current_block_timestamp;
+use types::IncentiveKey;
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IncentiveCreated {
+    pub reward_token: Key,
+    pub pool: Key,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub refundee: Key,
+    pub reward: U256,
+    pub timestamp: u64,
+}
+
+impl IncentiveCreated {
+    pub fn new(key: &IncentiveKey, reward: U256) -> Self {
+        Self {
+            reward_token: key.reward_token,
+            pool: key.pool,
+            start_time: key.start_time,
+            end_time: key.end_time,
+            refundee: key.refundee,
+            reward,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IncentiveEnded {
+    pub reward_token: Key,
+    pub pool: Key,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub refund: U256,
+    pub timestamp: u64,
+}
+
+impl IncentiveEnded {
+    pub fn new(key: &IncentiveKey, refund: U256) -> Self {
+        Self {
+            reward_token: key.reward_token,
+            pool: key.pool,
+            start_time: key.start_time,
+            end_time: key.end_time,
+            refund,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositTransferred {
+    pub token_id: U256,
+    pub old_owner: Key,
+    pub new_owner: Key,
+    pub timestamp: u64,
+}
+
+impl DepositTransferred {
+    pub fn new(token_id: U256, old_owner: Key, new_owner: Key) -> Self {
+        Self {
+            token_id,
+            old_owner,
+            new_owner,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenStaked {
+    pub token_id: U256,
+    pub reward_token: Key,
+    pub pool: Key,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub liquidity: U128,
+    pub timestamp: u64,
+}
+
+impl TokenStaked {
+    pub fn new(token_id: U256, key: &IncentiveKey, liquidity: U128) -> Self {
+        Self {
+            token_id,
+            reward_token: key.reward_token,
+            pool: key.pool,
+            start_time: key.start_time,
+            end_time: key.end_time,
+            liquidity,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenUnstaked {
+    pub token_id: U256,
+    pub reward_token: Key,
+    pub pool: Key,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub reward: U256,
+    pub timestamp: u64,
+}
+
+impl TokenUnstaked {
+    pub fn new(token_id: U256, key: &IncentiveKey, reward: U256) -> Self {
+        Self {
+            token_id,
+            reward_token: key.reward_token,
+            pool: key.pool,
+            start_time: key.start_time,
+            end_time: key.end_time,
+            reward,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardClaimed {
+    pub reward_token: Key,
+    pub owner: Key,
+    pub to: Key,
+    pub reward: U256,
+    pub timestamp: u64,
+}
+
+impl RewardClaimed {
+    pub fn new(reward_token: Key, owner: Key, to: Key, reward: U256) -> Self {
+        Self {
+            reward_token,
+            owner,
+            to,
+            reward,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
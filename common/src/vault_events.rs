@@ -0,0 +1,193 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+
+extern crate alloc;
+use casper_event_standard::Event;
+use casper_types::U128;
+use casper_types::{Key, U256};
+use contract_utilities::helpers::current_block_timestamp;
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Deposit {
+    pub sender: Key,
+    pub to: Key,
+    pub shares: U256,
+    pub amount0: U256,
+    pub amount1: U256,
+    pub timestamp: u64,
+}
+
+impl Deposit {
+    pub fn new(sender: Key, to: Key, shares: U256, amount0: U256, amount1: U256) -> Self {
+        Self {
+            sender,
+            to,
+            shares,
+            amount0,
+            amount1,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Withdraw {
+    pub sender: Key,
+    pub to: Key,
+    pub shares: U256,
+    pub amount0: U256,
+    pub amount1: U256,
+    pub timestamp: u64,
+}
+
+impl Withdraw {
+    pub fn new(sender: Key, to: Key, shares: U256, amount0: U256, amount1: U256) -> Self {
+        Self {
+            sender,
+            to,
+            shares,
+            amount0,
+            amount1,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CollectFees {
+    pub fees0: U256,
+    pub fees1: U256,
+    pub timestamp: u64,
+}
+
+impl CollectFees {
+    pub fn new(fees0: U256, fees1: U256) -> Self {
+        Self {
+            fees0,
+            fees1,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rebalance {
+    pub tick: i32,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub liquidity: U128,
+    pub total_amount0: U256,
+    pub total_amount1: U256,
+    pub total_supply: U256,
+    pub timestamp: u64,
+}
+
+impl Rebalance {
+    pub fn new(
+        tick: i32,
+        tick_lower: i32,
+        tick_upper: i32,
+        liquidity: U128,
+        total_amount0: U256,
+        total_amount1: U256,
+        total_supply: U256,
+    ) -> Self {
+        Self {
+            tick,
+            tick_lower,
+            tick_upper,
+            liquidity,
+            total_amount0,
+            total_amount1,
+            total_supply,
+            timestamp: current_block_timestamp(),
+        }
+    }
+}
+
+// share token events, named after the CEP-18 ones
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mint {
+    pub recipient: Key,
+    pub amount: U256,
+}
+
+impl Mint {
+    pub fn new(recipient: Key, amount: U256) -> Self {
+        Self { recipient, amount }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Burn {
+    pub owner: Key,
+    pub amount: U256,
+}
+
+impl Burn {
+    pub fn new(owner: Key, amount: U256) -> Self {
+        Self { owner, amount }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetAllowance {
+    pub owner: Key,
+    pub spender: Key,
+    pub allowance: U256,
+}
+
+impl SetAllowance {
+    pub fn new(owner: Key, spender: Key, allowance: U256) -> Self {
+        Self {
+            owner,
+            spender,
+            allowance,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transfer {
+    pub sender: Key,
+    pub recipient: Key,
+    pub amount: U256,
+}
+
+impl Transfer {
+    pub fn new(sender: Key, recipient: Key, amount: U256) -> Self {
+        Self {
+            sender,
+            recipient,
+            amount,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransferFrom {
+    pub spender: Key,
+    pub owner: Key,
+    pub recipient: Key,
+    pub amount: U256,
+}
+
+impl TransferFrom {
+    pub fn new(spender: Key, owner: Key, recipient: Key, amount: U256) -> Self {
+        Self {
+            spender,
+            owner,
+            recipient,
+            amount,
+        }
+    }
+}
@@ -4,7 +4,9 @@ use common::error::require;
 use contract_utilities::helpers;
 use math::tickmath::{MAX_TICK, MIN_TICK};
 
-use crate::store::{read_factory, read_slot0_unlocked};
+use crate::store::{
+    read_factory, read_slot0_unlocked, read_tick_encoding, TICK_ENCODING_FIXED_WIDTH,
+};
 
 pub fn only_factory_owner() {
     let caller = helpers::get_immediate_caller_key();
@@ -34,6 +36,14 @@ pub fn check_pool() {
     );
 }
 
+// legacy ticks would be misread until `migrate_ticks` completed
+pub fn check_ticks_migrated() {
+    require(
+        read_tick_encoding() == TICK_ENCODING_FIXED_WIDTH,
+        common::error::Error::ErrTicksNotMigrated,
+    );
+}
+
 pub fn check_slot0_unlocked() {
     require(read_slot0_unlocked(), common::error::Error::ErrLOK);
 }
@@ -4,15 +4,15 @@ use casper_types::{
 };
 
 use common::{owner, timestamp_testing};
-use types::ProtocolFees;
+use types::{i256::I256, ProtocolFees};
 
 use crate::{
     factory::fac::{get_fee_amount_tick_spacing_ep, get_pool_map_ep},
     store::{
         get_factory_ep, get_fee_ep, get_fee_growth_global0_x128_ep, get_fee_growth_global1_x128_ep,
         get_liquidity_ep, get_max_liquidity_per_tick_ep, get_observation_ep, get_position_ep,
-        get_protocol_fees_ep, get_slot0_ep, get_slot0_unlocked_ep, get_tick_bitmap_ep, get_tick_ep,
-        get_tick_spacing_ep, get_token0_ep, get_token1_ep,
+        get_protocol_fees_ep, get_slot0_ep, get_slot0_unlocked_ep, get_tick_bitmap_ep,
+        get_tick_encoding_ep, get_tick_ep, get_tick_spacing_ep, get_token0_ep, get_token1_ep,
     },
 };
 
@@ -39,6 +39,7 @@ pub(crate) fn default(is_factory: bool) -> EntryPoints {
     entry_points.add_entry_point(get_protocol_fees_ep());
     entry_points.add_entry_point(get_liquidity_ep());
     entry_points.add_entry_point(get_tick_ep());
+    entry_points.add_entry_point(get_tick_encoding_ep());
     entry_points.add_entry_point(get_tick_bitmap_ep());
     entry_points.add_entry_point(get_position_ep());
     entry_points.add_entry_point(get_observation_ep());
@@ -186,7 +187,7 @@ pub(crate) fn default(is_factory: bool) -> EntryPoints {
             Parameter::new("sqrt_price_limit_x96", CLType::U256),
             Parameter::new("data", CLType::List(Box::new(CLType::U8))),
         ],
-        CLType::Unit,
+        <(I256, I256)>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
@@ -238,6 +239,17 @@ pub(crate) fn default(is_factory: bool) -> EntryPoints {
         EntryPointType::Contract,
     ));
 
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("migrate_ticks"),
+        vec![
+            Parameter::new("ticks", Vec::<i32>::cl_type()),
+            Parameter::new("complete", bool::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
     entry_points.add_entry_point(EntryPoint::new(
         String::from("get_position_key"),
         vec![
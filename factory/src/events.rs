@@ -1,19 +1,4 @@
-use casper_event_standard::Schemas;
-use common::pool_events::*;
-pub fn event_schemas() -> Schemas {
-    Schemas::new()
-        .with::<Initialize>()
-        .with::<Mint>()
-        .with::<Collect>()
-        .with::<Burn>()
-        .with::<Swap>()
-        .with::<Flash>()
-        .with::<IncreaseObservationCardinalityNext>()
-        .with::<SetFeeProtocol>()
-        .with::<CollectProtocol>()
-        .with::<PoolCreated>()
-        .with::<SnapshotCumulativesInside>()
-}
+pub use common::pool_events::event_schemas;
 
 pub fn init_events() {
     casper_event_standard::init(event_schemas());
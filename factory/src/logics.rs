@@ -77,6 +77,7 @@ pub fn balance1() -> U256 {
 #[no_mangle]
 pub extern "C" fn snapshot_cumulatives_inside() {
     check_pool();
+    checks::check_ticks_migrated();
     let tick_lower: i32 = runtime::get_named_arg("tick_lower");
     let tick_upper: i32 = runtime::get_named_arg("tick_upper");
     check_ticks(tick_lower, tick_upper);
@@ -286,6 +287,7 @@ pub extern "C" fn mint() {
                 .collect(),
         );
     checks::check_slot0_unlocked();
+    checks::check_ticks_migrated();
     lock::when_not_locked();
     lock::lock_contract();
     let amount = amount.as_u128();
@@ -536,6 +538,7 @@ pub extern "C" fn collect() {
 pub extern "C" fn burn() {
     check_pool();
     checks::check_slot0_unlocked();
+    checks::check_ticks_migrated();
     lock::when_not_locked();
     lock::lock_contract();
     let (tick_lower, tick_upper, amount): (i32, i32, U128) = get_named_args_3(
@@ -585,6 +588,7 @@ pub extern "C" fn burn() {
 pub extern "C" fn swap() {
     check_pool();
     checks::check_slot0_unlocked();
+    checks::check_ticks_migrated();
     lock::when_not_locked();
     lock::lock_contract();
     let (
@@ -1045,6 +1049,31 @@ pub extern "C" fn collect_protocol() {
     lock::unlock_contract();
 }
 
+#[no_mangle]
+pub extern "C" fn migrate_ticks() {
+    check_pool();
+    only_factory_owner();
+    require(
+        store::read_tick_encoding() == store::TICK_ENCODING_LEGACY,
+        Error::ErrInvalidLegacyTick,
+    );
+    let ticks: Vec<i32> = runtime::get_named_arg("ticks");
+    let complete: bool = runtime::get_named_arg("complete");
+    for tick in ticks.iter() {
+        store::migrate_tick(tick);
+    }
+    // the last batch switches the pool back on, once nothing is left in the legacy layout
+    if complete {
+        require(
+            tick_bitmap::initialized_ticks(read_tick_spacing())
+                .iter()
+                .all(store::is_tick_migrated),
+            Error::ErrTicksNotMigrated,
+        );
+        store::save_tick_encoding(store::TICK_ENCODING_FIXED_WIDTH);
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn get_position_key() {
     console::log("get_position_key 0");
@@ -8,7 +8,7 @@ use casper_types::{
 };
 use common::{error::Error, get_set_dict, get_set_no_set};
 use contract_utilities::helpers::{self, null_key};
-use types::{Observation, PositionInfo, ProtocolFees, Slot0, TickInfo};
+use types::{LegacyTickInfo, Observation, PositionInfo, ProtocolFees, Slot0, TickInfo};
 
 get_set_no_set!(
     factory,
@@ -157,6 +157,64 @@ get_set_dict!(
     "get_tick"
 );
 
+// Pools deployed before `I128` and `I256` switched to their fixed width encoding store their
+// ticks in the legacy layout and stay on `TICK_ENCODING_LEGACY` until the factory owner has run
+// `migrate_ticks` over every initialized tick. The ticks are the only stored values embedding
+// those types: the `Swap` event keeps its amounts as decimal strings, while the swap callback
+// deltas and the `swap` return value are only passed between contracts, so pools and the
+// router have to be upgraded together.
+pub const TICK_ENCODING_LEGACY: u8 = 0;
+pub const TICK_ENCODING_FIXED_WIDTH: u8 = 1;
+
+get_set_no_set!(
+    tick_encoding,
+    "tick_encoding",
+    u8,
+    TICK_ENCODING_LEGACY,
+    save_tick_encoding,
+    read_tick_encoding,
+    get_tick_encoding,
+    get_tick_encoding_ep,
+    "get_tick_encoding"
+);
+
+// a legacy record can be as long as a fixed width one, so the migration never tries to guess
+// the layout and remembers which ticks it already rewrote
+get_set_dict!(
+    "migrated_ticks",
+    "tick",
+    i32,
+    bool,
+    false,
+    save_migrated_tick,
+    read_migrated_tick,
+    get_migrated_tick,
+    get_migrated_tick_ep,
+    "get_migrated_tick"
+);
+
+pub fn migrate_tick(tick: &i32) {
+    if runtime::get_key("migrated_ticks").is_none() {
+        storage::new_dictionary("migrated_ticks")
+            .unwrap_or_revert_with(Error::FailedToCreateDictionary);
+    }
+    if read_migrated_tick(tick) {
+        return;
+    }
+    let key = helpers::encode_key(&helpers::encode_1(tick));
+    let seed_uref = helpers::get_uref("ticks");
+    let legacy = storage::dictionary_get::<LegacyTickInfo>(seed_uref, &key)
+        .ok()
+        .flatten()
+        .unwrap_or_revert_with(Error::ErrInvalidLegacyTick);
+    storage::dictionary_put(seed_uref, &key, legacy.0);
+    save_migrated_tick(tick, &true);
+}
+
+pub fn is_tick_migrated(tick: &i32) -> bool {
+    runtime::get_key("migrated_ticks").is_some() && read_migrated_tick(tick)
+}
+
 get_set_dict!(
     "tick_bitmap",
     "tick",
@@ -201,6 +259,7 @@ pub fn initialize() {
     storage::new_dictionary("positions").unwrap_or_revert_with(Error::FailedToCreateDictionary);
     storage::new_dictionary("tick_bitmap").unwrap_or_revert_with(Error::FailedToCreateDictionary);
     storage::new_dictionary("ticks").unwrap_or_revert_with(Error::FailedToCreateDictionary);
+    save_tick_encoding(TICK_ENCODING_FIXED_WIDTH);
     save_factory(helpers::null_key());
     save_token0(helpers::null_key());
     save_token1(helpers::null_key());
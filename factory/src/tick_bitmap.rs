@@ -1,8 +1,12 @@
+use alloc::vec::Vec;
 use core::ops::{BitAnd, BitXor, Shl, Shr};
 
 use casper_types::U256;
 use common::error::{require, Error};
-use math::bitmath;
+use math::{
+    bitmath,
+    tickmath::{MAX_TICK, MIN_TICK},
+};
 
 use crate::store::{read_tick_bitmap, save_tick_bitmap};
 
@@ -18,6 +22,25 @@ pub fn flip_tick(tick: i32, tick_spacing: i32) {
     save_tick_bitmap(&word_pos.into(), &previous_mask.bitxor(mask));
 }
 
+// every initialized tick, walking the words that span the whole tick range
+pub fn initialized_ticks(tick_spacing: i32) -> Vec<i32> {
+    let (first_word, _) = position(MIN_TICK / tick_spacing);
+    let (last_word, _) = position(MAX_TICK / tick_spacing);
+    let mut ticks = Vec::new();
+    for word_pos in first_word..=last_word {
+        let word = read_tick_bitmap(&word_pos.into());
+        if word.is_zero() {
+            continue;
+        }
+        for bit_pos in 0..256 {
+            if word.bit(bit_pos) {
+                ticks.push((i32::from(word_pos) * 256 + bit_pos as i32) * tick_spacing);
+            }
+        }
+    }
+    ticks
+}
+
 pub fn tick_next_initialized_tick_within_one_word(
     tick: i32,
     tick_spacing: i32,
@@ -0,0 +1,85 @@
+use casper_types::{U128, U256};
+use types::{Slot0, TickInfo};
+
+use crate::pool::{PoolState, TickState};
+
+// a difference between the indexed state and what the pool returns
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Mismatch {
+    SqrtPrice {
+        indexed: U256,
+        on_chain: U256,
+    },
+    Tick {
+        indexed: i32,
+        on_chain: i32,
+    },
+    Liquidity {
+        indexed: U128,
+        on_chain: U128,
+    },
+    TickLiquidity {
+        tick: i32,
+        indexed: TickState,
+        on_chain: TickState,
+    },
+}
+
+impl PoolState {
+    pub fn check_slot0(&self, slot0: &Slot0) -> Vec<Mismatch> {
+        let mut mismatches = vec![];
+        if self.sqrt_price_x96 != slot0.sqrt_price_x96 {
+            mismatches.push(Mismatch::SqrtPrice {
+                indexed: self.sqrt_price_x96,
+                on_chain: slot0.sqrt_price_x96,
+            });
+        }
+        if self.tick != slot0.tick {
+            mismatches.push(Mismatch::Tick {
+                indexed: self.tick,
+                on_chain: slot0.tick,
+            });
+        }
+        mismatches
+    }
+
+    pub fn check_liquidity(&self, liquidity: U128) -> Option<Mismatch> {
+        if self.liquidity == liquidity {
+            return None;
+        }
+        Some(Mismatch::Liquidity {
+            indexed: self.liquidity,
+            on_chain: liquidity,
+        })
+    }
+
+    // a tick missing from the index must be cleared on chain
+    pub fn check_tick(&self, tick: i32, info: &TickInfo) -> Option<Mismatch> {
+        let indexed = self.ticks.get(&tick).cloned().unwrap_or_default();
+        let on_chain = TickState {
+            liquidity_gross: info.liquidity_gross,
+            liquidity_net: info.liquidity_net.0,
+        };
+        if indexed == on_chain {
+            return None;
+        }
+        Some(Mismatch::TickLiquidity {
+            tick,
+            indexed,
+            on_chain,
+        })
+    }
+
+    // compares slot0 and every initialized tick, `get_tick` reads a tick from the pool
+    pub fn check<F: FnMut(i32) -> TickInfo>(
+        &self,
+        slot0: &Slot0,
+        mut get_tick: F,
+    ) -> Vec<Mismatch> {
+        let mut mismatches = self.check_slot0(slot0);
+        for tick in self.ticks.keys() {
+            mismatches.extend(self.check_tick(*tick, &get_tick(*tick)));
+        }
+        mismatches
+    }
+}
@@ -0,0 +1,274 @@
+// rebuilds the state of pools and router positions from their CES events, which have to be
+// applied in the order they were emitted
+pub mod check;
+pub mod pool;
+pub mod position;
+
+use std::collections::BTreeMap;
+
+use casper_types::{Key, U128, U256};
+
+pub use check::Mismatch;
+pub use pool::{PoolPosition, PoolState, TickState};
+pub use position::NftPosition;
+pub use sdk::events::{PoolEvent, RouterEvent};
+
+#[derive(Default)]
+pub struct Indexer {
+    pub pools: BTreeMap<Key, PoolState>,
+    pub positions: BTreeMap<U256, NftPosition>,
+}
+
+impl Indexer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn pool(&self, pool: Key) -> Option<&PoolState> {
+        self.pools.get(&pool)
+    }
+
+    pub fn position(&self, token_id: U256) -> Option<&NftPosition> {
+        self.positions.get(&token_id)
+    }
+
+    // `contract` is the package that emitted the event. `PoolCreated` comes from the factory, it
+    // sets the fee used by the volume estimates and goes before the events of the pool it names
+    pub fn apply_pool_event(&mut self, contract: Key, event: &PoolEvent) {
+        match event {
+            PoolEvent::PoolCreated(event) => {
+                let pool = self.pools.entry(event.pool).or_default();
+                pool.token0 = event.token0;
+                pool.token1 = event.token1;
+                pool.fee = event.fee;
+                pool.tick_spacing = event.tick_spacing;
+            }
+            event => self.pools.entry(contract).or_default().apply(event),
+        }
+    }
+
+    pub fn apply_router_event(&mut self, event: &RouterEvent) {
+        match event {
+            RouterEvent::IncreaseLiquidity(event) => {
+                let position = self.positions.entry(event.token_id).or_default();
+                position.liquidity += event.liquidity;
+                position.deposited0 += event.amount0;
+                position.deposited1 += event.amount1;
+            }
+            RouterEvent::DecreaseLiquidity(event) => {
+                let position = self.positions.entry(event.token_id).or_default();
+                position.liquidity -= event.liquidity;
+                position.withdrawn0 += event.amount0;
+                position.withdrawn1 += event.amount1;
+            }
+            RouterEvent::Collect(event) => {
+                let position = self.positions.entry(event.token_id).or_default();
+                position.collected0 += event.amount0;
+                position.collected1 += event.amount1;
+            }
+            RouterEvent::Rebalance(event) => {
+                if event.new_token_id != event.token_id {
+                    self.positions.entry(event.token_id).or_default().closed = true;
+                }
+            }
+            RouterEvent::Split(event) => {
+                self.positions.entry(event.token_id).or_default().liquidity -= event.liquidity;
+                self.positions
+                    .entry(event.new_token_id)
+                    .or_default()
+                    .liquidity = event.liquidity;
+            }
+            RouterEvent::Merge(event) => {
+                for merged_id in event.merged_token_ids.iter() {
+                    let merged = self.positions.entry(*merged_id).or_default();
+                    merged.liquidity = U128::zero();
+                    merged.closed = true;
+                }
+                self.positions.entry(event.token_id).or_default().liquidity = event.liquidity;
+            }
+            RouterEvent::PositionClosed(event) => {
+                self.positions.entry(event.token_id).or_default().closed = true;
+            }
+            RouterEvent::SwapExactInput(_)
+            | RouterEvent::SwapExactOutput(_)
+            | RouterEvent::PartnerFeeCharged(_)
+            | RouterEvent::PartnerFeesClaimed(_) => {}
+        }
+    }
+
+    pub fn replay_pool_events<'a, I: IntoIterator<Item = &'a PoolEvent>>(
+        &mut self,
+        contract: Key,
+        events: I,
+    ) {
+        for event in events {
+            self.apply_pool_event(contract, event);
+        }
+    }
+
+    pub fn replay_router_events<'a, I: IntoIterator<Item = &'a RouterEvent>>(&mut self, events: I) {
+        for event in events {
+            self.apply_router_event(event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{Key, U128, U256};
+    use common::{pool_events, router_events};
+
+    use super::{Indexer, PoolEvent, RouterEvent, TickState};
+
+    const POOL: Key = Key::Hash([1; 32]);
+    const OWNER: Key = Key::Hash([2; 32]);
+
+    fn mint(tick_lower: i32, tick_upper: i32, amount: u128) -> PoolEvent {
+        PoolEvent::Mint(pool_events::Mint {
+            sender: OWNER,
+            owner: OWNER,
+            tick_lower,
+            tick_upper,
+            amount: amount.into(),
+            amount0: 10.into(),
+            amount1: 20.into(),
+            timestamp: 0,
+        })
+    }
+
+    fn burn(tick_lower: i32, tick_upper: i32, amount: u128) -> PoolEvent {
+        PoolEvent::Burn(pool_events::Burn {
+            owner: OWNER,
+            tick_lower,
+            tick_upper,
+            amount: amount.into(),
+            amount0: 5.into(),
+            amount1: 10.into(),
+            timestamp: 0,
+        })
+    }
+
+    fn initialized() -> Indexer {
+        let mut indexer = Indexer::new();
+        indexer.apply_pool_event(
+            POOL,
+            &PoolEvent::Initialize(pool_events::Initialize {
+                sqrt_price_x96: U256::one() << 96,
+                tick: 0,
+                timestamp: 0,
+            }),
+        );
+        indexer
+    }
+
+    #[test]
+    fn test_mints_and_burns_update_ticks_and_liquidity() {
+        let mut indexer = initialized();
+        indexer.apply_pool_event(POOL, &mint(-60, 60, 100));
+        indexer.apply_pool_event(POOL, &mint(60, 120, 50));
+        indexer.apply_pool_event(POOL, &burn(-60, 60, 40));
+        let pool = indexer.pool(POOL).unwrap();
+        assert_eq!(pool.liquidity, U128::from(60));
+        assert_eq!(
+            pool.ticks.get(&60),
+            Some(&TickState {
+                liquidity_gross: 110.into(),
+                liquidity_net: -60 + 50,
+            })
+        );
+        assert_eq!(pool.ticks.get(&120).unwrap().liquidity_net, -50);
+        assert_eq!(pool.position(OWNER, -60, 60).unwrap().liquidity, 60.into());
+
+        indexer.apply_pool_event(POOL, &burn(60, 120, 50));
+        let pool = indexer.pool(POOL).unwrap();
+        assert!(!pool.ticks.contains_key(&120));
+        assert_eq!(pool.ticks.get(&60).unwrap().liquidity_gross, 60.into());
+    }
+
+    #[test]
+    fn test_swaps_move_the_price_and_count_volume() {
+        let mut indexer = initialized();
+        indexer.apply_pool_event(
+            POOL,
+            &PoolEvent::PoolCreated(pool_events::PoolCreated {
+                token0: Key::Hash([3; 32]),
+                token1: Key::Hash([4; 32]),
+                fee: 3000,
+                tick_spacing: 60,
+                pool: POOL,
+                timestamp: 0,
+            }),
+        );
+        indexer.apply_pool_event(
+            POOL,
+            &PoolEvent::Swap(pool_events::Swap {
+                sender: OWNER,
+                recipient: OWNER,
+                amount0: "1000".to_string(),
+                amount1: "-990".to_string(),
+                sqrt_price_x96: U256::one() << 95,
+                liquidity: 7.into(),
+                tick: -6932,
+                timestamp: 0,
+            }),
+        );
+        let pool = indexer.pool(POOL).unwrap();
+        assert_eq!(pool.tick, -6932);
+        assert_eq!(pool.liquidity, U128::from(7));
+        assert_eq!((pool.volume0, pool.volume1), (1000.into(), 990.into()));
+        assert_eq!((pool.fees0, pool.fees1), (3.into(), 0.into()));
+    }
+
+    #[test]
+    fn test_router_events_track_each_token() {
+        let mut indexer = Indexer::new();
+        indexer.apply_router_event(&RouterEvent::IncreaseLiquidity(
+            router_events::IncreaseLiquidity {
+                token_id: 1.into(),
+                liquidity: 100.into(),
+                amount0: 10.into(),
+                amount1: 20.into(),
+                timestamp: 0,
+            },
+        ));
+        indexer.apply_router_event(&RouterEvent::Split(router_events::Split {
+            token_id: 1.into(),
+            new_token_id: 2.into(),
+            liquidity: 30.into(),
+            tokens_owed0: 0.into(),
+            tokens_owed1: 0.into(),
+            timestamp: 0,
+        }));
+        assert_eq!(indexer.position(1.into()).unwrap().liquidity, 70.into());
+        assert_eq!(indexer.position(2.into()).unwrap().liquidity, 30.into());
+
+        indexer.apply_router_event(&RouterEvent::Merge(router_events::Merge {
+            token_id: 1.into(),
+            merged_token_ids: vec![2.into()],
+            liquidity: 100.into(),
+            timestamp: 0,
+        }));
+        assert_eq!(indexer.position(1.into()).unwrap().liquidity, 100.into());
+        assert!(indexer.position(2.into()).unwrap().closed);
+
+        indexer.apply_router_event(&RouterEvent::DecreaseLiquidity(
+            router_events::DecreaseLiquidity {
+                token_id: 1.into(),
+                liquidity: 100.into(),
+                amount0: 9.into(),
+                amount1: 19.into(),
+                timestamp: 0,
+            },
+        ));
+        indexer.apply_router_event(&RouterEvent::Collect(router_events::Collect {
+            token_id: 1.into(),
+            recipient: OWNER,
+            amount0: 12.into(),
+            amount1: 19.into(),
+            timestamp: 0,
+        }));
+        let position = indexer.position(1.into()).unwrap();
+        assert!(position.liquidity.is_zero());
+        assert_eq!(position.fees_collected(), (3.into(), 0.into()));
+    }
+}
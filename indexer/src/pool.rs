@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+
+use casper_types::{Key, U128, U256};
+use math::fullmath;
+use sdk::events::PoolEvent;
+use types::i256::I256;
+
+const FEE_DENOMINATOR: u32 = 1_000_000;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TickState {
+    pub liquidity_gross: U128,
+    pub liquidity_net: i128,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PoolPosition {
+    pub liquidity: U128,
+    pub deposited0: U256,
+    pub deposited1: U256,
+    pub withdrawn0: U256,
+    pub withdrawn1: U256,
+    pub collected0: U256,
+    pub collected1: U256,
+}
+
+impl PoolPosition {
+    // whatever was collected on top of the burned liquidity
+    pub fn fees_collected(&self) -> (U256, U256) {
+        (
+            self.collected0.saturating_sub(self.withdrawn0),
+            self.collected1.saturating_sub(self.withdrawn1),
+        )
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PoolState {
+    pub token0: Key,
+    pub token1: Key,
+    pub fee: u32,
+    pub tick_spacing: i32,
+    pub sqrt_price_x96: U256,
+    pub tick: i32,
+    pub liquidity: U128,
+    pub ticks: BTreeMap<i32, TickState>,
+    pub positions: BTreeMap<(Key, i32, i32), PoolPosition>,
+    pub volume0: U256,
+    pub volume1: U256,
+    // swap fees are taken on the input and rounded up on every step, so they can be off by one
+    // unit per crossed tick. Flash fees are exact
+    pub fees0: U256,
+    pub fees1: U256,
+    pub fee_protocol0: u8,
+    pub fee_protocol1: u8,
+    pub protocol_fees_collected0: U256,
+    pub protocol_fees_collected1: U256,
+    pub observation_cardinality_next: u32,
+    pub swaps: u64,
+}
+
+fn signed_amount(amount: &str) -> (bool, U256) {
+    let amount = I256::from(amount);
+    if amount < I256::from(0) {
+        (true, U256::from(-amount))
+    } else {
+        (false, U256::from(amount))
+    }
+}
+
+impl PoolState {
+    pub fn new(token0: Key, token1: Key, fee: u32, tick_spacing: i32) -> Self {
+        Self {
+            token0,
+            token1,
+            fee,
+            tick_spacing,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        !self.sqrt_price_x96.is_zero()
+    }
+
+    pub fn position(&self, owner: Key, tick_lower: i32, tick_upper: i32) -> Option<&PoolPosition> {
+        self.positions.get(&(owner, tick_lower, tick_upper))
+    }
+
+    fn update_tick(&mut self, tick: i32, liquidity_delta: i128, upper: bool) {
+        let state = self.ticks.entry(tick).or_default();
+        state.liquidity_gross = if liquidity_delta < 0 {
+            state.liquidity_gross - U128::from(liquidity_delta.unsigned_abs())
+        } else {
+            state.liquidity_gross + U128::from(liquidity_delta as u128)
+        };
+        state.liquidity_net += if upper {
+            -liquidity_delta
+        } else {
+            liquidity_delta
+        };
+        if state.liquidity_gross.is_zero() {
+            self.ticks.remove(&tick);
+        }
+    }
+
+    // the pool only counts the liquidity of positions whose range contains the current tick
+    fn modify_position(&mut self, tick_lower: i32, tick_upper: i32, liquidity_delta: i128) {
+        if liquidity_delta == 0 {
+            return;
+        }
+        self.update_tick(tick_lower, liquidity_delta, false);
+        self.update_tick(tick_upper, liquidity_delta, true);
+        if tick_lower <= self.tick && self.tick < tick_upper {
+            self.liquidity = if liquidity_delta < 0 {
+                self.liquidity - U128::from(liquidity_delta.unsigned_abs())
+            } else {
+                self.liquidity + U128::from(liquidity_delta as u128)
+            };
+        }
+    }
+
+    fn swap_fee(&self, amount_in: U256) -> U256 {
+        fullmath::mul_div_rounding_up(
+            &amount_in,
+            &U256::from(self.fee),
+            &U256::from(FEE_DENOMINATOR),
+        )
+    }
+
+    pub fn apply(&mut self, event: &PoolEvent) {
+        match event {
+            PoolEvent::Initialize(event) => {
+                self.sqrt_price_x96 = event.sqrt_price_x96;
+                self.tick = event.tick;
+            }
+            PoolEvent::Mint(event) => {
+                self.modify_position(
+                    event.tick_lower,
+                    event.tick_upper,
+                    event.amount.as_u128() as i128,
+                );
+                let position = self
+                    .positions
+                    .entry((event.owner, event.tick_lower, event.tick_upper))
+                    .or_default();
+                position.liquidity += event.amount;
+                position.deposited0 += event.amount0;
+                position.deposited1 += event.amount1;
+            }
+            PoolEvent::Burn(event) => {
+                self.modify_position(
+                    event.tick_lower,
+                    event.tick_upper,
+                    -(event.amount.as_u128() as i128),
+                );
+                let position = self
+                    .positions
+                    .entry((event.owner, event.tick_lower, event.tick_upper))
+                    .or_default();
+                position.liquidity -= event.amount;
+                position.withdrawn0 += event.amount0;
+                position.withdrawn1 += event.amount1;
+            }
+            PoolEvent::Collect(event) => {
+                let position = self
+                    .positions
+                    .entry((event.owner, event.tick_lower, event.tick_upper))
+                    .or_default();
+                position.collected0 += event.amount0;
+                position.collected1 += event.amount1;
+            }
+            PoolEvent::Swap(event) => {
+                let (out0, amount0) = signed_amount(&event.amount0);
+                let (out1, amount1) = signed_amount(&event.amount1);
+                self.volume0 += amount0;
+                self.volume1 += amount1;
+                if !out0 {
+                    let fee = self.swap_fee(amount0);
+                    self.fees0 += fee;
+                }
+                if !out1 {
+                    let fee = self.swap_fee(amount1);
+                    self.fees1 += fee;
+                }
+                self.sqrt_price_x96 = event.sqrt_price_x96;
+                self.tick = event.tick;
+                self.liquidity = event.liquidity;
+                self.swaps += 1;
+            }
+            PoolEvent::Flash(event) => {
+                self.fees0 += event.paid0;
+                self.fees1 += event.paid1;
+            }
+            PoolEvent::IncreaseObservationCardinalityNext(event) => {
+                self.observation_cardinality_next = event.observation_cardinality_next_new;
+            }
+            PoolEvent::SetFeeProtocol(event) => {
+                self.fee_protocol0 = event.fee_protocol0_new;
+                self.fee_protocol1 = event.fee_protocol1_new;
+            }
+            PoolEvent::CollectProtocol(event) => {
+                self.protocol_fees_collected0 += U256::from(event.amount0.as_u128());
+                self.protocol_fees_collected1 += U256::from(event.amount1.as_u128());
+            }
+            // emitted by the factory, or a read that does not change the pool
+            PoolEvent::PoolCreated(_) | PoolEvent::SnapshotCumulativesInside(_) => {}
+        }
+    }
+}
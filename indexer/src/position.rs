@@ -0,0 +1,24 @@
+use casper_types::{U128, U256};
+
+// a router NFT as seen through the router events, its pool and range are not part of them
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NftPosition {
+    pub liquidity: U128,
+    pub deposited0: U256,
+    pub deposited1: U256,
+    pub withdrawn0: U256,
+    pub withdrawn1: U256,
+    pub collected0: U256,
+    pub collected1: U256,
+    // set when the token is burned by an exit, a rebalance into a new token or a merge
+    pub closed: bool,
+}
+
+impl NftPosition {
+    pub fn fees_collected(&self) -> (U256, U256) {
+        (
+            self.collected0.saturating_sub(self.withdrawn0),
+            self.collected1.saturating_sub(self.withdrawn1),
+        )
+    }
+}
@@ -0,0 +1,64 @@
+use alloc::{string::String, vec, vec::Vec};
+use casper_types::{
+    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter, U256,
+};
+
+use common::{owner, timestamp_testing};
+use types::TriggerParams;
+
+use crate::store::{get_factory_ep, get_max_keeper_fee_bips_ep, get_router_ep, get_trigger_ep};
+
+fn add_entry_points(entry_points: &mut EntryPoints, list: &Vec<EntryPoint>) {
+    for e in list {
+        entry_points.add_entry_point(e.clone());
+    }
+}
+
+fn public_entry_point(name: &str, params: Vec<Parameter>, ret: CLType) -> EntryPoint {
+    EntryPoint::new(
+        String::from(name),
+        params,
+        ret,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub(crate) fn default() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    add_entry_points(&mut entry_points, &timestamp_testing::entry_points());
+    add_entry_points(&mut entry_points, &owner::entry_points());
+    entry_points.add_entry_point(get_factory_ep());
+    entry_points.add_entry_point(get_router_ep());
+    entry_points.add_entry_point(get_max_keeper_fee_bips_ep());
+    entry_points.add_entry_point(get_trigger_ep());
+
+    entry_points.add_entry_point(public_entry_point("init_keeper", vec![], CLType::Unit));
+    entry_points.add_entry_point(public_entry_point(
+        "register_trigger",
+        vec![Parameter::new("params", TriggerParams::cl_type())],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "cancel_trigger",
+        vec![Parameter::new("token_id", CLType::U256)],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "execute",
+        vec![Parameter::new("token_id", CLType::U256)],
+        <(U256, U256)>::cl_type(),
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "is_executable",
+        vec![Parameter::new("token_id", CLType::U256)],
+        CLType::Bool,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "set_max_keeper_fee_bips",
+        vec![Parameter::new("max_keeper_fee_bips", CLType::U32)],
+        CLType::Unit,
+    ));
+
+    entry_points
+}
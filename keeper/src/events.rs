@@ -0,0 +1,12 @@
+use casper_event_standard::Schemas;
+use common::keeper_events::*;
+pub fn event_schemas() -> Schemas {
+    Schemas::new()
+        .with::<TriggerRegistered>()
+        .with::<TriggerCancelled>()
+        .with::<TriggerExecuted>()
+}
+
+pub fn init_events() {
+    casper_event_standard::init(event_schemas());
+}
@@ -0,0 +1,242 @@
+use crate::store::{
+    read_factory, read_max_keeper_fee_bips, read_router, read_trigger, save_max_keeper_fee_bips,
+    save_trigger,
+};
+use alloc::vec;
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{CLValue, Key, U128, U256};
+use common::{
+    erc20_helpers,
+    error::{require, Error},
+    intf, keeper_events, owner,
+    path::encode_pool,
+};
+use contract_utilities::helpers::{
+    current_block_timestamp, get_immediate_caller_key, get_self_key, null_key,
+};
+use math::{
+    liquidity_amounts::get_amounts_for_liquidity,
+    oracle_library::{get_arithmetic_mean_tick, get_quote_at_tick},
+    tickmath::{get_sqrt_ratio_at_tick, MAX_TICK, MIN_TICK},
+};
+use types::{CollectParams, DecreaseLiquidityParams, ExactInputParams, Trigger, TriggerParams};
+
+const BIPS: u32 = 10000;
+const FEE_DENOMINATOR: u32 = 1000000;
+
+fn twap_tick(pool: Key, twap_duration: u32) -> i32 {
+    let (tick_cumulatives, _) = intf::observe(pool, vec![twap_duration, 0]);
+    get_arithmetic_mean_tick(&tick_cumulatives, twap_duration)
+}
+
+fn is_triggered(trigger: &Trigger, tick: i32) -> bool {
+    if trigger.trigger_below {
+        tick <= trigger.trigger_tick
+    } else {
+        tick >= trigger.trigger_tick
+    }
+}
+
+fn apply_bips(amount: U256, bips: u32) -> U256 {
+    amount * U256::from(bips) / U256::from(BIPS)
+}
+
+// swaps the whole amount_in through the position's pool, bounded by the twap quote
+fn swap_at_twap(trigger: &Trigger, tick: i32, zero_for_one: bool, amount_in: U256) -> U256 {
+    if amount_in.is_zero() {
+        return U256::zero();
+    }
+    let (token_in, token_out) = if zero_for_one {
+        (trigger.token0, trigger.token1)
+    } else {
+        (trigger.token1, trigger.token0)
+    };
+    let quote = get_quote_at_tick(tick, amount_in, zero_for_one);
+    let quote_after_fee =
+        quote * U256::from(FEE_DENOMINATOR - trigger.fee) / U256::from(FEE_DENOMINATOR);
+    let amount_out_minimum = apply_bips(quote_after_fee, BIPS - trigger.max_slippage_bips);
+
+    let router = read_router();
+    erc20_helpers::approve(token_in, router, amount_in);
+    intf::exact_input(
+        router,
+        &ExactInputParams {
+            path: encode_pool(token_in, trigger.fee, token_out),
+            recipient: get_self_key(),
+            deadline: current_block_timestamp(),
+            amount_in,
+            amount_out_minimum,
+            fee_recipient: null_key(),
+            fee_bips: 0,
+        },
+    )
+}
+
+// the position NFT must be approved to the keeper contract before registering
+#[no_mangle]
+pub extern "C" fn register_trigger() {
+    let params: TriggerParams = runtime::get_named_arg("params");
+    let owner = get_immediate_caller_key();
+    let router = read_router();
+    let position = intf::get_position(router, params.token_id);
+    require(
+        params.trigger_tick >= MIN_TICK && params.trigger_tick <= MAX_TICK,
+        Error::ErrInvalidTrigger,
+    );
+    require(params.twap_duration > 0, Error::ErrInvalidTrigger);
+    require(
+        params.swap_to == null_key()
+            || params.swap_to == position.token0
+            || params.swap_to == position.token1,
+        Error::ErrInvalidTrigger,
+    );
+    require(params.max_slippage_bips < BIPS, Error::ErrInvalidTrigger);
+    require(
+        params.keeper_fee_bips <= read_max_keeper_fee_bips(),
+        Error::ErrKeeperFeeTooHigh,
+    );
+
+    let pool = intf::get_pool_address(
+        read_factory(),
+        &intf::get_pool_key(position.token0, position.token1, position.fee),
+    );
+    intf::nft_transfer_from(router, owner, get_self_key(), params.token_id);
+
+    let trigger = Trigger {
+        owner,
+        pool,
+        token0: position.token0,
+        token1: position.token1,
+        fee: position.fee,
+        trigger_tick: params.trigger_tick,
+        trigger_below: params.trigger_below,
+        twap_duration: params.twap_duration,
+        swap_to: params.swap_to,
+        max_slippage_bips: params.max_slippage_bips,
+        keeper_fee_bips: params.keeper_fee_bips,
+    };
+    save_trigger(&params.token_id, &trigger);
+    casper_event_standard::emit(keeper_events::TriggerRegistered::new(
+        params.token_id,
+        &trigger,
+    ));
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_trigger() {
+    let token_id: U256 = runtime::get_named_arg("token_id");
+    let trigger = read_trigger(&token_id);
+    require(trigger.owner != null_key(), Error::ErrTriggerNotFound);
+    require(
+        trigger.owner == get_immediate_caller_key(),
+        Error::ErrNotTriggerOwner,
+    );
+
+    save_trigger(&token_id, &Trigger::default());
+    intf::nft_transfer(read_router(), trigger.owner, token_id);
+    casper_event_standard::emit(keeper_events::TriggerCancelled::new(
+        token_id,
+        trigger.owner,
+    ));
+}
+
+#[no_mangle]
+pub extern "C" fn execute() {
+    let token_id: U256 = runtime::get_named_arg("token_id");
+    let trigger = read_trigger(&token_id);
+    require(trigger.owner != null_key(), Error::ErrTriggerNotFound);
+
+    let tick = twap_tick(trigger.pool, trigger.twap_duration);
+    require(is_triggered(&trigger, tick), Error::ErrTriggerNotMet);
+    save_trigger(&token_id, &Trigger::default());
+
+    let router = read_router();
+    let position = intf::get_position(router, token_id);
+    if !position.liquidity.is_zero() {
+        // the position holds the most value when spot is at the twap, any spot move away from it
+        // withdraws less of one token, bounded like the swap by the owner's slippage
+        let (amount0_at_twap, amount1_at_twap) = get_amounts_for_liquidity(
+            &get_sqrt_ratio_at_tick(tick),
+            &get_sqrt_ratio_at_tick(position.tick_lower),
+            &get_sqrt_ratio_at_tick(position.tick_upper),
+            position.liquidity.as_u128(),
+        );
+        intf::decrease_liquidity(
+            router,
+            &DecreaseLiquidityParams {
+                token_id,
+                liquidity: position.liquidity,
+                amount0_min: apply_bips(amount0_at_twap, BIPS - trigger.max_slippage_bips),
+                amount1_min: apply_bips(amount1_at_twap, BIPS - trigger.max_slippage_bips),
+                deadline: current_block_timestamp(),
+            },
+        );
+    }
+    let collected = intf::collect_position(
+        router,
+        &CollectParams {
+            token_id,
+            recipient: get_self_key(),
+            amount0_max: U128::MAX,
+            amount1_max: U128::MAX,
+        },
+    );
+    // the emptied position is handed back to its owner
+    intf::nft_transfer(router, trigger.owner, token_id);
+
+    let (mut amount0, mut amount1) = (collected.amount0, collected.amount1);
+    if trigger.swap_to == trigger.token1 {
+        amount1 += swap_at_twap(&trigger, tick, true, amount0);
+        amount0 = U256::zero();
+    } else if trigger.swap_to == trigger.token0 {
+        amount0 += swap_at_twap(&trigger, tick, false, amount1);
+        amount1 = U256::zero();
+    }
+
+    let keeper = get_immediate_caller_key();
+    let keeper_fee0 = apply_bips(amount0, trigger.keeper_fee_bips);
+    let keeper_fee1 = apply_bips(amount1, trigger.keeper_fee_bips);
+    for (token, amount, keeper_fee) in [
+        (trigger.token0, amount0, keeper_fee0),
+        (trigger.token1, amount1, keeper_fee1),
+    ] {
+        if !keeper_fee.is_zero() {
+            erc20_helpers::transfer(token, keeper, keeper_fee);
+        }
+        if amount > keeper_fee {
+            erc20_helpers::transfer(token, trigger.owner, amount - keeper_fee);
+        }
+    }
+
+    casper_event_standard::emit(keeper_events::TriggerExecuted::new(
+        token_id,
+        trigger.owner,
+        keeper,
+        tick,
+        amount0 - keeper_fee0,
+        amount1 - keeper_fee1,
+        keeper_fee0,
+        keeper_fee1,
+    ));
+    runtime::ret(
+        CLValue::from_t((amount0 - keeper_fee0, amount1 - keeper_fee1)).unwrap_or_revert(),
+    );
+}
+
+// whether the trigger of the given position would fire at the current twap
+#[no_mangle]
+pub extern "C" fn is_executable() {
+    let token_id: U256 = runtime::get_named_arg("token_id");
+    let trigger = read_trigger(&token_id);
+    let executable = trigger.owner != null_key()
+        && is_triggered(&trigger, twap_tick(trigger.pool, trigger.twap_duration));
+    runtime::ret(CLValue::from_t(executable).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_max_keeper_fee_bips() {
+    owner::only_owner();
+    let max_keeper_fee_bips: u32 = runtime::get_named_arg("max_keeper_fee_bips");
+    require(max_keeper_fee_bips <= BIPS, Error::ErrKeeperFeeTooHigh);
+    save_max_keeper_fee_bips(max_keeper_fee_bips);
+}
@@ -0,0 +1,67 @@
+use alloc::{string::String, vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    CLTyped, CLValue, EntryPoint, EntryPointAccess, EntryPointType, Key, Parameter, U256,
+};
+use common::{error::Error, get_set_dict, get_set_no_set};
+use contract_utilities::helpers::{self, null_key};
+use types::Trigger;
+
+pub fn initialize(factory: Key, router: Key, max_keeper_fee_bips: u32) {
+    storage::new_dictionary("triggers").unwrap_or_revert_with(Error::FailedToCreateDictionary);
+    save_factory(factory);
+    save_router(router);
+    save_max_keeper_fee_bips(max_keeper_fee_bips);
+}
+
+get_set_no_set!(
+    factory,
+    "factory",
+    Key,
+    null_key(),
+    save_factory,
+    read_factory,
+    get_factory,
+    get_factory_ep,
+    "get_factory"
+);
+
+get_set_no_set!(
+    router,
+    "router",
+    Key,
+    null_key(),
+    save_router,
+    read_router,
+    get_router,
+    get_router_ep,
+    "get_router"
+);
+
+get_set_no_set!(
+    max_keeper_fee_bips,
+    "max_keeper_fee_bips",
+    u32,
+    0,
+    save_max_keeper_fee_bips,
+    read_max_keeper_fee_bips,
+    get_max_keeper_fee_bips,
+    get_max_keeper_fee_bips_ep,
+    "get_max_keeper_fee_bips"
+);
+
+get_set_dict!(
+    "triggers",
+    "token_id",
+    U256,
+    Trigger,
+    Trigger::default(),
+    save_trigger,
+    read_trigger,
+    get_trigger,
+    get_trigger_ep,
+    "get_trigger"
+);
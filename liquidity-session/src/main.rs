@@ -2,25 +2,25 @@
 #![no_main]
 
 extern crate alloc;
-use alloc::string::String;
-
 use alloc::vec::Vec;
 use casper_contract::contract_api::account;
 use casper_contract::{self, contract_api::runtime};
-use casper_types::bytesrepr::{Bytes, ToBytes};
-use casper_types::{runtime_args, Key, RuntimeArgs, U128, U256, U512};
+use casper_types::bytesrepr::{Bytes, FromBytes, ToBytes};
+use casper_types::{runtime_args, CLTyped, Key, RuntimeArgs, U128, U256, U512};
 use common::erc20_helpers;
-use common::error::require;
-use common::utils::{new_purse, u256_to_u512, unwrap_wcspr, unwrap_wcspr_to_purse, wrap_cspr};
-use contract_utilities::helpers::{self, get_self_key};
+use common::error::{require, Error};
+use common::session::{decode_command, save_session_result, SessionFunds};
+use common::utils::{u256_to_u512, unwrap_wcspr, unwrap_wcspr_to_purse};
+use contract_utilities::helpers::get_self_key;
+use types::session::{SessionCommand, SessionStepResult};
 use types::{
-    CollectParams, CollectResult, DecreaseLiquidityParams, DecreaseLiquidityResult,
-    IncreaseLiquidityParams, IncreaseLiquidityResult, MintParams, MintResult,
+    CollectResult, CompositeIncreaseLiquidityResult, CompositeMintResult, CompositePosition,
+    DecreaseLiquidityResult, IncreaseLiquidityResult, MintResult,
 };
 
-fn try_wrap_cspr() {
+fn try_wrap_cspr(funds: &mut SessionFunds) {
     let amount: U512 = runtime::get_named_arg("amount");
-    wrap_cspr(get_wcspr(), new_purse(amount), amount);
+    funds.wrap_cspr(get_wcspr(), amount);
 }
 
 fn get_wcspr() -> Key {
@@ -31,161 +31,309 @@ fn try_unwrap_cspr(wcspr: Key, amount: U512) {
     unwrap_wcspr_to_purse(wcspr, account::get_main_purse(), amount);
 }
 
+fn call_router<T: CLTyped + FromBytes>(router: Key, entry_point: &str, args: RuntimeArgs) -> T {
+    runtime::call_versioned_contract::<T>(
+        router.into_hash().unwrap().into(),
+        None,
+        entry_point,
+        args,
+    )
+}
+
+fn total_liquidity(liquidities: &[U128]) -> U128 {
+    liquidities
+        .iter()
+        .fold(U128::zero(), |total, liquidity| total + *liquidity)
+}
+
 #[no_mangle]
 pub extern "C" fn call() {
-    let entry_points: Vec<String> = runtime::get_named_arg("entry_points");
     let datas: Vec<Bytes> = runtime::get_named_arg("datas");
-    require(
-        entry_points.len() == datas.len(),
-        common::error::Error::ErrInvalidLiquiditySessionParams,
-    );
     let router: Key = runtime::get_named_arg("router");
+    let mut steps: Vec<SessionStepResult> = Vec::with_capacity(datas.len());
+    let mut funds = SessionFunds::default();
 
-    for i in 0..entry_points.len() {
-        let entry_point = &entry_points[i];
-        let data = &datas[i];
-        if entry_point == "create_and_initialize_pool_if_necessary" {
-            let (token0, token1, fee, sqrt_price_x96): (Key, Key, u32, U256) =
-                helpers::decode_4(data);
-            runtime::call_versioned_contract::<Key>(
-                router.into_hash().unwrap().into(),
-                None,
-                "create_and_initialize_pool_if_necessary",
-                runtime_args! {
-                    "token0" => token0,
-                    "token1" => token1,
-                    "fee" => fee,
-                    "sqrt_price_x96" => sqrt_price_x96,
-                },
-            );
-        } else if entry_point == "increase_liquidity" {
-            let (
-                token0,
-                token1,
-                is_cspr,
-                token_id,
-                amount0_desired,
-                amount1_desired,
-                amount0_min,
-                amount1_min,
-                deadline,
-            ): (Key, Key, bool, U256, U256, U256, U256, U256, u64) = helpers::decode_9(data);
-            erc20_helpers::approve(token0, router, amount0_desired);
-            erc20_helpers::approve(token1, router, amount1_desired);
-            if is_cspr {
-                try_wrap_cspr();
-            }
-            runtime::call_versioned_contract::<IncreaseLiquidityResult>(
-                router.into_hash().unwrap().into(),
-                None,
-                "increase_liquidity",
-                runtime_args! {
-                    "data" => Bytes::from(IncreaseLiquidityParams { token_id, amount0_desired, amount1_desired, amount0_min, amount1_min, deadline, token0, token1 }.to_bytes().unwrap()),
-                },
-            );
-        } else if entry_point == "mint" {
-            let (
+    for data in datas.iter() {
+        let command = decode_command(data);
+        let mut step = SessionStepResult::new(command.tag());
+        match command {
+            SessionCommand::CreateAndInitializePoolIfNecessary {
                 token0,
                 token1,
-                is_cspr,
                 fee,
-                tick_lower,
-                tick_upper,
-                amount0_desired,
-                amount1_desired,
-                amount0_min,
-                amount1_min,
-                recipient,
-                deadline,
-            ): (
-                Key,
-                Key,
-                bool,
-                u32,
-                i32,
-                i32,
-                U256,
-                U256,
-                U256,
-                U256,
-                Key,
-                u64,
-            ) = helpers::decode_12(data);
-            erc20_helpers::approve(token0, router, amount0_desired);
-            erc20_helpers::approve(token1, router, amount1_desired);
-            if is_cspr {
-                try_wrap_cspr();
-            }
-            runtime::call_versioned_contract::<MintResult>(
-                router.into_hash().unwrap().into(),
-                None,
-                "mint",
-                runtime_args! {
-                    "data" => Bytes::from(MintParams { token0, token1, fee, tick_lower, tick_upper, amount0_desired, amount1_desired, amount0_min, amount1_min, recipient, deadline }.to_bytes().unwrap()),
-                },
-            );
-        } else if entry_point == "decrease_liquidity" {
-            let (token_id, liquidity, amount0_min, amount1_min, deadline): (
-                U256,
-                U128,
-                U256,
-                U256,
-                u64,
-            ) = helpers::decode_5(data);
-            runtime::call_versioned_contract::<DecreaseLiquidityResult>(
-                router.into_hash().unwrap().into(),
-                None,
-                "decrease_liquidity",
-                runtime_args! {
-                    "data" => Bytes::from(DecreaseLiquidityParams { token_id, liquidity, amount0_min, amount1_min, deadline }.to_bytes().unwrap()),
-                },
-            );
-        } else if entry_point == "collect" {
-            let (is_cspr, token_id, recipient, amount0_max, amount1_max): (
-                bool,
-                U256,
-                Key,
-                U128,
-                U128,
-            ) = helpers::decode_5(data);
-            let collect_result = runtime::call_versioned_contract::<CollectResult>(
-                router.into_hash().unwrap().into(),
-                None,
-                "collect",
-                runtime_args! {
-                    "data" => Bytes::from(CollectParams {token_id, recipient, amount0_max, amount1_max }.to_bytes().unwrap()),
-                },
-            );
+                sqrt_price_x96,
+            } => {
+                call_router::<Key>(
+                    router,
+                    "create_and_initialize_pool_if_necessary",
+                    runtime_args! {
+                        "token0" => token0,
+                        "token1" => token1,
+                        "fee" => fee,
+                        "sqrt_price_x96" => sqrt_price_x96,
+                    },
+                );
+            }
+            SessionCommand::IncreaseLiquidity { params, wrap_cspr } => {
+                funds.approve(params.token0, router, params.amount0_desired);
+                funds.approve(params.token1, router, params.amount1_desired);
+                if wrap_cspr {
+                    try_wrap_cspr(&mut funds);
+                }
+                let result: IncreaseLiquidityResult = call_router(
+                    router,
+                    "increase_liquidity",
+                    runtime_args! {
+                        "data" => Bytes::from(params.to_bytes().unwrap()),
+                    },
+                );
+                step.token_id = params.token_id;
+                step.liquidity = result.liquidity;
+                step.amount0 = result.amount0;
+                step.amount1 = result.amount1;
+            }
+            SessionCommand::Mint { params, wrap_cspr } => {
+                funds.approve(params.token0, router, params.amount0_desired);
+                funds.approve(params.token1, router, params.amount1_desired);
+                if wrap_cspr {
+                    try_wrap_cspr(&mut funds);
+                }
+                let result: MintResult = call_router(
+                    router,
+                    "mint",
+                    runtime_args! {
+                        "data" => Bytes::from(params.to_bytes().unwrap()),
+                    },
+                );
+                step.token_id = result.token_id;
+                step.liquidity = result.liquidity;
+                step.amount0 = result.amount0;
+                step.amount1 = result.amount1;
+            }
+            SessionCommand::DecreaseLiquidity { params } => {
+                let result: DecreaseLiquidityResult = call_router(
+                    router,
+                    "decrease_liquidity",
+                    runtime_args! {
+                        "data" => Bytes::from(params.to_bytes().unwrap()),
+                    },
+                );
+                step.token_id = params.token_id;
+                step.liquidity = params.liquidity;
+                step.amount0 = result.amount0;
+                step.amount1 = result.amount1;
+            }
+            SessionCommand::Collect {
+                params,
+                unwrap_cspr,
+            } => {
+                let collect_result: CollectResult = call_router(
+                    router,
+                    "collect",
+                    runtime_args! {
+                        "data" => Bytes::from(params.to_bytes().unwrap()),
+                    },
+                );
 
-            if is_cspr {
-                let wcspr = get_wcspr();
-                if collect_result.token0 == wcspr {
-                    try_unwrap_cspr(wcspr, u256_to_u512(collect_result.amount0));
-                } else {
-                    try_unwrap_cspr(wcspr, u256_to_u512(collect_result.amount1));
+                if unwrap_cspr {
+                    let wcspr = get_wcspr();
+                    if collect_result.token0 == wcspr {
+                        try_unwrap_cspr(wcspr, u256_to_u512(collect_result.amount0));
+                    } else {
+                        try_unwrap_cspr(wcspr, u256_to_u512(collect_result.amount1));
+                    }
                 }
+                step.token_id = params.token_id;
+                step.amount0 = collect_result.amount0;
+                step.amount1 = collect_result.amount1;
             }
-        } else if entry_point == "burn" {
-            let token_id: U256 = helpers::decode_1(data);
-            runtime::call_versioned_contract::<()>(
-                router.into_hash().unwrap().into(),
-                None,
-                "burn",
-                runtime_args! {
-                    "token_id" => token_id,
-                },
-            );
-        } else if entry_point == "unwrap_cspr" {
-            let (amount, recipient): (U256, Key) = helpers::decode_2(data);
-            let wcspr = get_wcspr();
-            let wcspr_balance = erc20_helpers::get_balance(wcspr, get_self_key());
-            require(
-                wcspr_balance >= amount,
-                common::error::Error::ErrInsufficientBalanceWCSPR,
-            );
-            if wcspr_balance > 0.into() {
-                unwrap_wcspr(wcspr, recipient, u256_to_u512(wcspr_balance));
+            SessionCommand::CompositeMint { params } => {
+                let (amount0_desired, amount1_desired) =
+                    params
+                        .ranges
+                        .iter()
+                        .fold((U256::zero(), U256::zero()), |total, range| {
+                            (
+                                total.0 + range.amount0_desired,
+                                total.1 + range.amount1_desired,
+                            )
+                        });
+                funds.approve(params.token0, router, amount0_desired);
+                funds.approve(params.token1, router, amount1_desired);
+                let result: CompositeMintResult = call_router(
+                    router,
+                    "composite_mint",
+                    runtime_args! {
+                        "data" => Bytes::from(params.to_bytes().unwrap()),
+                    },
+                );
+                step.token_id = result.token_id;
+                step.liquidity = total_liquidity(&result.liquidities);
+                step.amount0 = result.amount0;
+                step.amount1 = result.amount1;
+            }
+            SessionCommand::CompositeIncreaseLiquidity { params } => {
+                let position: CompositePosition = call_router(
+                    router,
+                    "get_composite_position",
+                    runtime_args! {
+                        "token_id" => params.token_id,
+                    },
+                );
+                let (amount0_desired, amount1_desired) =
+                    params
+                        .amounts
+                        .iter()
+                        .fold((U256::zero(), U256::zero()), |total, amounts| {
+                            (
+                                total.0 + amounts.amount0_desired,
+                                total.1 + amounts.amount1_desired,
+                            )
+                        });
+                funds.approve(position.token0, router, amount0_desired);
+                funds.approve(position.token1, router, amount1_desired);
+                let result: CompositeIncreaseLiquidityResult = call_router(
+                    router,
+                    "composite_increase_liquidity",
+                    runtime_args! {
+                        "data" => Bytes::from(params.to_bytes().unwrap()),
+                    },
+                );
+                step.token_id = params.token_id;
+                step.liquidity = total_liquidity(&result.liquidities);
+                step.amount0 = result.amount0;
+                step.amount1 = result.amount1;
+            }
+            SessionCommand::CompositeDecreaseLiquidity { params } => {
+                let result: DecreaseLiquidityResult = call_router(
+                    router,
+                    "composite_decrease_liquidity",
+                    runtime_args! {
+                        "data" => Bytes::from(params.to_bytes().unwrap()),
+                    },
+                );
+                step.token_id = params.token_id;
+                step.liquidity = total_liquidity(&params.liquidities);
+                step.amount0 = result.amount0;
+                step.amount1 = result.amount1;
+            }
+            SessionCommand::CompositeCollect { params } => {
+                let result: CollectResult = call_router(
+                    router,
+                    "composite_collect",
+                    runtime_args! {
+                        "data" => Bytes::from(params.to_bytes().unwrap()),
+                    },
+                );
+                step.token_id = params.token_id;
+                step.amount0 = result.amount0;
+                step.amount1 = result.amount1;
+            }
+            SessionCommand::Burn { token_id } => {
+                call_router::<()>(
+                    router,
+                    "burn",
+                    runtime_args! {
+                        "token_id" => token_id,
+                    },
+                );
+                step.token_id = token_id;
+            }
+            SessionCommand::ExitPosition { params } => {
+                let result: CollectResult = call_router(
+                    router,
+                    "exit_position",
+                    runtime_args! {
+                        "data" => Bytes::from(params.to_bytes().unwrap()),
+                    },
+                );
+                step.token_id = params.token_id;
+                step.amount0 = result.amount0;
+                step.amount1 = result.amount1;
+            }
+            SessionCommand::Split {
+                token_id,
+                liquidity,
+            } => {
+                step.token_id = call_router(
+                    router,
+                    "split",
+                    runtime_args! {
+                        "token_id" => token_id,
+                        "liquidity" => liquidity,
+                    },
+                );
+                step.liquidity = liquidity;
+            }
+            SessionCommand::Merge { token_ids } => {
+                step.token_id = call_router(
+                    router,
+                    "merge",
+                    runtime_args! {
+                        "token_ids" => token_ids,
+                    },
+                );
+            }
+            SessionCommand::Rebalance { params } => {
+                let result: MintResult = call_router(
+                    router,
+                    "rebalance",
+                    runtime_args! {
+                        "data" => Bytes::from(params.to_bytes().unwrap()),
+                    },
+                );
+                step.token_id = result.token_id;
+                step.liquidity = result.liquidity;
+                step.amount0 = result.amount0;
+                step.amount1 = result.amount1;
+            }
+            SessionCommand::ZapMint { params } => {
+                funds.approve(params.token_in, router, params.amount_in);
+                let result: MintResult = call_router(
+                    router,
+                    "zap_mint",
+                    runtime_args! {
+                        "data" => Bytes::from(params.to_bytes().unwrap()),
+                    },
+                );
+                step.token_id = result.token_id;
+                step.liquidity = result.liquidity;
+                step.amount0 = result.amount0;
+                step.amount1 = result.amount1;
+            }
+            SessionCommand::SweepToken {
+                token,
+                amount_minimum,
+                recipient,
+            } => {
+                step.amount0 = call_router(
+                    router,
+                    "sweep_token",
+                    runtime_args! {
+                        "token" => token,
+                        "amount_minimum" => amount_minimum,
+                        "recipient" => recipient,
+                    },
+                );
+            }
+            SessionCommand::RefundCspr {} => {
+                step.amount0 = call_router(router, "refund_cspr", runtime_args! {});
+            }
+            SessionCommand::UnwrapCspr { amount, recipient } => {
+                let wcspr = get_wcspr();
+                let wcspr_balance = erc20_helpers::get_balance(wcspr, get_self_key());
+                require(wcspr_balance >= amount, Error::ErrInsufficientBalanceWCSPR);
+                if wcspr_balance > 0.into() {
+                    unwrap_wcspr(wcspr, recipient, u256_to_u512(wcspr_balance));
+                }
+                step.amount0 = wcspr_balance;
             }
+            // swaps go through the swap session
+            _ => runtime::revert(Error::ErrInvalidSessionCommand),
         }
+        funds.checkpoint();
+        steps.push(step);
     }
+    funds.settle();
+    save_session_result(steps);
 }
@@ -0,0 +1,74 @@
+use alloc::{string::String, vec, vec::Vec};
+use casper_types::{
+    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter, U256,
+};
+
+use common::{owner, timestamp_testing};
+
+use crate::store::{get_lock_ep, get_router_ep};
+
+fn add_entry_points(entry_points: &mut EntryPoints, list: &Vec<EntryPoint>) {
+    for e in list {
+        entry_points.add_entry_point(e.clone());
+    }
+}
+
+fn public_entry_point(name: &str, params: Vec<Parameter>, ret: CLType) -> EntryPoint {
+    EntryPoint::new(
+        String::from(name),
+        params,
+        ret,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub(crate) fn default() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    add_entry_points(&mut entry_points, &timestamp_testing::entry_points());
+    add_entry_points(&mut entry_points, &owner::entry_points());
+    entry_points.add_entry_point(get_router_ep());
+    entry_points.add_entry_point(get_lock_ep());
+
+    entry_points.add_entry_point(public_entry_point("init_locker", vec![], CLType::Unit));
+    entry_points.add_entry_point(public_entry_point(
+        "lock",
+        vec![
+            Parameter::new("token_id", CLType::U256),
+            Parameter::new("beneficiary", CLType::Key),
+            Parameter::new("unlock_time", CLType::U64),
+            Parameter::new("vesting", CLType::Bool),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "collect_fees",
+        vec![
+            Parameter::new("token_id", CLType::U256),
+            Parameter::new("recipient", CLType::Key),
+        ],
+        <(U256, U256)>::cl_type(),
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "withdraw_vested",
+        vec![
+            Parameter::new("token_id", CLType::U256),
+            Parameter::new("recipient", CLType::Key),
+            Parameter::new("amount0_min", CLType::U256),
+            Parameter::new("amount1_min", CLType::U256),
+        ],
+        <(U256, U256)>::cl_type(),
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "unlock",
+        vec![Parameter::new("token_id", CLType::U256)],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "get_withdrawable_liquidity",
+        vec![Parameter::new("token_id", CLType::U256)],
+        CLType::U128,
+    ));
+
+    entry_points
+}
@@ -0,0 +1,13 @@
+use casper_event_standard::Schemas;
+use common::locker_events::*;
+pub fn event_schemas() -> Schemas {
+    Schemas::new()
+        .with::<Locked>()
+        .with::<LockedFeesCollected>()
+        .with::<VestedLiquidityWithdrawn>()
+        .with::<Unlocked>()
+}
+
+pub fn init_events() {
+    casper_event_standard::init(event_schemas());
+}
@@ -0,0 +1,165 @@
+use crate::store::{read_lock, read_router, save_lock};
+use alloc::{string::ToString, vec};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{CLValue, Key, U128, U256};
+use common::{
+    error::{require, Error},
+    intf, locker_events,
+};
+use contract_utilities::helpers::{
+    current_block_timestamp, get_immediate_caller_key, get_named_args_2, get_named_args_4,
+    get_self_key, null_key,
+};
+use types::{CollectParams, DecreaseLiquidityParams, Lock};
+
+fn read_existing_lock(token_id: &U256) -> Lock {
+    let lock = read_lock(token_id);
+    require(lock.owner != null_key(), Error::ErrLockNotFound);
+    lock
+}
+
+fn only_beneficiary(lock: &Lock) {
+    require(
+        lock.beneficiary == get_immediate_caller_key(),
+        Error::ErrNotBeneficiary,
+    );
+}
+
+// liquidity released so far, all of it once unlocked
+pub fn vested_liquidity(lock: &Lock, now: u64) -> U128 {
+    if now >= lock.unlock_time {
+        lock.initial_liquidity
+    } else if !lock.vesting || now <= lock.start_time {
+        U128::zero()
+    } else {
+        let vested = U256::from(lock.initial_liquidity.as_u128())
+            * U256::from(now - lock.start_time)
+            / U256::from(lock.unlock_time - lock.start_time);
+        U128::from(vested.as_u128())
+    }
+}
+
+fn collect_to(token_id: U256, recipient: Key) -> (U256, U256) {
+    let result = intf::collect_position(
+        read_router(),
+        &CollectParams {
+            token_id,
+            recipient,
+            amount0_max: U128::MAX,
+            amount1_max: U128::MAX,
+        },
+    );
+    (result.amount0, result.amount1)
+}
+
+// the position NFT must be approved to the locker before locking
+#[no_mangle]
+pub extern "C" fn lock() {
+    let (token_id, beneficiary, unlock_time, vesting): (U256, Key, u64, bool) = get_named_args_4(
+        vec!["token_id", "beneficiary", "unlock_time", "vesting"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    let now = current_block_timestamp();
+    require(unlock_time > now, Error::ErrInvalidLock);
+    require(beneficiary != null_key(), Error::ErrInvalidLock);
+
+    let owner = get_immediate_caller_key();
+    let router = read_router();
+    let position = intf::get_position(router, token_id);
+    intf::nft_transfer_from(router, owner, get_self_key(), token_id);
+
+    let lock = Lock {
+        owner,
+        beneficiary,
+        start_time: now,
+        unlock_time,
+        vesting,
+        initial_liquidity: position.liquidity,
+        withdrawn_liquidity: U128::zero(),
+    };
+    save_lock(&token_id, &lock);
+    casper_event_standard::emit(locker_events::Locked::new(token_id, &lock));
+}
+
+// fees stay claimable by the beneficiary during the lock
+#[no_mangle]
+pub extern "C" fn collect_fees() {
+    let (token_id, recipient): (U256, Key) = get_named_args_2(
+        vec!["token_id", "recipient"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    let lock = read_existing_lock(&token_id);
+    only_beneficiary(&lock);
+
+    let (amount0, amount1) = collect_to(token_id, recipient);
+    casper_event_standard::emit(locker_events::LockedFeesCollected::new(
+        token_id, recipient, amount0, amount1,
+    ));
+    runtime::ret(CLValue::from_t((amount0, amount1)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw_vested() {
+    let (token_id, recipient, amount0_min, amount1_min): (U256, Key, U256, U256) = get_named_args_4(
+        vec!["token_id", "recipient", "amount0_min", "amount1_min"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    let mut lock = read_existing_lock(&token_id);
+    only_beneficiary(&lock);
+
+    let now = current_block_timestamp();
+    let liquidity = vested_liquidity(&lock, now) - lock.withdrawn_liquidity;
+    require(!liquidity.is_zero(), Error::ErrStillLocked);
+    lock.withdrawn_liquidity += liquidity;
+    save_lock(&token_id, &lock);
+
+    intf::decrease_liquidity(
+        read_router(),
+        &DecreaseLiquidityParams {
+            token_id,
+            liquidity,
+            amount0_min,
+            amount1_min,
+            deadline: now,
+        },
+    );
+    // uncollected fees are released along with the principal
+    let (amount0, amount1) = collect_to(token_id, recipient);
+    casper_event_standard::emit(locker_events::VestedLiquidityWithdrawn::new(
+        token_id, recipient, liquidity, amount0, amount1,
+    ));
+    runtime::ret(CLValue::from_t((amount0, amount1)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn unlock() {
+    let token_id: U256 = runtime::get_named_arg("token_id");
+    let lock = read_existing_lock(&token_id);
+    only_beneficiary(&lock);
+    require(
+        current_block_timestamp() >= lock.unlock_time,
+        Error::ErrStillLocked,
+    );
+
+    save_lock(&token_id, &Lock::default());
+    intf::nft_transfer(read_router(), lock.beneficiary, token_id);
+    casper_event_standard::emit(locker_events::Unlocked::new(token_id, lock.beneficiary));
+}
+
+#[no_mangle]
+pub extern "C" fn get_withdrawable_liquidity() {
+    let token_id: U256 = runtime::get_named_arg("token_id");
+    let lock = read_lock(&token_id);
+    let liquidity = if lock.owner == null_key() {
+        U128::zero()
+    } else {
+        vested_liquidity(&lock, current_block_timestamp()) - lock.withdrawn_liquidity
+    };
+    runtime::ret(CLValue::from_t(liquidity).unwrap_or_revert());
+}
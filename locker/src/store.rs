@@ -0,0 +1,41 @@
+use alloc::{string::String, vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    CLTyped, CLValue, EntryPoint, EntryPointAccess, EntryPointType, Key, Parameter, U256,
+};
+use common::{error::Error, get_set_dict, get_set_no_set};
+use contract_utilities::helpers::{self, null_key};
+use types::Lock;
+
+pub fn initialize(router: Key) {
+    storage::new_dictionary("locks").unwrap_or_revert_with(Error::FailedToCreateDictionary);
+    save_router(router);
+}
+
+get_set_no_set!(
+    router,
+    "router",
+    Key,
+    null_key(),
+    save_router,
+    read_router,
+    get_router,
+    get_router_ep,
+    "get_router"
+);
+
+get_set_dict!(
+    "locks",
+    "token_id",
+    U256,
+    Lock,
+    Lock::default(),
+    save_lock,
+    read_lock,
+    get_lock,
+    get_lock_ep,
+    "get_lock"
+);
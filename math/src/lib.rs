@@ -5,8 +5,11 @@ pub mod bitmath;
 pub mod fixed_point_128;
 pub mod fixed_point_96;
 pub mod fullmath;
+pub mod liquidity_amounts;
 pub mod liquidity_math;
+pub mod oracle_library;
 pub mod safe_cast;
 pub mod sqrt_price_math;
 pub mod swap_math;
 pub mod tickmath;
+pub mod zap;
@@ -1,7 +1,7 @@
 use core::ops::{Div, Shl};
 
+use crate::{fixed_point_96, fullmath};
 use casper_types::U256;
-use math::{fixed_point_96, fullmath};
 
 pub fn to_u128(x: &U256) -> u128 {
     x.as_u128()
@@ -0,0 +1,34 @@
+use core::ops::Shl;
+
+use crate::{fullmath, tickmath};
+use casper_types::U256;
+
+// arithmetic mean tick between two tick cumulatives, rounded to negative infinity
+pub fn get_arithmetic_mean_tick(tick_cumulatives: &[i64], seconds_ago: u32) -> i32 {
+    let tick_cumulatives_delta = tick_cumulatives[1] - tick_cumulatives[0];
+    let mut arithmetic_mean_tick = (tick_cumulatives_delta / seconds_ago as i64) as i32;
+    if tick_cumulatives_delta < 0 && tick_cumulatives_delta % seconds_ago as i64 != 0 {
+        arithmetic_mean_tick -= 1;
+    }
+    arithmetic_mean_tick
+}
+
+// amount of quote token received for base_amount of base token at the price of the given tick
+pub fn get_quote_at_tick(tick: i32, base_amount: U256, base_is_token0: bool) -> U256 {
+    let sqrt_ratio_x96 = tickmath::get_sqrt_ratio_at_tick(tick);
+    if sqrt_ratio_x96 <= U256::from(u128::MAX) {
+        let ratio_x192 = sqrt_ratio_x96 * sqrt_ratio_x96;
+        if base_is_token0 {
+            fullmath::mul_div(&ratio_x192, &base_amount, &U256::one().shl(192))
+        } else {
+            fullmath::mul_div(&U256::one().shl(192), &base_amount, &ratio_x192)
+        }
+    } else {
+        let ratio_x128 = fullmath::mul_div(&sqrt_ratio_x96, &sqrt_ratio_x96, &U256::one().shl(64));
+        if base_is_token0 {
+            fullmath::mul_div(&ratio_x128, &base_amount, &U256::one().shl(128))
+        } else {
+            fullmath::mul_div(&U256::one().shl(128), &base_amount, &ratio_x128)
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use core::ops::Shl;
+
+use casper_types::U256;
+
+use crate::{
+    fixed_point_96, fullmath, liquidity_amounts::get_amounts_for_liquidity, sqrt_price_math,
+};
+
+const FEE_DENOMINATOR: u32 = 1_000_000;
+
+fn unit_liquidity() -> u128 {
+    1_u128.shl(fixed_point_96::RESOLUTION)
+}
+
+// amount of the input to swap so that the remainder and the swap output match the
+// ratio of the range at price `sqrt_price_x96`, given the swap rate amount_out / amount_in
+fn split_amount_in(
+    amount_in: &U256,
+    sqrt_price_x96: &U256,
+    sqrt_ratio_a_x96: &U256,
+    sqrt_ratio_b_x96: &U256,
+    zero_for_one: bool,
+    rate_out: &U256,
+    rate_in: &U256,
+) -> U256 {
+    let (amount0, amount1) = get_amounts_for_liquidity(
+        sqrt_price_x96,
+        sqrt_ratio_a_x96,
+        sqrt_ratio_b_x96,
+        unit_liquidity(),
+    );
+    let (range_in, range_out) = if zero_for_one {
+        (amount0, amount1)
+    } else {
+        (amount1, amount0)
+    };
+    let denominator = range_out + fullmath::mul_div(&range_in, rate_out, rate_in);
+    if denominator.is_zero() {
+        return U256::zero();
+    }
+    fullmath::mul_div(amount_in, &range_out, &denominator)
+}
+
+pub fn get_zap_swap_amount(
+    sqrt_price_x96: &U256,
+    sqrt_ratio_a_x96: &U256,
+    sqrt_ratio_b_x96: &U256,
+    liquidity: u128,
+    amount_in: &U256,
+    zero_for_one: bool,
+    fee: u32,
+) -> U256 {
+    // out of range positions only need one of the tokens
+    if sqrt_price_x96 <= sqrt_ratio_a_x96 {
+        return if zero_for_one {
+            U256::zero()
+        } else {
+            *amount_in
+        };
+    }
+    if sqrt_price_x96 >= sqrt_ratio_b_x96 {
+        return if zero_for_one {
+            *amount_in
+        } else {
+            U256::zero()
+        };
+    }
+
+    let q96 = fixed_point_96::q96();
+    let fee_complement = U256::from(FEE_DENOMINATOR - fee);
+    let spot_out = if zero_for_one {
+        fullmath::mul_div(sqrt_price_x96, sqrt_price_x96, &q96)
+    } else {
+        fullmath::mul_div(
+            &fullmath::mul_div(&q96, &q96, sqrt_price_x96),
+            &q96,
+            sqrt_price_x96,
+        )
+    };
+    let spot_out = fullmath::mul_div(&spot_out, &fee_complement, &FEE_DENOMINATOR.into());
+    let swap_amount = split_amount_in(
+        amount_in,
+        sqrt_price_x96,
+        sqrt_ratio_a_x96,
+        sqrt_ratio_b_x96,
+        zero_for_one,
+        &spot_out,
+        &q96,
+    );
+    if liquidity == 0 || swap_amount.is_zero() {
+        return swap_amount;
+    }
+
+    // account for the price impact of the swap inside the current liquidity
+    let swap_amount_less_fee =
+        fullmath::mul_div(&swap_amount, &fee_complement, &FEE_DENOMINATOR.into());
+    let sqrt_price_next_x96 = sqrt_price_math::get_next_sqrt_price_from_input(
+        sqrt_price_x96,
+        liquidity,
+        &swap_amount_less_fee,
+        zero_for_one,
+    );
+    if sqrt_price_next_x96 <= *sqrt_ratio_a_x96 || sqrt_price_next_x96 >= *sqrt_ratio_b_x96 {
+        return swap_amount;
+    }
+    let amount_out = if zero_for_one {
+        sqrt_price_math::get_amount1_delta(&sqrt_price_next_x96, sqrt_price_x96, liquidity, false)
+    } else {
+        sqrt_price_math::get_amount0_delta(sqrt_price_x96, &sqrt_price_next_x96, liquidity, false)
+    };
+    split_amount_in(
+        amount_in,
+        &sqrt_price_next_x96,
+        sqrt_ratio_a_x96,
+        sqrt_ratio_b_x96,
+        zero_for_one,
+        &amount_out,
+        &swap_amount,
+    )
+}
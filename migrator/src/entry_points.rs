@@ -0,0 +1,43 @@
+use alloc::{string::String, vec, vec::Vec};
+use casper_types::{
+    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter,
+};
+
+use common::{owner, timestamp_testing};
+use types::{MigrateParams, MintResult};
+
+use crate::store::{get_router_ep, get_v2_factory_ep, get_v2_router_ep};
+
+fn add_entry_points(entry_points: &mut EntryPoints, list: &Vec<EntryPoint>) {
+    for e in list {
+        entry_points.add_entry_point(e.clone());
+    }
+}
+
+fn public_entry_point(name: &str, params: Vec<Parameter>, ret: CLType) -> EntryPoint {
+    EntryPoint::new(
+        String::from(name),
+        params,
+        ret,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub(crate) fn default() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    add_entry_points(&mut entry_points, &timestamp_testing::entry_points());
+    add_entry_points(&mut entry_points, &owner::entry_points());
+    entry_points.add_entry_point(get_router_ep());
+    entry_points.add_entry_point(get_v2_router_ep());
+    entry_points.add_entry_point(get_v2_factory_ep());
+
+    entry_points.add_entry_point(public_entry_point("init_migrator", vec![], CLType::Unit));
+    entry_points.add_entry_point(public_entry_point(
+        "migrate",
+        vec![Parameter::new("params", MigrateParams::cl_type())],
+        MintResult::cl_type(),
+    ));
+
+    entry_points
+}
@@ -0,0 +1,9 @@
+use casper_event_standard::Schemas;
+use common::migrator_events::*;
+pub fn event_schemas() -> Schemas {
+    Schemas::new().with::<Migrated>()
+}
+
+pub fn init_events() {
+    casper_event_standard::init(event_schemas());
+}
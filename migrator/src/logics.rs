@@ -0,0 +1,121 @@
+use crate::store::{read_router, read_v2_factory, read_v2_router};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{CLValue, U256};
+use common::{
+    erc20_helpers,
+    error::{require, Error},
+    intf, migrator_events,
+};
+use contract_utilities::helpers::{get_immediate_caller_key, get_self_key};
+use types::{MigrateParams, MintParams};
+
+// the lp tokens must be approved to the migrator before migrating
+#[no_mangle]
+pub extern "C" fn migrate() {
+    let params: MigrateParams = runtime::get_named_arg("params");
+    require(
+        params.percentage_to_migrate > 0 && params.percentage_to_migrate <= 100,
+        Error::ErrInvalidMigrateParams,
+    );
+    require(
+        !params.liquidity_to_migrate.is_zero(),
+        Error::ErrInvalidMigrateParams,
+    );
+    // the pair must be the trusted factory's pair of the tokens minted into
+    require(
+        intf::get_pair_v2(read_v2_factory(), params.token0, params.token1) == params.pair,
+        Error::ErrInvalidMigrateParams,
+    );
+    let (pair_token0, pair_token1) = intf::get_pair_tokens_v2(params.pair);
+    require(
+        (pair_token0 == params.token0 && pair_token1 == params.token1)
+            || (pair_token0 == params.token1 && pair_token1 == params.token0),
+        Error::ErrInvalidMigrateParams,
+    );
+    let owner = get_immediate_caller_key();
+    let router = read_router();
+    let v2_router = read_v2_router();
+
+    // burn v2 liquidity to this address
+    erc20_helpers::transfer_from(
+        params.pair,
+        owner,
+        get_self_key(),
+        params.liquidity_to_migrate,
+    );
+    erc20_helpers::approve(params.pair, v2_router, params.liquidity_to_migrate);
+    let (amount0_v2, amount1_v2) = intf::remove_liquidity_v2(
+        v2_router,
+        params.token0,
+        params.token1,
+        params.liquidity_to_migrate,
+        params.amount0_v2_min,
+        params.amount1_v2_min,
+        get_self_key(),
+        params.deadline,
+    );
+
+    // calculate the amounts to migrate to v3
+    let percentage = U256::from(params.percentage_to_migrate);
+    let amount0_v2_to_migrate = amount0_v2 * percentage / 100;
+    let amount1_v2_to_migrate = amount1_v2 * percentage / 100;
+
+    intf::create_and_initialize_pool_if_necessary(
+        router,
+        params.token0,
+        params.token1,
+        params.fee,
+        params.sqrt_price_x96,
+    );
+
+    // approve the position manager up to the maximum token amounts
+    erc20_helpers::approve(params.token0, router, amount0_v2_to_migrate);
+    erc20_helpers::approve(params.token1, router, amount1_v2_to_migrate);
+
+    // mint v3 position
+    let result = intf::mint_position(
+        router,
+        &MintParams {
+            token0: params.token0,
+            token1: params.token1,
+            fee: params.fee,
+            tick_lower: params.tick_lower,
+            tick_upper: params.tick_upper,
+            amount0_desired: amount0_v2_to_migrate,
+            amount1_desired: amount1_v2_to_migrate,
+            amount0_min: params.amount0_min,
+            amount1_min: params.amount1_min,
+            recipient: params.recipient,
+            deadline: params.deadline,
+        },
+    );
+
+    // if necessary, clear allowance and refund dust
+    let refund0 = amount0_v2 - result.amount0;
+    if result.amount0 < amount0_v2_to_migrate {
+        erc20_helpers::approve(params.token0, router, U256::zero());
+    }
+    if !refund0.is_zero() {
+        erc20_helpers::transfer(params.token0, owner, refund0);
+    }
+    let refund1 = amount1_v2 - result.amount1;
+    if result.amount1 < amount1_v2_to_migrate {
+        erc20_helpers::approve(params.token1, router, U256::zero());
+    }
+    if !refund1.is_zero() {
+        erc20_helpers::transfer(params.token1, owner, refund1);
+    }
+
+    casper_event_standard::emit(migrator_events::Migrated::new(
+        params.pair,
+        owner,
+        params.liquidity_to_migrate,
+        result.token_id,
+        result.liquidity,
+        result.amount0,
+        result.amount1,
+        refund0,
+        refund1,
+    ));
+    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
+}
@@ -0,0 +1,73 @@
+#![no_main]
+#![no_std]
+
+extern crate alloc;
+mod entry_points;
+
+pub mod events;
+pub mod logics;
+pub mod store;
+
+use alloc::{format, string::String};
+use casper_contract::contract_api::runtime;
+use casper_types::{contracts::NamedKeys, runtime_args, Key, RuntimeArgs};
+use common::{
+    error::Error,
+    owner,
+    timestamp_testing::{self, with_testing_mod},
+    upgrade,
+};
+use contract_utilities::helpers;
+use events::event_schemas;
+
+#[no_mangle]
+pub extern "C" fn init_migrator() {
+    if runtime::has_key("contract_hash") {
+        runtime::revert(Error::ContractAlreadyInitialized);
+    }
+
+    let caller = helpers::get_immediate_caller_key();
+    let contract_hash: Key = runtime::get_named_arg("contract_hash");
+    let contract_package_hash: Key = runtime::get_named_arg("contract_package_hash");
+    let router: Key = runtime::get_named_arg("router");
+    let v2_router: Key = runtime::get_named_arg("v2_router");
+    let v2_factory: Key = runtime::get_named_arg("v2_factory");
+
+    helpers::set_key("contract_hash", contract_hash);
+    helpers::set_key("contract_package_hash", contract_package_hash);
+    owner::init(caller);
+    events::init_events();
+    store::initialize(router, v2_router, v2_factory);
+    timestamp_testing::init();
+}
+
+#[no_mangle]
+fn call() {
+    let contract_name: String = runtime::get_named_arg("contract_name");
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        let router: Key = runtime::get_named_arg("router");
+        let v2_router: Key = runtime::get_named_arg("v2_router");
+        let v2_factory: Key = runtime::get_named_arg("v2_factory");
+        let (contract_hash, contract_package_hash) =
+            upgrade::install_contract(contract_name, entry_points::default(), NamedKeys::new());
+
+        runtime::call_contract::<()>(
+            contract_hash,
+            "init_migrator",
+            with_testing_mod(&mut runtime_args! {
+                "contract_hash" => Key::from(contract_hash),
+                "contract_package_hash" => Key::from(contract_package_hash),
+                "router" => router,
+                "v2_router" => v2_router,
+                "v2_factory" => v2_factory,
+            }),
+        );
+    } else {
+        upgrade::upgrade(
+            contract_name,
+            entry_points::default(),
+            NamedKeys::new(),
+            event_schemas(),
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use alloc::{string::String, vec};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{CLTyped, CLValue, EntryPoint, EntryPointAccess, EntryPointType, Key};
+use common::get_set_no_set;
+use contract_utilities::helpers::{self, null_key};
+
+pub fn initialize(router: Key, v2_router: Key, v2_factory: Key) {
+    save_router(router);
+    save_v2_router(v2_router);
+    save_v2_factory(v2_factory);
+}
+
+get_set_no_set!(
+    router,
+    "router",
+    Key,
+    null_key(),
+    save_router,
+    read_router,
+    get_router,
+    get_router_ep,
+    "get_router"
+);
+
+get_set_no_set!(
+    v2_router,
+    "v2_router",
+    Key,
+    null_key(),
+    save_v2_router,
+    read_v2_router,
+    get_v2_router,
+    get_v2_router_ep,
+    "get_v2_router"
+);
+
+get_set_no_set!(
+    v2_factory,
+    "v2_factory",
+    Key,
+    null_key(),
+    save_v2_factory,
+    read_v2_factory,
+    get_v2_factory,
+    get_v2_factory_ep,
+    "get_v2_factory"
+);
@@ -11,7 +11,6 @@ use alloc::{
     vec::Vec,
 };
 use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
-use casper_event_standard::Schemas;
 use casper_types::{
     contracts::NamedKeys, runtime_args, CLType, CLTyped, CLValue, EntryPoint, EntryPointAccess,
     EntryPointType, EntryPoints, Key, Parameter, RuntimeArgs, U256,
@@ -175,7 +174,7 @@ fn call() {
             contract_name,
             get_entry_points(),
             NamedKeys::new(),
-            Schemas::new(),
+            events::event_schemas(),
         );
     }
 }
@@ -5,26 +5,39 @@ use casper_types::{
     CLType, CLTyped, CLValue, EntryPoint, EntryPointAccess, EntryPointType, Key, Parameter, U128,
     U256,
 };
+use common::path::Path;
 use contract_utilities::helpers::get_named_args_3;
 use router::periphery::{
+    composite::{
+        composite_collect_internal, composite_decrease_liquidity_internal,
+        composite_increase_liquidity_internal, composite_mint_internal, composite_totals_internal,
+    },
     liquidity_amounts,
     logics::{
         burn_internal, collect_internal, create_and_initialize_pool_if_necessary_internal,
-        decrease_liquidity_internal, increase_liquidity_internal, mint_callback_internal,
-        mint_internal,
+        decrease_liquidity_internal, exit_position_internal, increase_liquidity_internal,
+        mint_callback_internal, mint_internal, rebalance_internal, zap_mint_internal,
     },
+    payments::{refund_cspr_internal, sweep_token_internal},
+    split_merge::{merge_internal, split_internal},
     store::{
-        get_next_id_ep, get_next_pool_id_ep, get_pool_id_ep, get_pool_key_ep, get_position_ep,
+        compute_partner_fee_id, get_composite_position_ep, get_max_partner_fee_bips_ep,
+        get_next_id_ep, get_next_pool_id_ep, get_partner_fees_ep, get_pool_id_ep, get_pool_key_ep,
+        get_position_ep, get_reserved_partner_fees_ep,
     },
     swap_router::{
-        exact_input_internal, exact_input_single_internal, exact_output_internal,
-        exact_output_single_internal, swap_callback_internal,
+        claim_partner_fees_internal, exact_input_internal, exact_input_single_internal,
+        exact_input_split_internal, exact_output_internal, exact_output_single_internal,
+        exact_output_split_internal, set_max_partner_fee_bips_internal, swap_callback_internal,
     },
 };
 use types::{
-    i256::I256, CollectParams, CollectResult, DecreaseLiquidityParams, DecreaseLiquidityResult,
-    ExactInputParams, ExactInputSingleParams, ExactOutputParams, ExactOutputSingleParams,
-    IncreaseLiquidityParams, IncreaseLiquidityResult, MintParams, MintResult,
+    i256::I256, CollectParams, CollectResult, CompositeDecreaseLiquidityParams,
+    CompositeIncreaseLiquidityParams, CompositeMintParams, CompositeTotals,
+    DecreaseLiquidityParams, DecreaseLiquidityResult, ExactInputParams, ExactInputSingleParams,
+    ExactInputSplitParams, ExactOutputParams, ExactOutputSingleParams, ExactOutputSplitParams,
+    ExitPositionParams, IncreaseLiquidityParams, IncreaseLiquidityResult, MintParams, MintResult,
+    RebalanceParams, ZapMintParams,
 };
 
 pub fn router_entry_points_list() -> Vec<EntryPoint> {
@@ -34,6 +47,10 @@ pub fn router_entry_points_list() -> Vec<EntryPoint> {
     ret.push(get_pool_key_ep());
     ret.push(get_next_id_ep());
     ret.push(get_next_pool_id_ep());
+    ret.push(get_composite_position_ep());
+    ret.push(get_max_partner_fee_bips_ep());
+    ret.push(get_partner_fees_ep());
+    ret.push(get_reserved_partner_fees_ep());
 
     ret.push(EntryPoint::new(
         "mint",
@@ -75,6 +92,158 @@ pub fn router_entry_points_list() -> Vec<EntryPoint> {
         EntryPointType::Contract,
     ));
 
+    ret.push(EntryPoint::new(
+        "exit_position",
+        vec![Parameter::new("data", CLType::List(Box::new(CLType::U8)))],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "split",
+        vec![
+            Parameter::new("token_id", CLType::U256),
+            Parameter::new("liquidity", CLType::U128),
+        ],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "merge",
+        vec![Parameter::new(
+            "token_ids",
+            CLType::List(Box::new(CLType::U256)),
+        )],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "rebalance",
+        vec![Parameter::new("data", CLType::List(Box::new(CLType::U8)))],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "zap_mint",
+        vec![Parameter::new("data", CLType::List(Box::new(CLType::U8)))],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "composite_mint",
+        vec![Parameter::new("data", CLType::List(Box::new(CLType::U8)))],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "composite_increase_liquidity",
+        vec![Parameter::new("data", CLType::List(Box::new(CLType::U8)))],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "composite_decrease_liquidity",
+        vec![Parameter::new("data", CLType::List(Box::new(CLType::U8)))],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "composite_collect",
+        vec![Parameter::new("data", CLType::List(Box::new(CLType::U8)))],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "get_composite_totals",
+        vec![Parameter::new("token_id", CLType::U256)],
+        CompositeTotals::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "sweep_token",
+        vec![
+            Parameter::new("token", Key::cl_type()),
+            Parameter::new("amount_minimum", CLType::U256),
+            Parameter::new("recipient", Key::cl_type()),
+        ],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "refund_cspr",
+        vec![],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "get_wcspr",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "set_wcspr",
+        vec![Parameter::new("wcspr", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "set_max_partner_fee_bips",
+        vec![Parameter::new("max_partner_fee_bips", CLType::U32)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "claim_partner_fees",
+        vec![
+            Parameter::new("token", Key::cl_type()),
+            Parameter::new("recipient", Key::cl_type()),
+        ],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "get_partner_fee_id",
+        vec![
+            Parameter::new("fee_recipient", Key::cl_type()),
+            Parameter::new("token", Key::cl_type()),
+        ],
+        CLType::String,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
     ret.push(EntryPoint::new(
         "swap_callback",
         vec![],
@@ -99,6 +268,38 @@ pub fn router_entry_points_list() -> Vec<EntryPoint> {
         EntryPointType::Contract,
     ));
 
+    ret.push(EntryPoint::new(
+        "exact_input_split",
+        vec![Parameter::new("data", CLType::List(Box::new(CLType::U8)))],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "exact_output_single",
+        vec![Parameter::new("data", CLType::List(Box::new(CLType::U8)))],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "exact_output",
+        vec![Parameter::new("data", CLType::List(Box::new(CLType::U8)))],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    ret.push(EntryPoint::new(
+        "exact_output_split",
+        vec![Parameter::new("data", CLType::List(Box::new(CLType::U8)))],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
     ret.push(EntryPoint::new(
         "get_liquidity_for_amount0",
         vec![],
@@ -264,6 +465,154 @@ pub fn burn() {
     burn_internal(token_id);
 }
 
+#[no_mangle]
+pub fn exit_position() {
+    let data: Bytes = runtime::get_named_arg("data");
+    let params = ExitPositionParams::from_bytes(&data).unwrap().0;
+    let (token0, token1, amount0, amount1) = exit_position_internal(&params);
+    runtime::ret(
+        CLValue::from_t(CollectResult {
+            token0,
+            token1,
+            amount0,
+            amount1,
+        })
+        .unwrap_or_revert(),
+    );
+}
+
+#[no_mangle]
+pub fn set_max_partner_fee_bips() {
+    let max_partner_fee_bips: u32 = runtime::get_named_arg("max_partner_fee_bips");
+    set_max_partner_fee_bips_internal(max_partner_fee_bips);
+}
+
+#[no_mangle]
+pub fn claim_partner_fees() {
+    let token: Key = runtime::get_named_arg("token");
+    let recipient: Key = runtime::get_named_arg("recipient");
+    runtime::ret(CLValue::from_t(claim_partner_fees_internal(token, recipient)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub fn get_partner_fee_id() {
+    let fee_recipient: Key = runtime::get_named_arg("fee_recipient");
+    let token: Key = runtime::get_named_arg("token");
+    runtime::ret(
+        CLValue::from_t(compute_partner_fee_id(&fee_recipient, &token)).unwrap_or_revert(),
+    );
+}
+
+#[no_mangle]
+pub fn split() {
+    let token_id: U256 = runtime::get_named_arg("token_id");
+    let liquidity: U128 = runtime::get_named_arg("liquidity");
+    runtime::ret(CLValue::from_t(split_internal(token_id, liquidity)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub fn merge() {
+    let token_ids: Vec<U256> = runtime::get_named_arg("token_ids");
+    runtime::ret(CLValue::from_t(merge_internal(&token_ids)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub fn composite_mint() {
+    let data: Bytes = runtime::get_named_arg("data");
+    let params = CompositeMintParams::from_bytes(&data).unwrap().0;
+    runtime::ret(CLValue::from_t(composite_mint_internal(&params)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub fn composite_increase_liquidity() {
+    let data: Bytes = runtime::get_named_arg("data");
+    let params = CompositeIncreaseLiquidityParams::from_bytes(&data)
+        .unwrap()
+        .0;
+    runtime::ret(
+        CLValue::from_t(composite_increase_liquidity_internal(&params)).unwrap_or_revert(),
+    );
+}
+
+#[no_mangle]
+pub fn composite_decrease_liquidity() {
+    let data: Bytes = runtime::get_named_arg("data");
+    let params = CompositeDecreaseLiquidityParams::from_bytes(&data)
+        .unwrap()
+        .0;
+    let (amount0, amount1) = composite_decrease_liquidity_internal(&params);
+    runtime::ret(CLValue::from_t(DecreaseLiquidityResult { amount0, amount1 }).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub fn composite_collect() {
+    let data: Bytes = runtime::get_named_arg("data");
+    let params = CollectParams::from_bytes(&data).unwrap().0;
+    let (token0, token1, amount0, amount1) = composite_collect_internal(&params);
+    runtime::ret(
+        CLValue::from_t(CollectResult {
+            amount0,
+            amount1,
+            token0,
+            token1,
+        })
+        .unwrap_or_revert(),
+    );
+}
+
+#[no_mangle]
+pub fn get_composite_totals() {
+    let token_id: U256 = runtime::get_named_arg("token_id");
+    runtime::ret(CLValue::from_t(composite_totals_internal(&token_id)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub fn rebalance() {
+    let data: Bytes = runtime::get_named_arg("data");
+    let params = RebalanceParams::from_bytes(&data).unwrap().0;
+    let (token_id, liquidity, amount0, amount1) = rebalance_internal(&params);
+    runtime::ret(
+        CLValue::from_t(MintResult {
+            token_id,
+            amount0,
+            amount1,
+            liquidity: liquidity.into(),
+        })
+        .unwrap_or_revert(),
+    );
+}
+
+#[no_mangle]
+pub fn zap_mint() {
+    let data: Bytes = runtime::get_named_arg("data");
+    let params = ZapMintParams::from_bytes(&data).unwrap().0;
+    let (token_id, liquidity, amount0, amount1) = zap_mint_internal(&params);
+    runtime::ret(
+        CLValue::from_t(MintResult {
+            token_id,
+            amount0,
+            amount1,
+            liquidity: liquidity.into(),
+        })
+        .unwrap_or_revert(),
+    );
+}
+
+#[no_mangle]
+pub fn sweep_token() {
+    let token: Key = runtime::get_named_arg("token");
+    let amount_minimum: U256 = runtime::get_named_arg("amount_minimum");
+    let recipient: Key = runtime::get_named_arg("recipient");
+    let amount = sweep_token_internal(token, amount_minimum, recipient);
+    runtime::ret(CLValue::from_t(amount).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub fn refund_cspr() {
+    let amount = refund_cspr_internal();
+    runtime::ret(CLValue::from_t(amount).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub fn swap_callback() {
     let (amount0_delta, amount1_delta, data): (I256, I256, Bytes) = get_named_args_3(vec![
@@ -290,6 +639,14 @@ pub fn exact_input() {
     runtime::ret(CLValue::from_t(amount_out).unwrap_or_revert());
 }
 
+#[no_mangle]
+pub fn exact_input_split() {
+    let data: Bytes = runtime::get_named_arg("data");
+    let params = ExactInputSplitParams::from_bytes(&data).unwrap().0;
+    let amount_out = exact_input_split_internal(&params);
+    runtime::ret(CLValue::from_t(amount_out).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub fn exact_output_single() {
     let data: Bytes = runtime::get_named_arg("data");
@@ -306,6 +663,14 @@ pub fn exact_output() {
     runtime::ret(CLValue::from_t(amount_in).unwrap_or_revert());
 }
 
+#[no_mangle]
+pub fn exact_output_split() {
+    let data: Bytes = runtime::get_named_arg("data");
+    let params = ExactOutputSplitParams::from_bytes(&data).unwrap().0;
+    let amount_in = exact_output_split_internal(&params);
+    runtime::ret(CLValue::from_t(amount_in).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub fn get_liquidity_for_amount0() {
     let sqrt_ratio_a_x96: U256 = runtime::get_named_arg("sqrt_ratio_a_x96");
@@ -420,27 +785,35 @@ pub extern "C" fn mint_callback() {
 #[no_mangle]
 pub fn decode_first_pool() {
     let path: Bytes = runtime::get_named_arg("path");
-    let ret = common::path::decode_first_pool(&path);
+    let ret = Path::decode(&path).unwrap_or_revert().first_pool();
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
 #[no_mangle]
 pub fn get_first_pool() {
     let path: Bytes = runtime::get_named_arg("path");
-    let ret = common::path::get_first_pool(&path);
+    let (token_a, token_b, fee) = Path::decode(&path).unwrap_or_revert().first_pool();
+    let ret = Path::new(token_a)
+        .hop(fee, token_b)
+        .encode()
+        .unwrap_or_revert();
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
 #[no_mangle]
 pub fn skip_token() {
     let path: Bytes = runtime::get_named_arg("path");
-    let ret = common::path::skip_token(&path);
+    let ret = Path::decode(&path)
+        .unwrap_or_revert()
+        .skip_token()
+        .encode()
+        .unwrap_or_revert();
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
 #[no_mangle]
 pub fn has_multiple_pools() {
     let path: Bytes = runtime::get_named_arg("path");
-    let ret = common::path::has_multiple_pools(&path);
+    let ret = Path::decode(&path).unwrap_or_revert().num_pools() > 1;
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
-}
\ No newline at end of file
+}
@@ -0,0 +1,351 @@
+use super::{
+    checks::{check_deadline, is_authorized_for_token},
+    logics::{add_liquidity_internal, mint_token},
+    pool_key::get_pool_address,
+    store::{cache_pool_key, read_composite_position, read_pool_key, save_composite_position},
+};
+use alloc::vec::Vec;
+use casper_types::{Key, U128, U256};
+use common::{
+    error::{require, Error},
+    intf,
+    router_events::{Collect, DecreaseLiquidity, IncreaseLiquidity},
+    utils::position_key,
+};
+use contract_utilities::helpers::{get_immediate_caller_key, get_self_key, null_key};
+use math::{fixed_point_128, fullmath, liquidity_amounts::get_amounts_for_liquidity, tickmath};
+use types::{
+    AddLiquidityParams, CollectParams, CompositeDecreaseLiquidityParams,
+    CompositeIncreaseLiquidityParams, CompositeIncreaseLiquidityResult, CompositeMintParams,
+    CompositeMintResult, CompositePosition, CompositeTotals, PoolKey, PositionInfo, RangePosition,
+};
+
+pub const MAX_COMPOSITE_RANGES: usize = 20;
+
+fn read_existing_composite_position(token_id: &U256) -> CompositePosition {
+    let position = read_composite_position(token_id);
+    require(
+        !position.ranges.is_empty(),
+        Error::ErrInvalidCompositeParams,
+    );
+    position
+}
+
+fn pool_position_info(pool: Key, range: &RangePosition) -> PositionInfo {
+    intf::get_pool_position(
+        pool,
+        position_key(get_self_key(), range.tick_lower, range.tick_upper),
+    )
+}
+
+pub fn fees_owed(
+    fee_growth_inside_x128: U256,
+    fee_growth_inside_last_x128: U256,
+    liquidity: U128,
+) -> U128 {
+    fullmath::mul_div(
+        &(fee_growth_inside_x128
+            .overflowing_sub(fee_growth_inside_last_x128)
+            .0),
+        &liquidity.as_u128().into(),
+        &fixed_point_128::q128(),
+    )
+    .as_u128()
+    .into()
+}
+
+// credits the fees earned by the range since its last update
+fn accrue_fees(range: &mut RangePosition, info: &PositionInfo) {
+    range.tokens_owed0 = range
+        .tokens_owed0
+        .overflowing_add(fees_owed(
+            info.fee_growth_inside0_last_x128,
+            range.fee_growth_inside0_last_x128,
+            range.liquidity,
+        ))
+        .0;
+    range.tokens_owed1 = range
+        .tokens_owed1
+        .overflowing_add(fees_owed(
+            info.fee_growth_inside1_last_x128,
+            range.fee_growth_inside1_last_x128,
+            range.liquidity,
+        ))
+        .0;
+    range.fee_growth_inside0_last_x128 = info.fee_growth_inside0_last_x128;
+    range.fee_growth_inside1_last_x128 = info.fee_growth_inside1_last_x128;
+}
+
+fn sum_liquidity(liquidities: &[U128]) -> U128 {
+    liquidities
+        .iter()
+        .fold(U128::zero(), |total, liquidity| total + *liquidity)
+}
+
+pub fn composite_mint_internal(params: &CompositeMintParams) -> CompositeMintResult {
+    check_deadline(params.deadline);
+    require(
+        !params.ranges.is_empty() && params.ranges.len() <= MAX_COMPOSITE_RANGES,
+        Error::ErrInvalidCompositeParams,
+    );
+
+    let payer = get_immediate_caller_key();
+    let mut pool = null_key();
+    let mut ranges: Vec<RangePosition> = Vec::new();
+    let mut liquidities: Vec<U128> = Vec::new();
+    let (mut amount0, mut amount1) = (U256::zero(), U256::zero());
+    for range in params.ranges.iter() {
+        let (liquidity, used0, used1, range_pool) = add_liquidity_internal(
+            &AddLiquidityParams {
+                token0: params.token0,
+                token1: params.token1,
+                fee: params.fee,
+                recipient: get_self_key(),
+                tick_lower: range.tick_lower,
+                tick_upper: range.tick_upper,
+                amount0_desired: range.amount0_desired,
+                amount1_desired: range.amount1_desired,
+                amount0_min: range.amount0_min,
+                amount1_min: range.amount1_min,
+            },
+            payer,
+        );
+        pool = range_pool;
+        let mut position = RangePosition {
+            tick_lower: range.tick_lower,
+            tick_upper: range.tick_upper,
+            liquidity: liquidity.into(),
+            ..Default::default()
+        };
+        let info = pool_position_info(pool, &position);
+        position.fee_growth_inside0_last_x128 = info.fee_growth_inside0_last_x128;
+        position.fee_growth_inside1_last_x128 = info.fee_growth_inside1_last_x128;
+        ranges.push(position);
+        liquidities.push(liquidity.into());
+        amount0 += used0;
+        amount1 += used1;
+    }
+
+    let pool_id = cache_pool_key(
+        pool,
+        &PoolKey {
+            token0: params.token0,
+            token1: params.token1,
+            fee: params.fee,
+        },
+    );
+    let token_id = mint_token(params.recipient);
+    save_composite_position(
+        &token_id,
+        &CompositePosition {
+            pool_id,
+            token0: params.token0,
+            token1: params.token1,
+            fee: params.fee,
+            ranges,
+        },
+    );
+
+    casper_event_standard::emit(IncreaseLiquidity::new(
+        token_id,
+        sum_liquidity(&liquidities),
+        amount0,
+        amount1,
+    ));
+    CompositeMintResult {
+        token_id,
+        liquidities,
+        amount0,
+        amount1,
+    }
+}
+
+pub fn composite_increase_liquidity_internal(
+    params: &CompositeIncreaseLiquidityParams,
+) -> CompositeIncreaseLiquidityResult {
+    check_deadline(params.deadline);
+    let mut position = read_existing_composite_position(&params.token_id);
+    require(
+        params.amounts.len() == position.ranges.len(),
+        Error::ErrInvalidCompositeParams,
+    );
+
+    let payer = get_immediate_caller_key();
+    let mut liquidities: Vec<U128> = Vec::new();
+    let (mut amount0, mut amount1) = (U256::zero(), U256::zero());
+    for (range, amounts) in position.ranges.iter_mut().zip(params.amounts.iter()) {
+        if amounts.amount0_desired.is_zero() && amounts.amount1_desired.is_zero() {
+            liquidities.push(U128::zero());
+            continue;
+        }
+        let (liquidity, used0, used1, pool) = add_liquidity_internal(
+            &AddLiquidityParams {
+                token0: position.token0,
+                token1: position.token1,
+                fee: position.fee,
+                recipient: get_self_key(),
+                tick_lower: range.tick_lower,
+                tick_upper: range.tick_upper,
+                amount0_desired: amounts.amount0_desired,
+                amount1_desired: amounts.amount1_desired,
+                amount0_min: amounts.amount0_min,
+                amount1_min: amounts.amount1_min,
+            },
+            payer,
+        );
+        let info = pool_position_info(pool, range);
+        accrue_fees(range, &info);
+        range.liquidity += liquidity.into();
+        liquidities.push(liquidity.into());
+        amount0 += used0;
+        amount1 += used1;
+    }
+
+    save_composite_position(&params.token_id, &position);
+    casper_event_standard::emit(IncreaseLiquidity::new(
+        params.token_id,
+        sum_liquidity(&liquidities),
+        amount0,
+        amount1,
+    ));
+    CompositeIncreaseLiquidityResult {
+        liquidities,
+        amount0,
+        amount1,
+    }
+}
+
+pub fn composite_decrease_liquidity_internal(
+    params: &CompositeDecreaseLiquidityParams,
+) -> (U256, U256) {
+    is_authorized_for_token(&params.token_id);
+    check_deadline(params.deadline);
+    let mut position = read_existing_composite_position(&params.token_id);
+    require(
+        params.liquidities.len() == position.ranges.len(),
+        Error::ErrInvalidCompositeParams,
+    );
+    let total_liquidity = sum_liquidity(&params.liquidities);
+    require(!total_liquidity.is_zero(), Error::ErrInvalidLiquidity);
+
+    let pool = get_pool_address(&read_pool_key(&position.pool_id));
+    let (mut amount0, mut amount1) = (U256::zero(), U256::zero());
+    for (range, liquidity) in position.ranges.iter_mut().zip(params.liquidities.iter()) {
+        if liquidity.is_zero() {
+            continue;
+        }
+        require(range.liquidity >= *liquidity, Error::ErrInvalidLiquidity);
+        let (burned0, burned1) = intf::burn(pool, range.tick_lower, range.tick_upper, *liquidity);
+        let info = pool_position_info(pool, range);
+        accrue_fees(range, &info);
+        range.tokens_owed0 = range
+            .tokens_owed0
+            .overflowing_add(burned0.as_u128().into())
+            .0;
+        range.tokens_owed1 = range
+            .tokens_owed1
+            .overflowing_add(burned1.as_u128().into())
+            .0;
+        range.liquidity -= *liquidity;
+        amount0 += burned0;
+        amount1 += burned1;
+    }
+    require(
+        amount0 >= params.amount0_min && amount1 >= params.amount1_min,
+        Error::ErrPriceSlippageCheck,
+    );
+
+    save_composite_position(&params.token_id, &position);
+    casper_event_standard::emit(DecreaseLiquidity::new(
+        params.token_id,
+        total_liquidity,
+        amount0,
+        amount1,
+    ));
+    (amount0, amount1)
+}
+
+// collects the ranges in order until the requested maximums are reached
+pub fn composite_collect_internal(params: &CollectParams) -> (Key, Key, U256, U256) {
+    is_authorized_for_token(&params.token_id);
+    require(
+        params.amount0_max != U128::zero() && params.amount1_max != U128::zero(),
+        Error::ErrInvalidCollectParams,
+    );
+    let recipient = if params.recipient == null_key() {
+        get_self_key()
+    } else {
+        params.recipient
+    };
+
+    let mut position = read_existing_composite_position(&params.token_id);
+    let pool = get_pool_address(&read_pool_key(&position.pool_id));
+    let (mut remaining0, mut remaining1) = (params.amount0_max, params.amount1_max);
+    let (mut amount0, mut amount1) = (U256::zero(), U256::zero());
+    for range in position.ranges.iter_mut() {
+        if range.liquidity != U128::zero() {
+            intf::burn(pool, range.tick_lower, range.tick_upper, U128::zero());
+            let info = pool_position_info(pool, range);
+            accrue_fees(range, &info);
+        }
+        let collect0 = range.tokens_owed0.min(remaining0);
+        let collect1 = range.tokens_owed1.min(remaining1);
+        if collect0.is_zero() && collect1.is_zero() {
+            continue;
+        }
+        let (collected0, collected1) = intf::collect(
+            pool,
+            recipient,
+            range.tick_lower,
+            range.tick_upper,
+            collect0,
+            collect1,
+        );
+        range.tokens_owed0 -= collect0;
+        range.tokens_owed1 -= collect1;
+        remaining0 -= collect0;
+        remaining1 -= collect1;
+        amount0 += U256::from(collected0.as_u128());
+        amount1 += U256::from(collected1.as_u128());
+    }
+
+    save_composite_position(&params.token_id, &position);
+    casper_event_standard::emit(Collect::new(params.token_id, recipient, amount0, amount1));
+    (position.token0, position.token1, amount0, amount1)
+}
+
+pub fn is_composite_position_cleared(token_id: &U256) -> bool {
+    read_composite_position(token_id)
+        .ranges
+        .iter()
+        .all(|range| {
+            range.liquidity.is_zero()
+                && range.tokens_owed0.is_zero()
+                && range.tokens_owed1.is_zero()
+        })
+}
+
+// principal at the current pool price plus the fees accounted so far, across all ranges
+pub fn composite_totals_internal(token_id: &U256) -> CompositeTotals {
+    let position = read_composite_position(token_id);
+    let mut totals = CompositeTotals::default();
+    if position.ranges.is_empty() {
+        return totals;
+    }
+    let pool = get_pool_address(&read_pool_key(&position.pool_id));
+    let sqrt_price_x96 = intf::get_slot0(pool).sqrt_price_x96;
+    for range in position.ranges.iter() {
+        let (amount0, amount1) = get_amounts_for_liquidity(
+            &sqrt_price_x96,
+            &tickmath::get_sqrt_ratio_at_tick(range.tick_lower),
+            &tickmath::get_sqrt_ratio_at_tick(range.tick_upper),
+            range.liquidity.as_u128(),
+        );
+        totals.liquidity += range.liquidity;
+        totals.amount0 += amount0;
+        totals.amount1 += amount1;
+        totals.tokens_owed0 += range.tokens_owed0;
+        totals.tokens_owed1 += range.tokens_owed1;
+    }
+    totals
+}
@@ -1,11 +1,4 @@
-use casper_event_standard::Schemas;
-use common::router_events::*;
-pub fn event_schemas() -> Schemas {
-    Schemas::new()
-        .with::<IncreaseLiquidity>()
-        .with::<DecreaseLiquidity>()
-        .with::<Collect>()
-}
+pub use common::router_events::event_schemas;
 
 pub fn init_events() {
     casper_event_standard::init(event_schemas());
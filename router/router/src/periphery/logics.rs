@@ -2,12 +2,16 @@ use core::ops::Add;
 
 use super::{
     checks::{check_deadline, is_authorized_for_token},
+    composite::is_composite_position_cleared,
     liquidity_amounts::get_liquidity_for_amounts,
+    payments::{pay, refund},
     pool_key::{get_pool_address, get_pool_key},
     store::{
-        self, cache_pool_key, read_next_id, read_pool_key, read_position, save_factory,
-        save_next_id, save_position,
+        self, cache_pool_key, read_next_id, read_pool_key, read_position, save_composite_position,
+        save_factory, save_next_id, save_position,
     },
+    swap_router::_exact_input_internal,
+    zap::get_zap_swap_amount,
 };
 use crate::NFTToken;
 use crate::{cep47::CEP47, periphery::store::read_factory};
@@ -19,14 +23,16 @@ use common::{
     erc20_helpers,
     error::require,
     intf::{create_pool, initialize_pool_price},
-    router_events::{Collect, DecreaseLiquidity, IncreaseLiquidity},
-    utils::{is_token_sorted, position_key},
+    path::encode_pool,
+    router_events::{Collect, DecreaseLiquidity, IncreaseLiquidity, PositionClosed, Rebalance},
+    utils::{is_token_sorted, position_key, u256_to_u512, unwrap_wcspr},
 };
 use contract_utilities::helpers::{self, get_self_key, null_key};
 use math::{fixed_point_128, fullmath, tickmath};
 use types::{
-    AddLiquidityParams, CollectParams, DecreaseLiquidityParams, IncreaseLiquidityParams,
-    MintCallbackData, MintParams, PoolKey, Position, PositionInfo, Slot0,
+    AddLiquidityParams, CollectParams, CompositePosition, DecreaseLiquidityParams,
+    ExitPositionParams, IncreaseLiquidityParams, MintCallbackData, MintParams, PoolKey, Position,
+    PositionInfo, RebalanceParams, Slot0, SwapCallbackData, ZapMintParams,
 };
 
 pub fn initialize(factory: Key) {
@@ -52,7 +58,7 @@ pub fn mint_callback_internal(amount0_owed: U256, amount1_owed: U256, data: &[u8
     verify_callback2(&decoded.pool_key);
 
     if amount0_owed.gt(&U256::zero()) {
-        erc20_helpers::transfer_from(
+        pay(
             decoded.pool_key.token0,
             decoded.payer,
             helpers::get_immediate_caller_key(),
@@ -60,7 +66,7 @@ pub fn mint_callback_internal(amount0_owed: U256, amount1_owed: U256, data: &[u8
         );
     }
     if amount1_owed.gt(&U256::zero()) {
-        erc20_helpers::transfer_from(
+        pay(
             decoded.pool_key.token1,
             decoded.payer,
             helpers::get_immediate_caller_key(),
@@ -72,29 +78,68 @@ pub fn mint_callback_internal(amount0_owed: U256, amount1_owed: U256, data: &[u8
 pub fn mint_internal(params: &MintParams) -> (U256, u128, U256, U256) {
     check_deadline(params.deadline);
 
-    let (liquidity, amount0, amount1, pool) = add_liquidity_internal(&AddLiquidityParams {
-        token0: params.token0,
-        token1: params.token1,
-        fee: params.fee,
-        recipient: get_self_key(),
-        tick_lower: params.tick_lower,
-        tick_upper: params.tick_upper,
-        amount0_desired: params.amount0_desired,
-        amount1_desired: params.amount1_desired,
-        amount0_min: params.amount0_min,
-        amount1_min: params.amount1_min,
-    });
+    let (liquidity, amount0, amount1, pool) = add_liquidity_internal(
+        &AddLiquidityParams {
+            token0: params.token0,
+            token1: params.token1,
+            fee: params.fee,
+            recipient: get_self_key(),
+            tick_lower: params.tick_lower,
+            tick_upper: params.tick_upper,
+            amount0_desired: params.amount0_desired,
+            amount1_desired: params.amount1_desired,
+            amount0_min: params.amount0_min,
+            amount1_min: params.amount1_min,
+        },
+        helpers::get_immediate_caller_key(),
+    );
 
     // mint an nft
+    let token_id = mint_token(params.recipient);
+    save_new_position(
+        &token_id,
+        pool,
+        &PoolKey {
+            token0: params.token0,
+            token1: params.token1,
+            fee: params.fee,
+        },
+        params.tick_lower,
+        params.tick_upper,
+        liquidity,
+    );
+
+    casper_event_standard::emit(IncreaseLiquidity::new(
+        token_id,
+        liquidity.into(),
+        amount0,
+        amount1,
+    ));
+
+    (token_id, liquidity, amount0, amount1)
+}
+
+pub fn mint_token(recipient: Key) -> U256 {
     let token_id = read_next_id();
     save_next_id(token_id.add(1));
     let token_ids = vec![token_id];
     let mut token_metas: Vec<BTreeMap<String, String>> = vec![];
     token_metas.push(BTreeMap::new());
     NFTToken::default()
-        .mint(params.recipient, token_ids, token_metas)
+        .mint(recipient, token_ids, token_metas)
         .unwrap_or_revert();
-    let position_key = position_key(get_self_key(), params.tick_lower, params.tick_upper);
+    token_id
+}
+
+fn save_new_position(
+    token_id: &U256,
+    pool: Key,
+    pool_key: &PoolKey,
+    tick_lower: i32,
+    tick_upper: i32,
+    liquidity: u128,
+) {
+    let position_key = position_key(get_self_key(), tick_lower, tick_upper);
     let position_info: PositionInfo = runtime::call_versioned_contract(
         pool.into_hash().unwrap().into(),
         None,
@@ -106,43 +151,27 @@ pub fn mint_internal(params: &MintParams) -> (U256, u128, U256, U256) {
     let fee_growth_inside0_last_x128 = position_info.fee_growth_inside0_last_x128;
     let fee_growth_inside1_last_x128 = position_info.fee_growth_inside1_last_x128;
 
-    let pool_id = cache_pool_key(
-        pool,
-        &PoolKey {
-            token0: params.token0,
-            token1: params.token1,
-            fee: params.fee,
-        },
-    );
+    let pool_id = cache_pool_key(pool, pool_key);
 
     save_position(
-        &token_id,
+        token_id,
         &Position {
             pool_id: pool_id,
-            tick_lower: params.tick_lower,
-            tick_upper: params.tick_upper,
+            tick_lower,
+            tick_upper,
             liquidity: liquidity.into(),
             fee_growth_inside0_last_x128,
             fee_growth_inside1_last_x128,
             tokens_owed0: 0.into(),
             tokens_owed1: 0.into(),
-            fee: params.fee,
-            token0: params.token0,
-            token1: params.token1,
+            fee: pool_key.fee,
+            token0: pool_key.token0,
+            token1: pool_key.token1,
         },
     );
-
-    casper_event_standard::emit(IncreaseLiquidity::new(
-        token_id,
-        liquidity.into(),
-        amount0,
-        amount1,
-    ));
-
-    (token_id, liquidity, amount0, amount1)
 }
 
-pub fn add_liquidity_internal(params: &AddLiquidityParams) -> (u128, U256, U256, Key) {
+pub fn add_liquidity_internal(params: &AddLiquidityParams, payer: Key) -> (u128, U256, U256, Key) {
     let pool_key = get_pool_key(params.token0, params.token1, params.fee);
 
     let pool = get_pool_address(&pool_key);
@@ -174,7 +203,7 @@ pub fn add_liquidity_internal(params: &AddLiquidityParams) -> (u128, U256, U256,
             "tick_lower" => params.tick_lower,
             "tick_upper" => params.tick_upper,
             "amount" => U128::from(liquidity),
-            "data" => Bytes::from(helpers::encode_1(&MintCallbackData{ pool_key, payer: helpers::get_immediate_caller_key()}))
+            "data" => Bytes::from(helpers::encode_1(&MintCallbackData{ pool_key, payer }))
         },
     );
 
@@ -190,18 +219,21 @@ pub fn increase_liquidity_internal(params: &IncreaseLiquidityParams) -> (u128, U
     let mut position = read_position(&params.token_id);
     let pool_key = read_pool_key(&position.pool_id);
     let self_key = get_self_key();
-    let (liquidity, amount0, amount1, pool) = add_liquidity_internal(&AddLiquidityParams {
-        token0: pool_key.token0,
-        token1: pool_key.token1,
-        fee: pool_key.fee,
-        recipient: self_key,
-        tick_lower: position.tick_lower,
-        tick_upper: position.tick_upper,
-        amount0_desired: params.amount0_desired,
-        amount1_desired: params.amount1_desired,
-        amount0_min: params.amount0_min,
-        amount1_min: params.amount1_min,
-    });
+    let (liquidity, amount0, amount1, pool) = add_liquidity_internal(
+        &AddLiquidityParams {
+            token0: pool_key.token0,
+            token1: pool_key.token1,
+            fee: pool_key.fee,
+            recipient: self_key,
+            tick_lower: position.tick_lower,
+            tick_upper: position.tick_upper,
+            amount0_desired: params.amount0_desired,
+            amount1_desired: params.amount1_desired,
+            amount0_min: params.amount0_min,
+            amount1_min: params.amount1_min,
+        },
+        helpers::get_immediate_caller_key(),
+    );
 
     let position_key = position_key(self_key, position.tick_lower, position.tick_upper);
     let position_info: PositionInfo = runtime::call_versioned_contract(
@@ -476,8 +508,13 @@ pub fn burn_internal(token_id: U256) {
             && position.tokens_owed1 == U128::zero(),
         common::error::Error::ErrPositionNotCleared,
     );
+    require(
+        is_composite_position_cleared(&token_id),
+        common::error::Error::ErrPositionNotCleared,
+    );
 
     save_position(&token_id, &Position::default());
+    save_composite_position(&token_id, &CompositePosition::default());
 
     NFTToken::default()
         .burn(
@@ -487,6 +524,61 @@ pub fn burn_internal(token_id: U256) {
         .unwrap_or_revert();
 }
 
+// decreases all the liquidity, collects everything to the router, pays the recipient and burns the token
+pub fn exit_position_internal(params: &ExitPositionParams) -> (Key, Key, U256, U256) {
+    is_authorized_for_token(&params.token_id);
+    check_deadline(params.deadline);
+    let recipient = if params.recipient == null_key() {
+        helpers::get_immediate_caller_key()
+    } else {
+        params.recipient
+    };
+    // unwrapped CSPR can only be sent to an account purse
+    require(
+        !params.unwrap_cspr || recipient.into_account().is_some(),
+        common::error::Error::ErrInvalidUnwrapRecipient,
+    );
+
+    let position = read_position(&params.token_id);
+    if position.liquidity > U128::zero() {
+        decrease_liquidity_internal(&DecreaseLiquidityParams {
+            token_id: params.token_id,
+            liquidity: position.liquidity,
+            amount0_min: params.amount0_min,
+            amount1_min: params.amount1_min,
+            deadline: params.deadline,
+        });
+    }
+
+    let (token0, token1, amount0, amount1) = collect_internal(&CollectParams {
+        token_id: params.token_id,
+        recipient: get_self_key(),
+        amount0_max: U128::MAX,
+        amount1_max: U128::MAX,
+    });
+
+    let wcspr = store::read_wcspr();
+    for (token, amount) in [(token0, amount0), (token1, amount1)] {
+        if params.unwrap_cspr && token == wcspr {
+            if amount > U256::zero() {
+                unwrap_wcspr(wcspr, recipient, u256_to_u512(amount));
+            }
+        } else {
+            refund(token, recipient, amount);
+        }
+    }
+
+    burn_internal(params.token_id);
+    casper_event_standard::emit(PositionClosed::new(
+        params.token_id,
+        recipient,
+        amount0,
+        amount1,
+        params.unwrap_cspr,
+    ));
+    (token0, token1, amount0, amount1)
+}
+
 pub fn create_and_initialize_pool_if_necessary_internal(
     token0: Key,
     token1: Key,
@@ -516,3 +608,238 @@ pub fn create_and_initialize_pool_if_necessary_internal(
     }
     pool
 }
+
+pub fn rebalance_internal(params: &RebalanceParams) -> (U256, u128, U256, U256) {
+    is_authorized_for_token(&params.token_id);
+    check_deadline(params.deadline);
+    require(
+        params.reuse_token_id || params.recipient != null_key(),
+        common::error::Error::ErrInvalidRecipient,
+    );
+
+    let self_key = get_self_key();
+    let position = read_position(&params.token_id);
+    if position.liquidity != U128::zero() {
+        decrease_liquidity_internal(&DecreaseLiquidityParams {
+            token_id: params.token_id,
+            liquidity: position.liquidity,
+            amount0_min: params.decrease_amount0_min,
+            amount1_min: params.decrease_amount1_min,
+            deadline: params.deadline,
+        });
+    }
+
+    let (token0, token1, mut amount0, mut amount1) = collect_internal(&CollectParams {
+        token_id: params.token_id,
+        recipient: self_key,
+        amount0_max: U128::MAX,
+        amount1_max: U128::MAX,
+    });
+
+    if !params.amount_in.is_zero() {
+        let (token_in, token_out) = if params.zero_for_one {
+            require(
+                params.amount_in <= amount0,
+                common::error::Error::ErrInvalidRebalanceParams,
+            );
+            (token0, token1)
+        } else {
+            require(
+                params.amount_in <= amount1,
+                common::error::Error::ErrInvalidRebalanceParams,
+            );
+            (token1, token0)
+        };
+        let amount_out = _exact_input_internal(
+            params.amount_in,
+            self_key,
+            params.sqrt_price_limit_x96,
+            &SwapCallbackData {
+                path: encode_pool(token_in, position.fee, token_out),
+                payer: self_key,
+            },
+        );
+        require(
+            amount_out >= params.amount_out_minimum,
+            common::error::Error::ErrTooLittleReceived,
+        );
+        if params.zero_for_one {
+            amount0 -= params.amount_in;
+            amount1 += amount_out;
+        } else {
+            amount1 -= params.amount_in;
+            amount0 += amount_out;
+        }
+    }
+
+    let pool_key = PoolKey {
+        token0,
+        token1,
+        fee: position.fee,
+    };
+    let (liquidity, used0, used1, pool) = add_liquidity_internal(
+        &AddLiquidityParams {
+            token0,
+            token1,
+            fee: position.fee,
+            recipient: self_key,
+            tick_lower: params.tick_lower,
+            tick_upper: params.tick_upper,
+            amount0_desired: amount0,
+            amount1_desired: amount1,
+            amount0_min: params.amount0_min,
+            amount1_min: params.amount1_min,
+        },
+        self_key,
+    );
+
+    let token_id = if params.reuse_token_id {
+        params.token_id
+    } else {
+        burn_internal(params.token_id);
+        mint_token(params.recipient)
+    };
+    save_new_position(
+        &token_id,
+        pool,
+        &pool_key,
+        params.tick_lower,
+        params.tick_upper,
+        liquidity,
+    );
+
+    // whatever could not be added to the new range goes back to the caller
+    let caller = helpers::get_immediate_caller_key();
+    refund(token0, caller, amount0 - used0);
+    refund(token1, caller, amount1 - used1);
+
+    casper_event_standard::emit(IncreaseLiquidity::new(
+        token_id,
+        liquidity.into(),
+        used0,
+        used1,
+    ));
+    casper_event_standard::emit(Rebalance::new(
+        params.token_id,
+        token_id,
+        params.tick_lower,
+        params.tick_upper,
+        liquidity.into(),
+        used0,
+        used1,
+    ));
+
+    (token_id, liquidity, used0, used1)
+}
+
+pub fn zap_mint_internal(params: &ZapMintParams) -> (U256, u128, U256, U256) {
+    check_deadline(params.deadline);
+    require(
+        is_token_sorted(params.token0, params.token1),
+        common::error::Error::ErrInvalidTokenOrder,
+    );
+    require(
+        params.token_in == params.token0 || params.token_in == params.token1,
+        common::error::Error::ErrInvalidZapParams,
+    );
+    let zero_for_one = params.token_in == params.token0;
+    let token_out = if zero_for_one {
+        params.token1
+    } else {
+        params.token0
+    };
+
+    let pool_key = get_pool_key(params.token0, params.token1, params.fee);
+    let pool = get_pool_address(&pool_key);
+    require(pool != null_key(), common::error::Error::ErrPoolNotFound);
+    let slot0: Slot0 = runtime::call_versioned_contract(
+        pool.into_hash().unwrap().into(),
+        None,
+        "get_slot0",
+        runtime_args! {},
+    );
+    require(
+        !slot0.sqrt_price_x96.is_zero(),
+        common::error::Error::ErrPoolNotFound,
+    );
+
+    let self_key = get_self_key();
+    let caller = helpers::get_immediate_caller_key();
+    erc20_helpers::transfer_from(params.token_in, caller, self_key, params.amount_in);
+    let liquidity: U128 = runtime::call_versioned_contract(
+        pool.into_hash().unwrap().into(),
+        None,
+        "get_liquidity",
+        runtime_args! {},
+    );
+
+    let amount_to_swap = get_zap_swap_amount(
+        &slot0.sqrt_price_x96,
+        &tickmath::get_sqrt_ratio_at_tick(params.tick_lower),
+        &tickmath::get_sqrt_ratio_at_tick(params.tick_upper),
+        liquidity.as_u128(),
+        &params.amount_in,
+        zero_for_one,
+        params.fee,
+    );
+    let amount_out = if amount_to_swap.is_zero() {
+        U256::zero()
+    } else {
+        _exact_input_internal(
+            amount_to_swap,
+            self_key,
+            U256::zero(),
+            &SwapCallbackData {
+                path: encode_pool(params.token_in, params.fee, token_out),
+                payer: self_key,
+            },
+        )
+    };
+    require(
+        amount_out >= params.amount_out_minimum,
+        common::error::Error::ErrTooLittleReceived,
+    );
+
+    let (amount0, amount1) = if zero_for_one {
+        (params.amount_in - amount_to_swap, amount_out)
+    } else {
+        (amount_out, params.amount_in - amount_to_swap)
+    };
+    let (liquidity, used0, used1, pool) = add_liquidity_internal(
+        &AddLiquidityParams {
+            token0: params.token0,
+            token1: params.token1,
+            fee: params.fee,
+            recipient: self_key,
+            tick_lower: params.tick_lower,
+            tick_upper: params.tick_upper,
+            amount0_desired: amount0,
+            amount1_desired: amount1,
+            amount0_min: params.amount0_min,
+            amount1_min: params.amount1_min,
+        },
+        self_key,
+    );
+
+    let token_id = mint_token(params.recipient);
+    save_new_position(
+        &token_id,
+        pool,
+        &pool_key,
+        params.tick_lower,
+        params.tick_upper,
+        liquidity,
+    );
+
+    refund(params.token0, caller, amount0 - used0);
+    refund(params.token1, caller, amount1 - used1);
+
+    casper_event_standard::emit(IncreaseLiquidity::new(
+        token_id,
+        liquidity.into(),
+        used0,
+        used1,
+    ));
+
+    (token_id, liquidity, used0, used1)
+}
@@ -1,7 +1,11 @@
 pub mod checks;
+pub mod composite;
 pub mod events;
-pub mod liquidity_amounts;
+pub use math::liquidity_amounts;
 pub mod logics;
+pub mod payments;
 pub mod pool_key;
+pub mod split_merge;
 pub mod store;
 pub mod swap_router;
+pub use math::zap;
@@ -0,0 +1,50 @@
+use casper_types::{Key, U256};
+use common::{
+    erc20_helpers,
+    error::require,
+    utils::{u256_to_u512, unwrap_wcspr},
+};
+use contract_utilities::helpers::{get_immediate_caller_key, get_self_key, null_key};
+
+use super::store::{read_reserved_partner_fees, read_wcspr};
+
+// tokens already held by the router are transferred directly, others are pulled from the payer
+pub fn pay(token: Key, payer: Key, recipient: Key, value: U256) {
+    if payer == get_self_key() {
+        erc20_helpers::transfer(token, recipient, value);
+    } else {
+        erc20_helpers::transfer_from(token, payer, recipient, value);
+    }
+}
+
+pub fn refund(token: Key, recipient: Key, value: U256) {
+    if value > U256::zero() {
+        erc20_helpers::transfer(token, recipient, value);
+    }
+}
+
+// accrued partner fees stay in the router until their partner claims them
+fn sweepable_balance(token: Key) -> U256 {
+    erc20_helpers::get_balance(token, get_self_key())
+        .saturating_sub(read_reserved_partner_fees(&token))
+}
+
+pub fn sweep_token_internal(token: Key, amount_minimum: U256, recipient: Key) -> U256 {
+    let balance = sweepable_balance(token);
+    require(
+        balance >= amount_minimum,
+        common::error::Error::ErrInsufficientToken,
+    );
+    refund(token, recipient, balance);
+    balance
+}
+
+pub fn refund_cspr_internal() -> U256 {
+    let wcspr = read_wcspr();
+    require(wcspr != null_key(), common::error::Error::ErrInvalidWCSPR);
+    let balance = sweepable_balance(wcspr);
+    if balance > U256::zero() {
+        unwrap_wcspr(wcspr, get_immediate_caller_key(), u256_to_u512(balance));
+    }
+    balance
+}
@@ -0,0 +1,159 @@
+use super::{
+    checks::is_authorized_for_token,
+    composite::fees_owed,
+    logics::mint_token,
+    pool_key::get_pool_address,
+    store::{read_pool_key, read_position, save_position},
+};
+use crate::{NFTToken, CEP47};
+use alloc::vec::Vec;
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::{Key, U128, U256};
+use common::{
+    error::{require, Error},
+    intf,
+    router_events::{Merge, Split},
+    utils::position_key,
+};
+use contract_utilities::helpers::{get_self_key, null_key};
+use math::fullmath;
+use types::{Position, PositionInfo};
+
+// credits the fees earned since the last snapshot so that they are not lost when liquidity moves
+fn accrue_fees(position: &mut Position, info: &PositionInfo) {
+    position.tokens_owed0 = position
+        .tokens_owed0
+        .overflowing_add(fees_owed(
+            info.fee_growth_inside0_last_x128,
+            position.fee_growth_inside0_last_x128,
+            position.liquidity,
+        ))
+        .0;
+    position.tokens_owed1 = position
+        .tokens_owed1
+        .overflowing_add(fees_owed(
+            info.fee_growth_inside1_last_x128,
+            position.fee_growth_inside1_last_x128,
+            position.liquidity,
+        ))
+        .0;
+    position.fee_growth_inside0_last_x128 = info.fee_growth_inside0_last_x128;
+    position.fee_growth_inside1_last_x128 = info.fee_growth_inside1_last_x128;
+}
+
+// pokes the pool so that its fee growth is up to date, returns the router's position in the range
+fn poke(pool: Key, position: &Position, liquidity: U128) -> PositionInfo {
+    if !liquidity.is_zero() {
+        intf::burn(pool, position.tick_lower, position.tick_upper, U128::zero());
+    }
+    intf::get_pool_position(
+        pool,
+        position_key(get_self_key(), position.tick_lower, position.tick_upper),
+    )
+}
+
+fn share_of(amount: U128, liquidity: U128, total_liquidity: U128) -> U128 {
+    fullmath::mul_div(
+        &amount.as_u128().into(),
+        &liquidity.as_u128().into(),
+        &total_liquidity.as_u128().into(),
+    )
+    .as_u128()
+    .into()
+}
+
+// moves part of the liquidity and a proportional share of the owed tokens into a new token
+pub fn split_internal(token_id: U256, liquidity: U128) -> U256 {
+    is_authorized_for_token(&token_id);
+    let mut position = read_position(&token_id);
+    require(
+        !liquidity.is_zero() && liquidity < position.liquidity,
+        Error::ErrInvalidPositionSplit,
+    );
+
+    let pool = get_pool_address(&read_pool_key(&position.pool_id));
+    let info = poke(pool, &position, position.liquidity);
+    accrue_fees(&mut position, &info);
+
+    let tokens_owed0 = share_of(position.tokens_owed0, liquidity, position.liquidity);
+    let tokens_owed1 = share_of(position.tokens_owed1, liquidity, position.liquidity);
+    let new_position = Position {
+        liquidity,
+        tokens_owed0,
+        tokens_owed1,
+        ..position.clone()
+    };
+    position.liquidity -= liquidity;
+    position.tokens_owed0 -= tokens_owed0;
+    position.tokens_owed1 -= tokens_owed1;
+    save_position(&token_id, &position);
+
+    let owner = NFTToken::default().owner_of(token_id).unwrap_or_revert();
+    let new_token_id = mint_token(owner);
+    save_position(&new_token_id, &new_position);
+
+    casper_event_standard::emit(Split::new(
+        token_id,
+        new_token_id,
+        liquidity,
+        tokens_owed0,
+        tokens_owed1,
+    ));
+    new_token_id
+}
+
+// folds every other token into the first one, the merged tokens are burned
+pub fn merge_internal(token_ids: &[U256]) -> U256 {
+    require(token_ids.len() >= 2, Error::ErrInvalidMergeParams);
+    let token_id = token_ids[0];
+    let mut position = read_position(&token_id);
+    require(position.token0 != null_key(), Error::ErrInvalidMergeParams);
+
+    let mut others: Vec<Position> = Vec::new();
+    let mut total_liquidity = position.liquidity;
+    for (i, other_id) in token_ids.iter().enumerate() {
+        is_authorized_for_token(other_id);
+        require(
+            !token_ids[..i].contains(other_id),
+            Error::ErrInvalidMergeParams,
+        );
+        if i == 0 {
+            continue;
+        }
+        let other = read_position(other_id);
+        require(
+            other.token0 != null_key()
+                && other.pool_id == position.pool_id
+                && other.tick_lower == position.tick_lower
+                && other.tick_upper == position.tick_upper,
+            Error::ErrInvalidMergeParams,
+        );
+        total_liquidity += other.liquidity;
+        others.push(other);
+    }
+
+    let pool = get_pool_address(&read_pool_key(&position.pool_id));
+    let info = poke(pool, &position, total_liquidity);
+    accrue_fees(&mut position, &info);
+    for other in others.iter_mut() {
+        accrue_fees(other, &info);
+        position.liquidity += other.liquidity;
+        position.tokens_owed0 = position.tokens_owed0.overflowing_add(other.tokens_owed0).0;
+        position.tokens_owed1 = position.tokens_owed1.overflowing_add(other.tokens_owed1).0;
+    }
+    save_position(&token_id, &position);
+
+    let merged_token_ids: Vec<U256> = token_ids[1..].to_vec();
+    for merged_id in merged_token_ids.iter() {
+        save_position(merged_id, &Position::default());
+        NFTToken::default()
+            .burn(
+                NFTToken::default().owner_of(*merged_id).unwrap_or_revert(),
+                vec![*merged_id],
+            )
+            .unwrap_or_revert();
+    }
+
+    casper_event_standard::emit(Merge::new(token_id, merged_token_ids, position.liquidity));
+    token_id
+}
@@ -6,9 +6,9 @@ use casper_contract::{
 use casper_types::{
     CLTyped, CLValue, EntryPoint, EntryPointAccess, EntryPointType, Key, Parameter, U256,
 };
-use common::{error::Error, get_set_dict, get_set_no_set};
+use common::{error::Error, get_set, get_set_dict, get_set_no_set};
 use contract_utilities::helpers::{self, null_key};
-use types::{PoolKey, Position};
+use types::{CompositePosition, PoolKey, Position};
 
 pub const DEFAULT_AMOUNT_IN_CACHED: U256 = U256::MAX;
 pub fn initialize() {
@@ -16,10 +16,17 @@ pub fn initialize() {
     storage::new_dictionary("pool_id_to_pool_key")
         .unwrap_or_revert_with(Error::FailedToCreateDictionary);
     storage::new_dictionary("positions").unwrap_or_revert_with(Error::FailedToCreateDictionary);
+    storage::new_dictionary("composite_positions")
+        .unwrap_or_revert_with(Error::FailedToCreateDictionary);
+    storage::new_dictionary("partner_fees").unwrap_or_revert_with(Error::FailedToCreateDictionary);
+    storage::new_dictionary("reserved_partner_fees")
+        .unwrap_or_revert_with(Error::FailedToCreateDictionary);
     save_next_id(1.into());
     save_next_pool_id(1);
     save_factory(null_key());
+    save_wcspr(null_key());
     save_amount_in_cached(DEFAULT_AMOUNT_IN_CACHED);
+    save_max_partner_fee_bips(0);
 }
 
 get_set_dict!(
@@ -61,6 +68,19 @@ get_set_dict!(
     "get_position"
 );
 
+get_set_dict!(
+    "composite_positions",
+    "token_id",
+    U256,
+    CompositePosition,
+    CompositePosition::default(),
+    save_composite_position,
+    read_composite_position,
+    get_composite_position,
+    get_composite_position_ep,
+    "get_composite_position"
+);
+
 get_set_no_set!(
     next_id,
     "next_id",
@@ -97,6 +117,17 @@ get_set_no_set!(
     "get_factory"
 );
 
+get_set!(
+    wcspr,
+    "wcspr",
+    Key,
+    helpers::null_key(),
+    save_wcspr,
+    read_wcspr,
+    get_wcspr,
+    set_wcspr
+);
+
 get_set_no_set!(
     amount_in_cached,
     "amount_in_cached",
@@ -109,6 +140,49 @@ get_set_no_set!(
     "get_amount_in_cached"
 );
 
+get_set_no_set!(
+    max_partner_fee_bips,
+    "max_partner_fee_bips",
+    u32,
+    0,
+    save_max_partner_fee_bips,
+    read_max_partner_fee_bips,
+    get_max_partner_fee_bips,
+    get_max_partner_fee_bips_ep,
+    "get_max_partner_fee_bips"
+);
+
+get_set_dict!(
+    "partner_fees",
+    "partner_fee_id",
+    String,
+    U256,
+    U256::zero(),
+    save_partner_fees,
+    read_partner_fees,
+    get_partner_fees,
+    get_partner_fees_ep,
+    "get_partner_fees"
+);
+
+// unclaimed partner fees per token, held by the router but not sweepable
+get_set_dict!(
+    "reserved_partner_fees",
+    "token",
+    Key,
+    U256,
+    U256::zero(),
+    save_reserved_partner_fees,
+    read_reserved_partner_fees,
+    get_reserved_partner_fees,
+    get_reserved_partner_fees_ep,
+    "get_reserved_partner_fees"
+);
+
+pub fn compute_partner_fee_id(fee_recipient: &Key, token: &Key) -> String {
+    helpers::encode_key(&helpers::encode_2(fee_recipient, token))
+}
+
 pub fn cache_pool_key(pool: Key, pool_key: &PoolKey) -> u64 {
     let mut pool_id = read_pool_id(&pool);
     if pool_id == 0 {
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::ops::{Add, Sub};
 
 use casper_contract::unwrap_or_revert::UnwrapOrRevert;
@@ -6,26 +7,59 @@ use casper_types::{
     Key, U256,
 };
 use common::{
-    erc20_helpers,
     error::require,
-    intf::swap,
-    path::{decode_first_pool, get_first_pool, has_multiple_pools, skip_token},
+    intf::{get_fee_amount_tick_spacing, swap},
+    owner,
+    path::Path,
+    router_events::{PartnerFeeCharged, PartnerFeesClaimed, SwapExactInput, SwapExactOutput},
     utils::is_token_sorted,
 };
-use contract_utilities::helpers::{self, get_immediate_caller_key, get_self_key, null_key};
+use contract_utilities::helpers::{get_immediate_caller_key, get_self_key, null_key};
 use math::tickmath;
 use types::{
-    i256::I256, ExactInputParams, ExactInputSingleParams, ExactOutputParams,
-    ExactOutputSingleParams, SwapCallbackData,
+    i256::I256, ExactInputParams, ExactInputSingleParams, ExactInputSplitParams, ExactOutputParams,
+    ExactOutputSingleParams, ExactOutputSplitParams, SwapCallbackData,
 };
 
 use super::{
     checks::check_deadline,
     logics::_verify_callback,
+    payments::{pay, refund},
     pool_key::{self, get_pool_address},
-    store::{read_amount_in_cached, save_amount_in_cached, DEFAULT_AMOUNT_IN_CACHED},
+    store::{
+        compute_partner_fee_id, read_amount_in_cached, read_factory, read_max_partner_fee_bips,
+        read_partner_fees, read_reserved_partner_fees, save_amount_in_cached,
+        save_max_partner_fee_bips, save_partner_fees, save_reserved_partner_fees,
+        DEFAULT_AMOUNT_IN_CACHED,
+    },
 };
 
+// every pool of the path must use a fee tier enabled by the factory, which is asked once per
+// distinct tier rather than once per hop
+fn check_path(path: &Path) {
+    path.validate_hops().unwrap_or_revert();
+    let factory = read_factory();
+    let mut enabled: Vec<u32> = path.pools().into_iter().map(|(_, _, fee)| fee).collect();
+    enabled.sort_unstable();
+    enabled.dedup();
+    enabled.retain(|fee| get_fee_amount_tick_spacing(factory, *fee) != 0);
+    path.validate(|fee| enabled.binary_search(&fee).is_ok())
+        .unwrap_or_revert();
+}
+
+fn decode_path(path: &[u8]) -> Path {
+    let path = Path::decode(path).unwrap_or_revert();
+    check_path(&path);
+    path
+}
+
+// an enabled fee tier can still have no pool for the pair
+fn get_path_pool(token_in: Key, token_out: Key, fee: u32) -> Key {
+    let pool = get_pool_address(&pool_key::get_pool_key(token_in, token_out, fee));
+    require(pool != null_key(), common::error::Error::ErrInvalidPath);
+    pool
+}
+
 pub fn swap_callback_internal(amount0_delta: I256, amount1_delta: I256, _data: &[u8]) {
     require(
         amount0_delta.0 > 0 || amount1_delta.0 > 0,
@@ -35,7 +69,9 @@ pub fn swap_callback_internal(amount0_delta: I256, amount1_delta: I256, _data: &
         .unwrap_or_revert_with(common::error::Error::ErrInvalidSwapCallbackParams)
         .0;
 
-    let (mut token_in, token_out, fee) = decode_first_pool(&data.path);
+    let path = Path::decode(&data.path)
+        .unwrap_or_revert_with(common::error::Error::ErrInvalidSwapCallbackParams);
+    let (mut token_in, token_out, fee) = path.first_pool();
 
     _verify_callback(token_in, token_out, fee);
 
@@ -52,21 +88,25 @@ pub fn swap_callback_internal(amount0_delta: I256, amount1_delta: I256, _data: &
     };
 
     if is_exact_input {
-        erc20_helpers::transfer_from(
+        pay(
             token_in,
             data.payer,
             get_immediate_caller_key(),
             amount_to_pay,
         );
     } else {
-        if has_multiple_pools(&data.path) {
-            data.path = skip_token(&data.path);
-
-            // call exact output internal
+        if path.num_pools() > 1 {
+            data.path = path.skip_token().encode().unwrap_or_revert();
+            _exact_output_internal(
+                amount_to_pay,
+                get_immediate_caller_key(),
+                U256::zero(),
+                &data,
+            );
         } else {
             save_amount_in_cached(amount_to_pay);
             token_in = token_out;
-            erc20_helpers::transfer_from(
+            pay(
                 token_in,
                 data.payer,
                 get_immediate_caller_key(),
@@ -76,6 +116,88 @@ pub fn swap_callback_internal(amount0_delta: I256, amount1_delta: I256, _data: &
     }
 }
 
+const BIPS: u32 = 10000;
+
+// a partner fee applies when a recipient and non zero bips are given, within the owner set maximum
+fn check_partner_fee(fee_recipient: Key, fee_bips: u32) -> bool {
+    if fee_recipient == null_key() || fee_bips == 0 {
+        return false;
+    }
+    require(
+        fee_bips <= read_max_partner_fee_bips(),
+        common::error::Error::ErrPartnerFeeTooHigh,
+    );
+    true
+}
+
+fn credit_partner_fee(fee_recipient: Key, token: Key, amount: U256) {
+    if amount.is_zero() {
+        return;
+    }
+    let fee_id = compute_partner_fee_id(&fee_recipient, &token);
+    save_partner_fees(&fee_id, &(read_partner_fees(&fee_id) + amount));
+    save_reserved_partner_fees(&token, &(read_reserved_partner_fees(&token) + amount));
+    casper_event_standard::emit(PartnerFeeCharged::new(fee_recipient, token, amount));
+}
+
+// the swap output was received by the router, keeps the fee and forwards the rest
+fn take_output_fee(
+    token_out: Key,
+    amount_out: U256,
+    recipient: Key,
+    fee_recipient: Key,
+    fee_bips: u32,
+) -> U256 {
+    let fee = amount_out * fee_bips / BIPS;
+    credit_partner_fee(fee_recipient, token_out, fee);
+    let amount_out = amount_out - fee;
+    if recipient != null_key() {
+        refund(token_out, recipient, amount_out);
+    }
+    amount_out
+}
+
+// pulls the fee on top of the swap input from the payer
+fn take_input_fee(
+    token_in: Key,
+    amount_in: U256,
+    payer: Key,
+    fee_recipient: Key,
+    fee_bips: u32,
+) -> U256 {
+    let fee = amount_in * fee_bips / BIPS;
+    if !fee.is_zero() {
+        pay(token_in, payer, get_self_key(), fee);
+        credit_partner_fee(fee_recipient, token_in, fee);
+    }
+    amount_in + fee
+}
+
+pub fn set_max_partner_fee_bips_internal(max_partner_fee_bips: u32) {
+    owner::only_owner();
+    require(
+        max_partner_fee_bips <= BIPS,
+        common::error::Error::ErrPartnerFeeTooHigh,
+    );
+    save_max_partner_fee_bips(max_partner_fee_bips);
+}
+
+pub fn claim_partner_fees_internal(token: Key, recipient: Key) -> U256 {
+    let fee_recipient = get_immediate_caller_key();
+    let fee_id = compute_partner_fee_id(&fee_recipient, &token);
+    let amount = read_partner_fees(&fee_id);
+    save_partner_fees(&fee_id, &U256::zero());
+    save_reserved_partner_fees(&token, &(read_reserved_partner_fees(&token) - amount));
+    refund(token, recipient, amount);
+    casper_event_standard::emit(PartnerFeesClaimed::new(
+        fee_recipient,
+        token,
+        recipient,
+        amount,
+    ));
+    amount
+}
+
 pub fn _exact_input_internal(
     amount_in: U256,
     recipient: Key,
@@ -88,11 +210,9 @@ pub fn _exact_input_internal(
         recipient
     };
 
-    let (token_in, token_out, fee) = decode_first_pool(&data.path);
+    let (token_in, token_out, fee) = Path::decode(&data.path).unwrap_or_revert().first_pool();
     let zero_for_one = is_token_sorted(token_in, token_out);
-
-    let pool_key = pool_key::get_pool_key(token_in, token_out, fee);
-    let pool = get_pool_address(&pool_key);
+    let pool = get_path_pool(token_in, token_out, fee);
 
     let (amount0, amount1) = swap(
         pool,
@@ -122,50 +242,146 @@ pub fn _exact_input_internal(
 pub fn exact_input_single_internal(params: &ExactInputSingleParams) -> U256 {
     check_deadline(params.deadline);
 
+    let path = Path::new(params.token_in).hop(params.fee, params.token_out);
+    check_path(&path);
+    let charges_fee = check_partner_fee(params.fee_recipient, params.fee_bips);
+    let payer = get_immediate_caller_key();
     let amount_out = _exact_input_internal(
         params.amount_in,
-        params.recipient,
+        if charges_fee {
+            get_self_key()
+        } else {
+            params.recipient
+        },
         params.sqrt_price_limit_x96,
         &SwapCallbackData {
-            path: helpers::encode_3(&params.token_out, &params.fee, &params.token_in).into(),
-            payer: get_immediate_caller_key(),
+            path: path.encode().unwrap_or_revert(),
+            payer,
         },
     );
+    let amount_out = if charges_fee {
+        take_output_fee(
+            params.token_out,
+            amount_out,
+            params.recipient,
+            params.fee_recipient,
+            params.fee_bips,
+        )
+    } else {
+        amount_out
+    };
     require(
         amount_out.ge(&params.amount_out_minimum),
         common::error::Error::ErrTooLittleReceived,
     );
+    casper_event_standard::emit(SwapExactInput::new(
+        payer,
+        params.recipient,
+        path.encode().unwrap_or_revert(),
+        params.amount_in,
+        amount_out,
+        params.fee_recipient,
+    ));
     amount_out
 }
 
 pub fn exact_input_internal(params: &ExactInputParams) -> U256 {
     check_deadline(params.deadline);
-    let mut payer = get_immediate_caller_key();
-    let mut params = params.clone();
-    let amount_out;
-    loop {
-        let has_multiple_pools_ = has_multiple_pools(&params.path);
-        params.amount_in = _exact_input_internal(
-            params.amount_in,
-            if has_multiple_pools_ {
+    let path = decode_path(&params.path);
+    let charges_fee = check_partner_fee(params.fee_recipient, params.fee_bips);
+    let payer = get_immediate_caller_key();
+    let amount_out = exact_input_path(
+        params.amount_in,
+        &path,
+        if charges_fee {
+            get_self_key()
+        } else {
+            params.recipient
+        },
+        payer,
+    );
+    let amount_out = if charges_fee {
+        take_output_fee(
+            path.last_token(),
+            amount_out,
+            params.recipient,
+            params.fee_recipient,
+            params.fee_bips,
+        )
+    } else {
+        amount_out
+    };
+    require(
+        amount_out.ge(&params.amount_out_minimum),
+        common::error::Error::ErrTooLittleReceived,
+    );
+    casper_event_standard::emit(SwapExactInput::new(
+        payer,
+        params.recipient,
+        params.path.clone(),
+        params.amount_in,
+        amount_out,
+        params.fee_recipient,
+    ));
+    amount_out
+}
+
+// every pool but the last pays its output to the router, which pays the next one
+fn exact_input_path(amount_in: U256, path: &Path, recipient: Key, payer: Key) -> U256 {
+    let mut payer = payer;
+    let mut amount_in = amount_in;
+    let pools = path.pools();
+    for (i, (token_in, token_out, fee)) in pools.iter().enumerate() {
+        amount_in = _exact_input_internal(
+            amount_in,
+            if i + 1 < pools.len() {
                 get_self_key()
             } else {
-                params.recipient
+                recipient
             },
             0.into(),
             &SwapCallbackData {
-                path: get_first_pool(&params.path),
+                path: Path::new(*token_in)
+                    .hop(*fee, *token_out)
+                    .encode()
+                    .unwrap_or_revert(),
                 payer,
             },
         );
+        payer = get_self_key();
+    }
+    amount_in
+}
 
-        if has_multiple_pools_ {
-            payer = get_self_key();
-            params.path = skip_token(&params.path);
-        } else {
-            amount_out = params.amount_in;
-            break;
-        }
+pub fn exact_input_split_internal(params: &ExactInputSplitParams) -> U256 {
+    check_deadline(params.deadline);
+    require(
+        !params.legs.is_empty(),
+        common::error::Error::ErrInvalidSplitParams,
+    );
+    let payer = get_immediate_caller_key();
+    let paths: Vec<Path> = params
+        .legs
+        .iter()
+        .map(|leg| decode_path(&leg.path))
+        .collect();
+    let (token_in, token_out) = (paths[0].first_token(), paths[0].last_token());
+    let mut amount_out = U256::zero();
+    for (leg, path) in params.legs.iter().zip(paths.iter()) {
+        require(
+            path.first_token() == token_in && path.last_token() == token_out,
+            common::error::Error::ErrInvalidSplitParams,
+        );
+        let leg_amount_out = exact_input_path(leg.amount_in, path, params.recipient, payer);
+        casper_event_standard::emit(SwapExactInput::new(
+            payer,
+            params.recipient,
+            leg.path.clone(),
+            leg.amount_in,
+            leg_amount_out,
+            null_key(),
+        ));
+        amount_out += leg_amount_out;
     }
     require(
         amount_out.ge(&params.amount_out_minimum),
@@ -186,11 +402,9 @@ pub fn _exact_output_internal(
         recipient
     };
 
-    let (token_in, token_out, fee) = decode_first_pool(&data.path);
+    let (token_in, token_out, fee) = Path::decode(&data.path).unwrap_or_revert().first_pool();
     let zero_for_one = is_token_sorted(token_in, token_out);
-
-    let pool_key = pool_key::get_pool_key(token_in, token_out, fee);
-    let pool = get_pool_address(&pool_key);
+    let pool = get_path_pool(token_in, token_out, fee);
 
     let (amount0_delta, amount1_delta) = swap(
         pool,
@@ -226,41 +440,131 @@ pub fn _exact_output_internal(
 
 pub fn exact_output_single_internal(params: &ExactOutputSingleParams) -> U256 {
     check_deadline(params.deadline);
+    let path = Path::new(params.token_in)
+        .hop(params.fee, params.token_out)
+        .reverse();
+    check_path(&path);
 
+    let payer = get_immediate_caller_key();
     let amount_in = _exact_output_internal(
         params.amount_out,
         params.recipient,
         params.sqrt_price_limit_x96,
         &SwapCallbackData {
-            path: helpers::encode_3(&params.token_out, &params.fee, &params.token_in).into(),
-            payer: get_immediate_caller_key(),
+            path: path.encode().unwrap_or_revert(),
+            payer,
         },
     );
+    save_amount_in_cached(DEFAULT_AMOUNT_IN_CACHED);
+    let amount_in = if check_partner_fee(params.fee_recipient, params.fee_bips) {
+        take_input_fee(
+            params.token_in,
+            amount_in,
+            payer,
+            params.fee_recipient,
+            params.fee_bips,
+        )
+    } else {
+        amount_in
+    };
     require(
         amount_in.le(&params.amount_in_maximum),
         common::error::Error::ErrTooMuchRequested,
     );
-    save_amount_in_cached(DEFAULT_AMOUNT_IN_CACHED);
+    casper_event_standard::emit(SwapExactOutput::new(
+        payer,
+        params.recipient,
+        path.encode().unwrap_or_revert(),
+        amount_in,
+        params.amount_out,
+        params.fee_recipient,
+    ));
     amount_in
 }
 
 pub fn exact_output_internal(params: &ExactOutputParams) -> U256 {
     check_deadline(params.deadline);
-    _exact_output_internal(
-        params.amount_out,
+    let path = decode_path(&params.path);
+    let payer = get_immediate_caller_key();
+    let amount_in = exact_output_path(params.amount_out, &path, params.recipient, payer);
+    // exact output paths are encoded in reverse, the input token comes last
+    let amount_in = if check_partner_fee(params.fee_recipient, params.fee_bips) {
+        take_input_fee(
+            path.last_token(),
+            amount_in,
+            payer,
+            params.fee_recipient,
+            params.fee_bips,
+        )
+    } else {
+        amount_in
+    };
+    require(
+        amount_in.le(&params.amount_in_maximum),
+        common::error::Error::ErrTooMuchRequested,
+    );
+    casper_event_standard::emit(SwapExactOutput::new(
+        payer,
         params.recipient,
+        params.path.clone(),
+        amount_in,
+        params.amount_out,
+        params.fee_recipient,
+    ));
+    amount_in
+}
+
+// the callbacks walk the path from the output pool back to the input one
+fn exact_output_path(amount_out: U256, path: &Path, recipient: Key, payer: Key) -> U256 {
+    _exact_output_internal(
+        amount_out,
+        recipient,
         U256::zero(),
         &SwapCallbackData {
-            path: params.path.clone(),
-            payer: get_immediate_caller_key(),
+            path: path.encode().unwrap_or_revert(),
+            payer,
         },
     );
 
     let amount_in = read_amount_in_cached();
+    save_amount_in_cached(DEFAULT_AMOUNT_IN_CACHED);
+    amount_in
+}
+
+pub fn exact_output_split_internal(params: &ExactOutputSplitParams) -> U256 {
+    check_deadline(params.deadline);
+    require(
+        !params.legs.is_empty(),
+        common::error::Error::ErrInvalidSplitParams,
+    );
+    let payer = get_immediate_caller_key();
+    // exact output paths are encoded in reverse, from token_out to token_in
+    let paths: Vec<Path> = params
+        .legs
+        .iter()
+        .map(|leg| decode_path(&leg.path))
+        .collect();
+    let (token_out, token_in) = (paths[0].first_token(), paths[0].last_token());
+    let mut amount_in = U256::zero();
+    for (leg, path) in params.legs.iter().zip(paths.iter()) {
+        require(
+            path.first_token() == token_out && path.last_token() == token_in,
+            common::error::Error::ErrInvalidSplitParams,
+        );
+        let leg_amount_in = exact_output_path(leg.amount_out, path, params.recipient, payer);
+        casper_event_standard::emit(SwapExactOutput::new(
+            payer,
+            params.recipient,
+            leg.path.clone(),
+            leg_amount_in,
+            leg.amount_out,
+            null_key(),
+        ));
+        amount_in += leg_amount_in;
+    }
     require(
         amount_in.le(&params.amount_in_maximum),
         common::error::Error::ErrTooMuchRequested,
     );
-    save_amount_in_cached(DEFAULT_AMOUNT_IN_CACHED);
     amount_in
 }
@@ -0,0 +1,118 @@
+// decoders for the `stored_value` of `query_global_state` and the results of view calls
+use casper_types::{
+    bytesrepr::{Bytes, FromBytes},
+    CLTyped, CLValue,
+};
+use types::{
+    session::SessionResult, CompositePosition, CompositeTotals, Observation, Position,
+    PositionInfo, Slot0, TickInfo,
+};
+
+use crate::error::SdkError;
+
+// a `CLValue` as rendered by the node, `{"cl_type": .., "bytes": .., "parsed": ..}`
+pub fn cl_value<T: CLTyped + FromBytes>(json: &str) -> Result<T, SdkError> {
+    let value: CLValue = serde_json::from_str(json)?;
+    Ok(value.into_t()?)
+}
+
+// values stored as raw `Bytes`, like the `result` of the get session or a `session_result`
+pub fn stored_bytes<T: FromBytes>(json: &str) -> Result<T, SdkError> {
+    let bytes: Bytes = cl_value(json)?;
+    let (value, remainder) = T::from_bytes(&bytes)?;
+    if !remainder.is_empty() {
+        return Err(SdkError::Bytes(
+            casper_types::bytesrepr::Error::LeftOverBytes,
+        ));
+    }
+    Ok(value)
+}
+
+pub fn slot0(json: &str) -> Result<Slot0, SdkError> {
+    cl_value(json)
+}
+
+pub fn tick_info(json: &str) -> Result<TickInfo, SdkError> {
+    cl_value(json)
+}
+
+pub fn position_info(json: &str) -> Result<PositionInfo, SdkError> {
+    cl_value(json)
+}
+
+pub fn observation(json: &str) -> Result<Observation, SdkError> {
+    cl_value(json)
+}
+
+pub fn position(json: &str) -> Result<Position, SdkError> {
+    cl_value(json)
+}
+
+pub fn composite_position(json: &str) -> Result<CompositePosition, SdkError> {
+    cl_value(json)
+}
+
+pub fn composite_totals(json: &str) -> Result<CompositeTotals, SdkError> {
+    cl_value(json)
+}
+
+pub fn session_result(json: &str) -> Result<SessionResult, SdkError> {
+    stored_bytes(json)
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{
+        bytesrepr::{Bytes, ToBytes},
+        CLValue, U128, U256,
+    };
+    use types::{
+        i128::I128,
+        session::{SessionResult, SessionStepResult},
+        Slot0, TickInfo,
+    };
+
+    use super::{session_result, slot0, tick_info};
+
+    fn json(value: CLValue) -> String {
+        serde_json::to_string(&value).unwrap()
+    }
+
+    #[test]
+    fn test_decodes_stored_values() {
+        let value = Slot0 {
+            sqrt_price_x96: U256::from(2).pow(96.into()),
+            tick: -5,
+            observation_index: 0,
+            observation_cardinality: 1,
+            observation_cardinality_next: 1,
+            fee_protocol: 0,
+        };
+        let decoded = slot0(&json(CLValue::from_t(value).unwrap())).unwrap();
+        assert_eq!(decoded.tick, -5);
+        assert_eq!(decoded.sqrt_price_x96, U256::from(2).pow(96.into()));
+
+        let tick = TickInfo {
+            liquidity_gross: U128::from(10),
+            liquidity_net: I128(-10),
+            ..Default::default()
+        };
+        let decoded = tick_info(&json(CLValue::from_t(tick).unwrap())).unwrap();
+        assert_eq!(decoded.liquidity_net.0, -10);
+    }
+
+    #[test]
+    fn test_decodes_session_results() {
+        let mut step = SessionStepResult::new(1);
+        step.token_id = U256::from(7);
+        let result = SessionResult { steps: vec![step] };
+        let stored = Bytes::from(result.to_bytes().unwrap());
+        let decoded = session_result(&json(CLValue::from_t(stored).unwrap())).unwrap();
+        assert_eq!(decoded, result);
+    }
+
+    #[test]
+    fn test_rejects_values_of_another_type() {
+        assert!(slot0(&json(CLValue::from_t(5u32).unwrap())).is_err());
+    }
+}
@@ -0,0 +1,126 @@
+use casper_types::{
+    ContractPackageHash, Deploy, DeployBuilder, ExecutableDeployItem, PublicKey, RuntimeArgs,
+    TimeDiff, Timestamp, U512,
+};
+
+use crate::error::SdkError;
+
+// everything a deploy needs besides its session, the deploy is left unsigned for the wallet
+#[derive(Clone, Debug)]
+pub struct DeployConfig {
+    pub chain_name: String,
+    pub account: PublicKey,
+    pub payment_amount: U512,
+    pub timestamp: Option<Timestamp>,
+    pub ttl: Option<TimeDiff>,
+}
+
+impl DeployConfig {
+    pub fn new(chain_name: &str, account: PublicKey, payment_amount: U512) -> Self {
+        Self {
+            chain_name: chain_name.to_string(),
+            account,
+            payment_amount,
+            timestamp: None,
+            ttl: None,
+        }
+    }
+
+    pub fn build(&self, session: ExecutableDeployItem) -> Result<Deploy, SdkError> {
+        let mut builder = DeployBuilder::new_unsigned_with_public_key(
+            self.chain_name.clone(),
+            session,
+            self.account.clone(),
+        )
+        .with_standard_payment(self.payment_amount);
+        if let Some(timestamp) = self.timestamp {
+            builder = builder.with_timestamp(timestamp);
+        }
+        if let Some(ttl) = self.ttl {
+            builder = builder.with_ttl(ttl);
+        }
+        Ok(builder.build()?)
+    }
+
+    // the json accepted by `account_put_deploy` once signed
+    pub fn build_json(&self, session: ExecutableDeployItem) -> Result<String, SdkError> {
+        Ok(serde_json::to_string_pretty(&self.build(session)?)?)
+    }
+}
+
+// calls the latest version of a contract package
+pub fn contract_call(
+    package_hash: ContractPackageHash,
+    entry_point: &str,
+    args: RuntimeArgs,
+) -> ExecutableDeployItem {
+    ExecutableDeployItem::StoredVersionedContractByHash {
+        hash: package_hash,
+        version: None,
+        entry_point: entry_point.to_string(),
+        args,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{
+        runtime_args, ContractPackageHash, ExecutableDeployItem, PublicKey, RuntimeArgs, SecretKey,
+        Timestamp, U512,
+    };
+
+    use super::{contract_call, DeployConfig};
+
+    fn config() -> DeployConfig {
+        let secret_key = SecretKey::ed25519_from_bytes([7u8; 32]).unwrap();
+        let mut config = DeployConfig::new(
+            "casper-test",
+            PublicKey::from(&secret_key),
+            U512::from(5_000_000_000_u64),
+        );
+        config.timestamp = Some(Timestamp::from(1_700_000_000_000));
+        config
+    }
+
+    #[test]
+    fn test_builds_an_unsigned_deploy() {
+        let session = contract_call(
+            ContractPackageHash::new([1u8; 32]),
+            "set_wcspr",
+            runtime_args! {},
+        );
+        let deploy = config().build(session).unwrap();
+        assert!(deploy.approvals().is_empty());
+        assert_eq!(deploy.header().chain_name(), "casper-test");
+        assert_eq!(
+            deploy.header().timestamp(),
+            Timestamp::from(1_700_000_000_000)
+        );
+        match deploy.session() {
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash, entry_point, ..
+            } => {
+                assert_eq!(*hash, ContractPackageHash::new([1u8; 32]));
+                assert_eq!(entry_point, "set_wcspr");
+            }
+            _ => panic!("expected a stored versioned contract call"),
+        }
+    }
+
+    #[test]
+    fn test_json_is_stable() {
+        let session = contract_call(
+            ContractPackageHash::new([1u8; 32]),
+            "refund_cspr",
+            runtime_args! {},
+        );
+        let json = config().build_json(session.clone()).unwrap();
+        assert_eq!(json, config().build_json(session).unwrap());
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["header"]["chain_name"], "casper-test");
+        assert_eq!(
+            value["session"]["StoredVersionedContractByHash"]["entry_point"],
+            "refund_cspr"
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt;
+
+use casper_types::{bytesrepr, CLValueError, DeployBuilderError};
+
+#[derive(Debug)]
+pub enum SdkError {
+    Deploy(DeployBuilderError),
+    Bytes(bytesrepr::Error),
+    CLValue(CLValueError),
+    Json(serde_json::Error),
+    InvalidPath,
+    PriceOutOfRange,
+    UnknownEvent(String),
+    UnexpectedEvent { expected: String, found: String },
+    SchemaMismatch(String),
+}
+
+impl fmt::Display for SdkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SdkError::Deploy(error) => write!(f, "failed to build the deploy: {}", error),
+            SdkError::Bytes(error) => write!(f, "failed to decode the bytes: {}", error),
+            SdkError::CLValue(error) => write!(f, "unexpected value: {}", error),
+            SdkError::Json(error) => write!(f, "invalid json: {}", error),
+            SdkError::InvalidPath => write!(f, "invalid swap path"),
+            SdkError::PriceOutOfRange => write!(f, "price is outside of the tick range"),
+            SdkError::UnknownEvent(name) => write!(f, "unknown event: {}", name),
+            SdkError::UnexpectedEvent { expected, found } => {
+                write!(f, "expected a {} event, found {}", expected, found)
+            }
+            SdkError::SchemaMismatch(name) => {
+                write!(
+                    f,
+                    "{} does not match the schema registered by the contract",
+                    name
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SdkError {}
+
+impl From<DeployBuilderError> for SdkError {
+    fn from(error: DeployBuilderError) -> Self {
+        SdkError::Deploy(error)
+    }
+}
+
+impl From<bytesrepr::Error> for SdkError {
+    fn from(error: bytesrepr::Error) -> Self {
+        SdkError::Bytes(error)
+    }
+}
+
+impl From<CLValueError> for SdkError {
+    fn from(error: CLValueError) -> Self {
+        SdkError::CLValue(error)
+    }
+}
+
+impl From<serde_json::Error> for SdkError {
+    fn from(error: serde_json::Error) -> Self {
+        SdkError::Json(error)
+    }
+}
@@ -0,0 +1,271 @@
+// CES events as stored in the `__events` dictionary, the bytes start with the `event_<Name>`
+// string. The pool and the router both emit a `Collect`, so each contract has its own enum
+use std::marker::PhantomData;
+
+use casper_types::bytesrepr::{self, FromBytes};
+use common::{pool_events, router_events};
+use serde::Serialize;
+
+use crate::error::SdkError;
+
+pub use casper_event_standard::{EventInstance, Schemas};
+
+const EVENT_PREFIX: &str = "event_";
+
+fn unprefixed(name: &str) -> &str {
+    name.trim_start_matches(EVENT_PREFIX)
+}
+
+pub fn event_name(bytes: &[u8]) -> Result<String, SdkError> {
+    let (name, _) = String::from_bytes(bytes)?;
+    Ok(unprefixed(&name).to_string())
+}
+
+// checks `T` against the schema registered under its name, either the one the contract is built
+// with or the `__events_schema` named key of a deployed contract
+pub fn validate<T: EventInstance>(schemas: &Schemas) -> Result<(), SdkError> {
+    match schemas.0.get(&T::name()) {
+        Some(schema) if *schema == T::schema() => Ok(()),
+        Some(_) => Err(SdkError::SchemaMismatch(T::name())),
+        None => Err(SdkError::UnknownEvent(T::name())),
+    }
+}
+
+pub fn decode_event<T: EventInstance + FromBytes>(
+    schemas: &Schemas,
+    bytes: &[u8],
+) -> Result<T, SdkError> {
+    validate::<T>(schemas)?;
+    let found = event_name(bytes)?;
+    if found != unprefixed(&T::name()) {
+        return Err(SdkError::UnexpectedEvent {
+            expected: T::name(),
+            found,
+        });
+    }
+    let (event, remainder) = T::from_bytes(bytes)?;
+    if !remainder.is_empty() {
+        return Err(SdkError::Bytes(bytesrepr::Error::LeftOverBytes));
+    }
+    Ok(event)
+}
+
+// all the events emitted by one contract
+pub trait ContractEvent: Sized {
+    fn schemas() -> Schemas;
+
+    fn decode(schemas: &Schemas, bytes: &[u8]) -> Result<Self, SdkError>;
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, SdkError> {
+        Self::decode(&Self::schemas(), bytes)
+    }
+}
+
+macro_rules! contract_events {
+    ($name:ident, $module:ident, [$($event:ident),* $(,)?]) => {
+        #[derive(Debug, PartialEq, Eq, Serialize)]
+        pub enum $name {
+            $($event($module::$event),)*
+        }
+
+        impl $name {
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $($name::$event(_) => stringify!($event),)*
+                }
+            }
+        }
+
+        impl ContractEvent for $name {
+            fn schemas() -> Schemas {
+                $module::event_schemas()
+            }
+
+            fn decode(schemas: &Schemas, bytes: &[u8]) -> Result<Self, SdkError> {
+                match event_name(bytes)?.as_str() {
+                    $(stringify!($event) => Ok($name::$event(decode_event(schemas, bytes)?)),)*
+                    other => Err(SdkError::UnknownEvent(other.to_string())),
+                }
+            }
+        }
+    };
+}
+
+contract_events!(
+    PoolEvent,
+    pool_events,
+    [
+        Initialize,
+        Mint,
+        Collect,
+        Burn,
+        Swap,
+        Flash,
+        IncreaseObservationCardinalityNext,
+        SetFeeProtocol,
+        CollectProtocol,
+        PoolCreated,
+        SnapshotCumulativesInside,
+    ]
+);
+
+contract_events!(
+    RouterEvent,
+    router_events,
+    [
+        IncreaseLiquidity,
+        DecreaseLiquidity,
+        Collect,
+        Rebalance,
+        Split,
+        Merge,
+        PositionClosed,
+        SwapExactInput,
+        SwapExactOutput,
+        PartnerFeeCharged,
+        PartnerFeesClaimed,
+    ]
+);
+
+pub struct EventDecoder<E> {
+    schemas: Schemas,
+    events: PhantomData<E>,
+}
+
+impl<E: ContractEvent> EventDecoder<E> {
+    pub fn new() -> Self {
+        Self::with_schemas(E::schemas())
+    }
+
+    pub fn with_schemas(schemas: Schemas) -> Self {
+        Self {
+            schemas,
+            events: PhantomData,
+        }
+    }
+
+    pub fn decode(&self, bytes: &[u8]) -> Result<E, SdkError> {
+        E::decode(&self.schemas, bytes)
+    }
+
+    // decodes entries of the events dictionary, given in order
+    pub fn decode_all<'a, I>(&'a self, events: I) -> impl Iterator<Item = Result<E, SdkError>> + 'a
+    where
+        I: IntoIterator,
+        I::IntoIter: 'a,
+        I::Item: AsRef<[u8]>,
+    {
+        events
+            .into_iter()
+            .map(move |bytes| self.decode(bytes.as_ref()))
+    }
+}
+
+impl<E: ContractEvent> Default for EventDecoder<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{
+        bytesrepr::{Bytes, ToBytes},
+        Key, U256,
+    };
+    use common::pool_events::{self, Initialize};
+
+    use super::{
+        decode_event, event_name, ContractEvent, EventDecoder, EventInstance, PoolEvent,
+        RouterEvent, Schemas,
+    };
+    use crate::error::SdkError;
+
+    fn initialize() -> Initialize {
+        Initialize {
+            sqrt_price_x96: U256::one() << 96,
+            tick: 0,
+            timestamp: 1,
+        }
+    }
+
+    #[test]
+    fn test_decodes_by_event_name() {
+        let bytes = initialize().to_bytes().unwrap();
+        assert_eq!(event_name(&bytes).unwrap(), "Initialize");
+        let event = PoolEvent::from_bytes(&bytes).unwrap();
+        assert_eq!(event.name(), "Initialize");
+        assert_eq!(event, PoolEvent::Initialize(initialize()));
+    }
+
+    #[test]
+    fn test_decodes_typed_events() {
+        let bytes = initialize().to_bytes().unwrap();
+        let schemas = pool_events::event_schemas();
+        let event: Initialize = decode_event(&schemas, &bytes).unwrap();
+        assert_eq!(event, initialize());
+        assert!(matches!(
+            decode_event::<pool_events::Swap>(&schemas, &bytes),
+            Err(SdkError::UnexpectedEvent { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validates_against_the_registered_schemas() {
+        let bytes = initialize().to_bytes().unwrap();
+        let unregistered = EventDecoder::<PoolEvent>::with_schemas(Schemas::new());
+        assert!(matches!(
+            unregistered.decode(&bytes),
+            Err(SdkError::UnknownEvent(_))
+        ));
+        let mut schemas = pool_events::event_schemas();
+        let swap = pool_events::Swap::schema();
+        for schema in schemas.0.values_mut() {
+            *schema = swap.clone();
+        }
+        let mismatched = EventDecoder::<PoolEvent>::with_schemas(schemas);
+        assert!(matches!(
+            mismatched.decode(&bytes),
+            Err(SdkError::SchemaMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_decodes_the_events_in_order() {
+        let second = Initialize {
+            tick: 1,
+            ..initialize()
+        };
+        let events = vec![
+            Bytes::from(initialize().to_bytes().unwrap()),
+            Bytes::from(second.to_bytes().unwrap()),
+        ];
+        let decoder = EventDecoder::<PoolEvent>::new();
+        let decoded: Vec<PoolEvent> = decoder
+            .decode_all(&events)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            decoded,
+            vec![
+                PoolEvent::Initialize(initialize()),
+                PoolEvent::Initialize(second)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rejects_unknown_and_malformed_events() {
+        let bytes = initialize().to_bytes().unwrap();
+        assert!(matches!(
+            RouterEvent::from_bytes(&bytes),
+            Err(SdkError::UnknownEvent(name)) if name == "Initialize"
+        ));
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(PoolEvent::from_bytes(&trailing).is_err());
+        assert!(PoolEvent::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        let not_an_event = Bytes::from(Key::Hash([0; 32]).to_bytes().unwrap());
+        assert!(PoolEvent::from_bytes(&not_an_event).is_err());
+    }
+}
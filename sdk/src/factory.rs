@@ -0,0 +1,75 @@
+use casper_types::{runtime_args, ContractPackageHash, ExecutableDeployItem, Key, RuntimeArgs};
+
+use crate::deploy::contract_call;
+
+pub fn create_pool(
+    factory: ContractPackageHash,
+    token0: Key,
+    token1: Key,
+    fee: u32,
+) -> ExecutableDeployItem {
+    contract_call(
+        factory,
+        "create_pool",
+        runtime_args! {
+            "token0" => token0,
+            "token1" => token1,
+            "fee" => fee,
+        },
+    )
+}
+
+pub fn enable_fee_amount(
+    factory: ContractPackageHash,
+    fee: u32,
+    tick_spacing: i32,
+) -> ExecutableDeployItem {
+    contract_call(
+        factory,
+        "enable_fee_amount",
+        runtime_args! {
+            "fee" => fee,
+            "tick_spacing" => tick_spacing,
+        },
+    )
+}
+
+// also works for the router and every other contract using common::owner
+pub fn transfer_owner(contract: ContractPackageHash, contract_owner: Key) -> ExecutableDeployItem {
+    contract_call(
+        contract,
+        "transfer_owner",
+        runtime_args! {
+            "contract_owner" => contract_owner,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{ContractPackageHash, ExecutableDeployItem, Key};
+
+    use super::create_pool;
+
+    #[test]
+    fn test_create_pool_args() {
+        let session = create_pool(
+            ContractPackageHash::new([1u8; 32]),
+            Key::Hash([2u8; 32]),
+            Key::Hash([3u8; 32]),
+            3000,
+        );
+        match session {
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                entry_point, args, ..
+            } => {
+                assert_eq!(entry_point, "create_pool");
+                let fee: u32 = args.get("fee").unwrap().clone().into_t().unwrap();
+                assert_eq!(fee, 3000);
+                let token0: Key = args.get("token0").unwrap().clone().into_t().unwrap();
+                assert_eq!(token0, Key::Hash([2u8; 32]));
+            }
+            _ => panic!("expected a stored versioned contract call"),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    crypto::blake2b,
+    Key,
+};
+
+use common::path::Path;
+
+use crate::error::SdkError;
+
+pub use common::utils::sort_tokens;
+
+fn hashed(bytes: Vec<Vec<u8>>) -> [u8; 32] {
+    blake2b(bytes.concat())
+}
+
+// same as common::utils::compute_pool_key, the factory sorts the tokens before hashing them
+pub fn pool_key(token0: Key, token1: Key, fee: u32) -> [u8; 32] {
+    hashed(vec![
+        token0.to_bytes().unwrap(),
+        token1.to_bytes().unwrap(),
+        fee.to_bytes().unwrap(),
+    ])
+}
+
+// same as common::utils::position_key
+pub fn position_key(owner: Key, tick_lower: i32, tick_upper: i32) -> String {
+    hex::encode(hashed(vec![
+        owner.to_bytes().unwrap(),
+        tick_lower.to_bytes().unwrap(),
+        tick_upper.to_bytes().unwrap(),
+    ]))
+}
+
+// common::path::Path with the router's hop checks, fee tiers are left to the router which asks
+// the factory
+pub fn encode_path(tokens: &[Key], fees: &[u32]) -> Result<Bytes, SdkError> {
+    if tokens.len() != fees.len() + 1 {
+        return Err(SdkError::InvalidPath);
+    }
+    let path = fees
+        .iter()
+        .zip(tokens[1..].iter())
+        .fold(Path::new(tokens[0]), |path, (fee, token)| {
+            path.hop(*fee, *token)
+        });
+    path.validate_hops().map_err(|_| SdkError::InvalidPath)?;
+    path.encode().map_err(|_| SdkError::InvalidPath)
+}
+
+pub fn decode_path(path: &[u8]) -> Result<(Vec<Key>, Vec<u32>), SdkError> {
+    let path = Path::decode(path).map_err(|_| SdkError::InvalidPath)?;
+    path.validate_hops().map_err(|_| SdkError::InvalidPath)?;
+    let pools = path.pools();
+    let mut tokens = vec![path.first_token()];
+    tokens.extend(pools.iter().map(|(_, token_b, _)| *token_b));
+    Ok((tokens, pools.iter().map(|(_, _, fee)| *fee).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{bytesrepr::ToBytes, crypto::blake2b, Key};
+
+    use common::path::Path;
+
+    use super::{decode_path, encode_path, pool_key, position_key};
+
+    fn token(byte: u8) -> Key {
+        Key::Hash([byte; 32])
+    }
+
+    #[test]
+    fn test_keys_hash_the_serialized_arguments() {
+        let mut bytes = token(1).to_bytes().unwrap();
+        bytes.append(&mut token(2).to_bytes().unwrap());
+        bytes.append(&mut 3000u32.to_bytes().unwrap());
+        assert_eq!(pool_key(token(1), token(2), 3000), blake2b(bytes));
+        assert_eq!(position_key(token(1), -60, 60).len(), 64);
+        assert_ne!(
+            position_key(token(1), -60, 60),
+            position_key(token(1), -120, 60)
+        );
+    }
+
+    #[test]
+    fn test_path_round_trip() {
+        let path = encode_path(&[token(1), token(2), token(3)], &[500, 3000]).unwrap();
+        assert_eq!(path.len(), 104);
+        let (tokens, fees) = decode_path(&path).unwrap();
+        assert_eq!(tokens, vec![token(1), token(2), token(3)]);
+        assert_eq!(fees, vec![500, 3000]);
+    }
+
+    #[test]
+    fn test_rejects_malformed_paths() {
+        assert!(encode_path(&[token(1)], &[]).is_err());
+        assert!(encode_path(&[token(1), token(2)], &[500, 3000]).is_err());
+        assert!(decode_path(&[0u8; 32]).is_err());
+        assert!(decode_path(&[0u8; 69]).is_err());
+    }
+
+    #[test]
+    fn test_rejects_paths_the_router_rejects() {
+        assert!(encode_path(&[token(1), token(1)], &[500]).is_err());
+        assert!(encode_path(&[token(1), token(2), token(1)], &[500, 500]).is_err());
+        let tokens: Vec<Key> = (1..=6).map(token).collect();
+        assert!(encode_path(&tokens, &[500; 5]).is_err());
+        let repeated = Path::new(token(1))
+            .hop(500, token(2))
+            .hop(500, token(1))
+            .encode()
+            .unwrap();
+        assert!(decode_path(&repeated).is_err());
+    }
+}
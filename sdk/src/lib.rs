@@ -0,0 +1,13 @@
+pub mod decode;
+pub mod deploy;
+pub mod error;
+pub mod events;
+pub mod factory;
+pub mod keys;
+pub mod pool;
+pub mod price;
+pub mod router;
+pub mod session;
+
+pub use deploy::{contract_call, DeployConfig};
+pub use error::SdkError;
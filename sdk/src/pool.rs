@@ -0,0 +1,67 @@
+// pool administration, mints and swaps need a callback and go through the router
+use casper_types::{
+    runtime_args, ContractPackageHash, ExecutableDeployItem, Key, RuntimeArgs, U128,
+};
+
+use crate::deploy::contract_call;
+
+pub fn increase_observation_cardinality_next(
+    pool: ContractPackageHash,
+    observation_cardinality_next: u32,
+) -> ExecutableDeployItem {
+    contract_call(
+        pool,
+        "increase_observation_cardinality_next",
+        runtime_args! {
+            "observation_cardinality_next" => observation_cardinality_next,
+        },
+    )
+}
+
+pub fn set_fee_protocol(
+    pool: ContractPackageHash,
+    fee_protocol0: u8,
+    fee_protocol1: u8,
+) -> ExecutableDeployItem {
+    contract_call(
+        pool,
+        "set_fee_protocol",
+        runtime_args! {
+            "fee_protocol0" => fee_protocol0,
+            "fee_protocol1" => fee_protocol1,
+        },
+    )
+}
+
+pub fn collect_protocol(
+    pool: ContractPackageHash,
+    recipient: Key,
+    amount0_requested: U128,
+    amount1_requested: U128,
+) -> ExecutableDeployItem {
+    contract_call(
+        pool,
+        "collect_protocol",
+        runtime_args! {
+            "recipient" => recipient,
+            "amount0_requested" => amount0_requested,
+            "amount1_requested" => amount1_requested,
+        },
+    )
+}
+
+// `complete` on the last batch switches the pool back on
+pub fn migrate_ticks(
+    pool: ContractPackageHash,
+    ticks: Vec<i32>,
+    complete: bool,
+) -> ExecutableDeployItem {
+    contract_call(
+        pool,
+        "migrate_ticks",
+        runtime_args! {
+            "ticks" => ticks,
+            "complete" => complete,
+        },
+    )
+}
@@ -0,0 +1,147 @@
+// conversions between human prices, ticks and Q64.96 square root prices. Prices are token1 per
+// token0, `decimals` is `(decimals0, decimals1)` and leaves the raw price untouched when `None`
+use casper_types::U256;
+use math::tickmath::{
+    get_sqrt_ratio_at_tick, get_tick_at_sqrt_ratio, max_sqrt_ratio, min_sqrt_ratio,
+};
+
+use crate::error::SdkError;
+
+const Q96: i32 = 96;
+
+fn to_raw_price(price: f64, decimals: Option<(u8, u8)>) -> f64 {
+    match decimals {
+        Some((decimals0, decimals1)) => price * 10f64.powi(decimals1 as i32 - decimals0 as i32),
+        None => price,
+    }
+}
+
+fn from_raw_price(price: f64, decimals: Option<(u8, u8)>) -> f64 {
+    match decimals {
+        Some((decimals0, decimals1)) => price * 10f64.powi(decimals0 as i32 - decimals1 as i32),
+        None => price,
+    }
+}
+
+// exact conversion of `value * 2^96` using the mantissa and exponent of the float
+fn to_x96(value: f64) -> Option<U256> {
+    if !value.is_finite() || value < 0.0 {
+        return None;
+    }
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let mut mantissa = bits & ((1u64 << 52) - 1);
+    let exponent = if biased_exponent == 0 {
+        -1074
+    } else {
+        mantissa |= 1u64 << 52;
+        biased_exponent - 1075
+    };
+    let shift = exponent + Q96;
+    if shift >= 0 {
+        if shift > 256 - 53 {
+            return None;
+        }
+        Some(U256::from(mantissa) << shift as usize)
+    } else if shift > -64 {
+        Some(U256::from(mantissa >> (-shift) as u32))
+    } else {
+        Some(U256::zero())
+    }
+}
+
+fn from_x96(value: U256) -> f64 {
+    let mut result = 0f64;
+    for (i, limb) in value.0.iter().enumerate() {
+        result += *limb as f64 * 2f64.powi(64 * i as i32);
+    }
+    result / 2f64.powi(Q96)
+}
+
+pub fn sqrt_price_x96_from_price(price: f64, decimals: Option<(u8, u8)>) -> Result<U256, SdkError> {
+    let sqrt_price_x96 =
+        to_x96(to_raw_price(price, decimals).sqrt()).ok_or(SdkError::PriceOutOfRange)?;
+    if sqrt_price_x96 < min_sqrt_ratio() || sqrt_price_x96 >= max_sqrt_ratio() {
+        return Err(SdkError::PriceOutOfRange);
+    }
+    Ok(sqrt_price_x96)
+}
+
+pub fn price_from_sqrt_price_x96(sqrt_price_x96: U256, decimals: Option<(u8, u8)>) -> f64 {
+    let sqrt_price = from_x96(sqrt_price_x96);
+    from_raw_price(sqrt_price * sqrt_price, decimals)
+}
+
+pub fn sqrt_price_x96_at_tick(tick: i32) -> Result<U256, SdkError> {
+    if !(math::tickmath::MIN_TICK..=math::tickmath::MAX_TICK).contains(&tick) {
+        return Err(SdkError::PriceOutOfRange);
+    }
+    Ok(get_sqrt_ratio_at_tick(tick))
+}
+
+// the greatest tick whose price is at most `sqrt_price_x96`
+pub fn tick_at_sqrt_price_x96(sqrt_price_x96: U256) -> Result<i32, SdkError> {
+    if sqrt_price_x96 < min_sqrt_ratio() || sqrt_price_x96 >= max_sqrt_ratio() {
+        return Err(SdkError::PriceOutOfRange);
+    }
+    Ok(get_tick_at_sqrt_ratio(sqrt_price_x96))
+}
+
+pub fn price_to_tick(price: f64, decimals: Option<(u8, u8)>) -> Result<i32, SdkError> {
+    tick_at_sqrt_price_x96(sqrt_price_x96_from_price(price, decimals)?)
+}
+
+pub fn tick_to_price(tick: i32, decimals: Option<(u8, u8)>) -> Result<f64, SdkError> {
+    Ok(price_from_sqrt_price_x96(
+        sqrt_price_x96_at_tick(tick)?,
+        decimals,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::U256;
+
+    use super::{
+        price_from_sqrt_price_x96, price_to_tick, sqrt_price_x96_at_tick,
+        sqrt_price_x96_from_price, tick_to_price,
+    };
+
+    #[test]
+    fn test_price_one_is_tick_zero() {
+        let q96 = U256::one() << 96;
+        assert_eq!(sqrt_price_x96_from_price(1.0, None).unwrap(), q96);
+        assert_eq!(sqrt_price_x96_from_price(4.0, None).unwrap(), q96 * 2);
+        assert_eq!(price_from_sqrt_price_x96(q96 * 2, None), 4.0);
+        assert_eq!(sqrt_price_x96_at_tick(0).unwrap(), q96);
+        assert_eq!(price_to_tick(1.0, None).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_ticks_round_down() {
+        assert_eq!(price_to_tick(1.00011, None).unwrap(), 1);
+        assert_eq!(price_to_tick(1.00005, None).unwrap(), 0);
+        assert_eq!(price_to_tick(0.99995, None).unwrap(), -1);
+        let price = tick_to_price(-6932, None).unwrap();
+        assert!((price - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_decimals_scale_the_raw_price() {
+        // 2000 token1 (6 decimals) per token0 (18 decimals)
+        let sqrt_price_x96 = sqrt_price_x96_from_price(2000.0, Some((18, 6))).unwrap();
+        let raw = price_from_sqrt_price_x96(sqrt_price_x96, None);
+        assert!((raw / 2000e-12 - 1.0).abs() < 1e-9);
+        let human = price_from_sqrt_price_x96(sqrt_price_x96, Some((18, 6)));
+        assert!((human / 2000.0 - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rejects_prices_outside_of_the_tick_range() {
+        assert!(sqrt_price_x96_from_price(0.0, None).is_err());
+        assert!(sqrt_price_x96_from_price(-1.0, None).is_err());
+        assert!(sqrt_price_x96_from_price(f64::NAN, None).is_err());
+        assert!(sqrt_price_x96_from_price(1e40, None).is_err());
+        assert!(sqrt_price_x96_at_tick(887273).is_err());
+    }
+}
@@ -0,0 +1,201 @@
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, ContractPackageHash, ExecutableDeployItem, Key, RuntimeArgs, U128, U256,
+};
+use types::{
+    CollectParams, CompositeDecreaseLiquidityParams, CompositeIncreaseLiquidityParams,
+    CompositeMintParams, DecreaseLiquidityParams, ExactInputParams, ExactInputSingleParams,
+    ExactInputSplitParams, ExactOutputParams, ExactOutputSingleParams, ExactOutputSplitParams,
+    ExitPositionParams, IncreaseLiquidityParams, MintParams, RebalanceParams, ZapMintParams,
+};
+
+use crate::{deploy::contract_call, error::SdkError};
+
+// entry points taking their params serialized in a single `data` argument
+macro_rules! data_calls {
+    ($($name: ident => $params: ty),* $(,)?) => {
+        $(
+            pub fn $name(
+                router: ContractPackageHash,
+                params: &$params,
+            ) -> Result<ExecutableDeployItem, SdkError> {
+                Ok(contract_call(
+                    router,
+                    stringify!($name),
+                    runtime_args! {
+                        "data" => Bytes::from(params.to_bytes()?),
+                    },
+                ))
+            }
+        )*
+    };
+}
+
+data_calls! {
+    mint => MintParams,
+    increase_liquidity => IncreaseLiquidityParams,
+    decrease_liquidity => DecreaseLiquidityParams,
+    collect => CollectParams,
+    exit_position => ExitPositionParams,
+    rebalance => RebalanceParams,
+    zap_mint => ZapMintParams,
+    composite_mint => CompositeMintParams,
+    composite_increase_liquidity => CompositeIncreaseLiquidityParams,
+    composite_decrease_liquidity => CompositeDecreaseLiquidityParams,
+    composite_collect => CollectParams,
+    exact_input_single => ExactInputSingleParams,
+    exact_input => ExactInputParams,
+    exact_input_split => ExactInputSplitParams,
+    exact_output_single => ExactOutputSingleParams,
+    exact_output => ExactOutputParams,
+    exact_output_split => ExactOutputSplitParams,
+}
+
+pub fn burn(router: ContractPackageHash, token_id: U256) -> ExecutableDeployItem {
+    contract_call(
+        router,
+        "burn",
+        runtime_args! {
+            "token_id" => token_id,
+        },
+    )
+}
+
+pub fn split(router: ContractPackageHash, token_id: U256, liquidity: U128) -> ExecutableDeployItem {
+    contract_call(
+        router,
+        "split",
+        runtime_args! {
+            "token_id" => token_id,
+            "liquidity" => liquidity,
+        },
+    )
+}
+
+pub fn merge(router: ContractPackageHash, token_ids: Vec<U256>) -> ExecutableDeployItem {
+    contract_call(
+        router,
+        "merge",
+        runtime_args! {
+            "token_ids" => token_ids,
+        },
+    )
+}
+
+pub fn create_and_initialize_pool_if_necessary(
+    router: ContractPackageHash,
+    token0: Key,
+    token1: Key,
+    fee: u32,
+    sqrt_price_x96: U256,
+) -> ExecutableDeployItem {
+    contract_call(
+        router,
+        "create_and_initialize_pool_if_necessary",
+        runtime_args! {
+            "token0" => token0,
+            "token1" => token1,
+            "fee" => fee,
+            "sqrt_price_x96" => sqrt_price_x96,
+        },
+    )
+}
+
+pub fn sweep_token(
+    router: ContractPackageHash,
+    token: Key,
+    amount_minimum: U256,
+    recipient: Key,
+) -> ExecutableDeployItem {
+    contract_call(
+        router,
+        "sweep_token",
+        runtime_args! {
+            "token" => token,
+            "amount_minimum" => amount_minimum,
+            "recipient" => recipient,
+        },
+    )
+}
+
+pub fn refund_cspr(router: ContractPackageHash) -> ExecutableDeployItem {
+    contract_call(router, "refund_cspr", runtime_args! {})
+}
+
+pub fn claim_partner_fees(
+    router: ContractPackageHash,
+    token: Key,
+    recipient: Key,
+) -> ExecutableDeployItem {
+    contract_call(
+        router,
+        "claim_partner_fees",
+        runtime_args! {
+            "token" => token,
+            "recipient" => recipient,
+        },
+    )
+}
+
+pub fn set_wcspr(router: ContractPackageHash, wcspr: Key) -> ExecutableDeployItem {
+    contract_call(
+        router,
+        "set_wcspr",
+        runtime_args! {
+            "wcspr" => wcspr,
+        },
+    )
+}
+
+pub fn set_max_partner_fee_bips(
+    router: ContractPackageHash,
+    max_partner_fee_bips: u32,
+) -> ExecutableDeployItem {
+    contract_call(
+        router,
+        "set_max_partner_fee_bips",
+        runtime_args! {
+            "max_partner_fee_bips" => max_partner_fee_bips,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{
+        bytesrepr::{Bytes, FromBytes},
+        ContractPackageHash, ExecutableDeployItem, Key, U256,
+    };
+    use types::MintParams;
+
+    use super::mint;
+
+    #[test]
+    fn test_params_are_passed_as_data() {
+        let params = MintParams {
+            token0: Key::Hash([2u8; 32]),
+            token1: Key::Hash([3u8; 32]),
+            fee: 3000,
+            tick_lower: -60,
+            tick_upper: 60,
+            amount0_desired: U256::from(1000),
+            amount1_desired: U256::from(1000),
+            amount0_min: U256::zero(),
+            amount1_min: U256::zero(),
+            recipient: Key::Hash([4u8; 32]),
+            deadline: 99999999999,
+        };
+        match mint(ContractPackageHash::new([1u8; 32]), &params).unwrap() {
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                entry_point, args, ..
+            } => {
+                assert_eq!(entry_point, "mint");
+                let data: Bytes = args.get("data").unwrap().clone().into_t().unwrap();
+                let decoded = MintParams::from_bytes(&data).unwrap().0;
+                assert_eq!(decoded.tick_lower, -60);
+                assert_eq!(decoded.recipient, Key::Hash([4u8; 32]));
+            }
+            _ => panic!("expected a stored versioned contract call"),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use casper_types::{bytesrepr::Bytes, runtime_args, ExecutableDeployItem, Key, RuntimeArgs, U512};
+use types::session::SessionBuilder;
+
+// runs the swap or liquidity session wasm, `amount` is the CSPR wrapped by commands asking for it
+pub fn session(
+    module_bytes: Vec<u8>,
+    router: Key,
+    wcspr: Key,
+    commands: &SessionBuilder,
+    amount: U512,
+) -> ExecutableDeployItem {
+    ExecutableDeployItem::ModuleBytes {
+        module_bytes: Bytes::from(module_bytes),
+        args: runtime_args! {
+            "datas" => commands.build(),
+            "router" => router,
+            "wcspr" => wcspr,
+            "amount" => amount,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{bytesrepr::Bytes, ExecutableDeployItem, Key, U256, U512};
+    use types::session::{SessionBuilder, SessionCommand};
+
+    use super::session;
+
+    #[test]
+    fn test_session_args() {
+        let builder = SessionBuilder::new().unwrap_cspr(U256::from(10), Key::Hash([5u8; 32]));
+        match session(
+            vec![0, 97, 115, 109],
+            Key::Hash([1u8; 32]),
+            Key::Hash([2u8; 32]),
+            &builder,
+            U512::from(10),
+        ) {
+            ExecutableDeployItem::ModuleBytes { module_bytes, args } => {
+                assert_eq!(module_bytes.len(), 4);
+                let datas: Vec<Bytes> = args.get("datas").unwrap().clone().into_t().unwrap();
+                assert_eq!(datas.len(), 1);
+                assert!(matches!(
+                    SessionCommand::from_versioned_bytes(&datas[0]).unwrap(),
+                    SessionCommand::UnwrapCspr { .. }
+                ));
+            }
+            _ => panic!("expected module bytes"),
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, Key, RuntimeArgs, U256,
+};
+use common::path::Path;
+use contract_utilities::helpers::null_key;
+use types::{
+    ExactInputLeg, ExactInputParams, ExactInputSplitParams, ExactOutputLeg, ExactOutputParams,
+    ExactOutputSplitParams,
+};
+
+use crate::{snapshot::PoolSnapshot, split::SplitQuote};
+
+const BIPS_DENOMINATOR: u32 = 10_000;
+
+// validated like the router does it, a fee tier counts as enabled when one of the pools uses it
+pub fn encode_path(path: &Path, pools: &[PoolSnapshot]) -> Bytes {
+    path.validate(|fee| pools.iter().any(|pool| pool.fee == fee))
+        .and_then(|_| path.encode())
+        .unwrap_or_else(|_| panic!("invalid path"))
+}
+
+pub fn amount_with_slippage_down(amount: U256, slippage_bips: u32) -> U256 {
+    amount * (BIPS_DENOMINATOR - slippage_bips) / BIPS_DENOMINATOR
+}
+
+pub fn amount_with_slippage_up(amount: U256, slippage_bips: u32) -> U256 {
+    amount * (BIPS_DENOMINATOR + slippage_bips) / BIPS_DENOMINATOR
+}
+
+// router entry point and runtime args executing an exact input quote
+pub fn exact_input_args(
+    quote: &SplitQuote,
+    pools: &[PoolSnapshot],
+    recipient: Key,
+    deadline: u64,
+    slippage_bips: u32,
+) -> (&'static str, RuntimeArgs) {
+    let amount_out_minimum = amount_with_slippage_down(quote.amount_out, slippage_bips);
+    let data: Vec<u8> = if quote.legs.len() == 1 {
+        ExactInputParams {
+            path: quote.legs[0].route.path(pools),
+            recipient,
+            deadline,
+            amount_in: quote.legs[0].amount_in,
+            amount_out_minimum,
+            fee_recipient: null_key(),
+            fee_bips: 0,
+        }
+        .to_bytes()
+        .unwrap()
+    } else {
+        ExactInputSplitParams {
+            legs: quote
+                .legs
+                .iter()
+                .map(|leg| ExactInputLeg {
+                    path: leg.route.path(pools),
+                    amount_in: leg.amount_in,
+                })
+                .collect(),
+            recipient,
+            deadline,
+            amount_out_minimum,
+        }
+        .to_bytes()
+        .unwrap()
+    };
+    (
+        if quote.legs.len() == 1 {
+            "exact_input"
+        } else {
+            "exact_input_split"
+        },
+        runtime_args! {
+            "data" => Bytes::from(data)
+        },
+    )
+}
+
+// router entry point and runtime args executing an exact output quote
+pub fn exact_output_args(
+    quote: &SplitQuote,
+    pools: &[PoolSnapshot],
+    recipient: Key,
+    deadline: u64,
+    slippage_bips: u32,
+) -> (&'static str, RuntimeArgs) {
+    let amount_in_maximum = amount_with_slippage_up(quote.amount_in, slippage_bips);
+    let data: Vec<u8> = if quote.legs.len() == 1 {
+        ExactOutputParams {
+            path: quote.legs[0].route.reversed_path(pools),
+            recipient,
+            deadline,
+            amount_out: quote.legs[0].amount_out,
+            amount_in_maximum,
+            fee_recipient: null_key(),
+            fee_bips: 0,
+        }
+        .to_bytes()
+        .unwrap()
+    } else {
+        ExactOutputSplitParams {
+            legs: quote
+                .legs
+                .iter()
+                .map(|leg| ExactOutputLeg {
+                    path: leg.route.reversed_path(pools),
+                    amount_out: leg.amount_out,
+                })
+                .collect(),
+            recipient,
+            deadline,
+            amount_in_maximum,
+        }
+        .to_bytes()
+        .unwrap()
+    };
+    (
+        if quote.legs.len() == 1 {
+            "exact_output"
+        } else {
+            "exact_output_split"
+        },
+        runtime_args! {
+            "data" => Bytes::from(data)
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{
+        bytesrepr::{Bytes, FromBytes},
+        Key, U256,
+    };
+    use types::{ExactInputParams, ExactInputSplitParams};
+
+    use common::path::Path;
+
+    use super::{amount_with_slippage_down, encode_path, exact_input_args};
+    use crate::{
+        fixture::{pool, token, FEE_LOW, FEE_MEDIUM},
+        split::{best_exact_input, RouterConfig},
+    };
+
+    #[test]
+    fn test_encodes_like_the_router_expects() {
+        let pools = vec![pool(1, 2, FEE_MEDIUM, 1_000_000)];
+        let path = encode_path(&Path::new(token(1)).hop(FEE_MEDIUM, token(2)), &pools);
+        assert_eq!(path.len(), 68);
+        assert_eq!(&path[0..32], &[1_u8; 32]);
+        assert_eq!(&path[32..36], &FEE_MEDIUM.to_le_bytes());
+        assert_eq!(&path[36..68], &[2_u8; 32]);
+    }
+
+    #[test]
+    #[should_panic = "invalid path"]
+    fn test_rejects_paths_the_router_rejects() {
+        let pools = vec![pool(1, 2, FEE_MEDIUM, 1_000_000)];
+        encode_path(
+            &Path::new(token(1))
+                .hop(FEE_MEDIUM, token(2))
+                .hop(FEE_MEDIUM, token(1)),
+            &pools,
+        );
+    }
+
+    #[test]
+    fn test_single_leg_uses_exact_input() {
+        let pools = vec![pool(1, 2, FEE_MEDIUM, 1_000_000_000)];
+        let quote = best_exact_input(
+            &pools,
+            token(1),
+            token(2),
+            10000.into(),
+            &RouterConfig::default(),
+        )
+        .unwrap();
+        let (entry_point, args) = exact_input_args(&quote, &pools, Key::Hash([9; 32]), 100, 50);
+        assert_eq!(entry_point, "exact_input");
+        let data: Bytes = args.get("data").unwrap().clone().into_t().unwrap();
+        let params = ExactInputParams::from_bytes(&data).unwrap().0;
+        assert_eq!(params.amount_in, U256::from(10000));
+        assert_eq!(
+            params.amount_out_minimum,
+            amount_with_slippage_down(quote.amount_out, 50)
+        );
+        assert_eq!(params.path, quote.legs[0].route.path(&pools));
+    }
+
+    #[test]
+    fn test_multiple_legs_use_exact_input_split() {
+        let pools = vec![
+            pool(1, 2, FEE_MEDIUM, 1_000_000),
+            pool(1, 2, FEE_LOW, 1_000_000),
+        ];
+        let quote = best_exact_input(
+            &pools,
+            token(1),
+            token(2),
+            100000.into(),
+            &RouterConfig::default(),
+        )
+        .unwrap();
+        let (entry_point, args) = exact_input_args(&quote, &pools, Key::Hash([9; 32]), 100, 0);
+        assert_eq!(entry_point, "exact_input_split");
+        let data: Bytes = args.get("data").unwrap().clone().into_t().unwrap();
+        let params = ExactInputSplitParams::from_bytes(&data).unwrap().0;
+        assert_eq!(params.legs.len(), 2);
+        assert_eq!(
+            params.legs[0].amount_in + params.legs[1].amount_in,
+            U256::from(100000)
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use casper_types::{Key, U256};
+use math::fixed_point_96;
+
+use crate::snapshot::PoolSnapshot;
+
+pub const FEE_TEST: u32 = 600;
+pub const FEE_LOW: u32 = 500;
+pub const FEE_MEDIUM: u32 = 3000;
+
+pub fn token(n: u8) -> Key {
+    Key::Hash([n; 32])
+}
+
+pub fn price_one() -> U256 {
+    fixed_point_96::q96()
+}
+
+pub fn expand_to_18_decimals(n: u64) -> U256 {
+    U256::from(n) * U256::exp10(18)
+}
+
+// full range style pool at price 1 with `liquidity` active between -887220 and 887220
+pub fn pool(token_a: u8, token_b: u8, fee: u32, liquidity: u128) -> PoolSnapshot {
+    let (token0, token1) = if token_a < token_b {
+        (token(token_a), token(token_b))
+    } else {
+        (token(token_b), token(token_a))
+    };
+    PoolSnapshot::new(token0, token1, fee, price_one(), 0, liquidity)
+        .with_tick(-887220, liquidity as i128)
+        .with_tick(887220, -(liquidity as i128))
+}
@@ -0,0 +1,12 @@
+pub mod encode;
+pub mod quoter;
+pub mod route;
+pub mod snapshot;
+pub mod split;
+
+#[cfg(test)]
+mod fixture;
+
+pub use route::{find_routes, Route};
+pub use snapshot::PoolSnapshot;
+pub use split::{best_exact_input, best_exact_output, Leg, RouterConfig, SplitQuote};
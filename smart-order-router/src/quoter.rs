@@ -0,0 +1,178 @@
+use core::ops::{Add, Sub};
+
+use casper_types::U256;
+use math::{liquidity_math, swap_math::compute_swap_step, tickmath};
+use types::i256::I256;
+
+use crate::snapshot::PoolSnapshot;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwapResult {
+    // amount_in includes the fee
+    pub amount_in: U256,
+    pub amount_out: U256,
+    pub sqrt_price_x96: U256,
+    pub tick: i32,
+    pub liquidity: u128,
+    // false when the pool ran out of liquidity before the specified amount was filled
+    pub filled: bool,
+}
+
+// replays the pool swap loop against a snapshot, positive amounts are exact input
+pub fn simulate_swap(
+    pool: &PoolSnapshot,
+    zero_for_one: bool,
+    amount_specified: I256,
+    sqrt_price_limit_x96: Option<U256>,
+) -> SwapResult {
+    let sqrt_price_limit_x96 = sqrt_price_limit_x96.unwrap_or(if zero_for_one {
+        tickmath::min_sqrt_ratio().add(1)
+    } else {
+        tickmath::max_sqrt_ratio().sub(1)
+    });
+    let exact_in = amount_specified.0.is_positive();
+    let mut amount_remaining = amount_specified;
+    let mut result = SwapResult {
+        amount_in: U256::zero(),
+        amount_out: U256::zero(),
+        sqrt_price_x96: pool.sqrt_price_x96,
+        tick: pool.tick,
+        liquidity: pool.liquidity,
+        filled: false,
+    };
+
+    while amount_remaining != I256::from(0) && result.sqrt_price_x96 != sqrt_price_limit_x96 {
+        let sqrt_price_start_x96 = result.sqrt_price_x96;
+        let (tick_next, initialized) = pool.next_initialized_tick(result.tick, zero_for_one);
+        let tick_next = tick_next.clamp(tickmath::MIN_TICK, tickmath::MAX_TICK);
+        let sqrt_price_next_x96 = tickmath::get_sqrt_ratio_at_tick(tick_next);
+        let past_limit = if zero_for_one {
+            sqrt_price_next_x96 < sqrt_price_limit_x96
+        } else {
+            sqrt_price_next_x96 > sqrt_price_limit_x96
+        };
+
+        let (sqrt_price_x96, amount_in, amount_out, fee_amount) = compute_swap_step(
+            &result.sqrt_price_x96,
+            if past_limit {
+                &sqrt_price_limit_x96
+            } else {
+                &sqrt_price_next_x96
+            },
+            result.liquidity,
+            amount_remaining,
+            pool.fee.into(),
+        );
+        result.sqrt_price_x96 = sqrt_price_x96;
+        if exact_in {
+            amount_remaining = amount_remaining - I256::from(amount_in + fee_amount);
+        } else {
+            amount_remaining = amount_remaining + I256::from(amount_out);
+        }
+        result.amount_in += amount_in + fee_amount;
+        result.amount_out += amount_out;
+
+        if result.sqrt_price_x96 == sqrt_price_next_x96 {
+            if initialized {
+                let liquidity_net = pool.ticks[&tick_next];
+                result.liquidity = liquidity_math::add_delta(
+                    result.liquidity,
+                    if zero_for_one {
+                        -liquidity_net
+                    } else {
+                        liquidity_net
+                    },
+                );
+            }
+            result.tick = if zero_for_one {
+                tick_next - 1
+            } else {
+                tick_next
+            };
+        } else if result.sqrt_price_x96 != sqrt_price_start_x96 {
+            result.tick = tickmath::get_tick_at_sqrt_ratio(result.sqrt_price_x96);
+        }
+    }
+    result.filled = amount_remaining == I256::from(0);
+    result
+}
+
+pub fn quote_exact_input(pool: &PoolSnapshot, zero_for_one: bool, amount_in: U256) -> SwapResult {
+    simulate_swap(pool, zero_for_one, I256::from(amount_in), None)
+}
+
+pub fn quote_exact_output(pool: &PoolSnapshot, zero_for_one: bool, amount_out: U256) -> SwapResult {
+    simulate_swap(pool, zero_for_one, -I256::from(amount_out), None)
+}
+
+impl PoolSnapshot {
+    pub fn apply(&mut self, result: &SwapResult) {
+        self.sqrt_price_x96 = result.sqrt_price_x96;
+        self.tick = result.tick;
+        self.liquidity = result.liquidity;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::U256;
+
+    use super::{quote_exact_input, quote_exact_output};
+    use crate::fixture::{expand_to_18_decimals, price_one, token, FEE_TEST};
+    use crate::snapshot::PoolSnapshot;
+
+    fn pool() -> PoolSnapshot {
+        PoolSnapshot::new(
+            token(1),
+            token(2),
+            FEE_TEST,
+            price_one(),
+            0,
+            expand_to_18_decimals(2).as_u128(),
+        )
+    }
+
+    #[test]
+    fn test_exact_input_fully_spent_one_for_zero() {
+        let result = quote_exact_input(&pool(), false, expand_to_18_decimals(1));
+        assert!(result.filled);
+        assert_eq!(result.amount_in, expand_to_18_decimals(1));
+        assert_eq!(
+            result.amount_out,
+            U256::from_dec_str("666399946655997866").unwrap()
+        );
+        assert!(result.tick > 0);
+    }
+
+    #[test]
+    fn test_exact_output_fully_received_one_for_zero() {
+        let result = quote_exact_output(&pool(), false, expand_to_18_decimals(1));
+        assert!(result.filled);
+        assert_eq!(result.amount_out, expand_to_18_decimals(1));
+        assert_eq!(
+            result.amount_in,
+            U256::from_dec_str("2001200720432259356").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_crossing_a_tick_removes_its_liquidity() {
+        let liquidity = expand_to_18_decimals(2).as_u128();
+        let pool = pool()
+            .with_tick(-60, liquidity as i128)
+            .with_tick(60, -(liquidity as i128));
+        let result = quote_exact_input(&pool, true, expand_to_18_decimals(1));
+        assert!(!result.filled);
+        assert_eq!(result.liquidity, 0);
+        assert!(result.amount_in < expand_to_18_decimals(1));
+    }
+
+    #[test]
+    fn test_is_deterministic() {
+        let pool = pool().with_tick(-600, 1_000_000).with_tick(600, -1_000_000);
+        assert_eq!(
+            quote_exact_input(&pool, true, expand_to_18_decimals(1)),
+            quote_exact_input(&pool, true, expand_to_18_decimals(1))
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use casper_types::{bytesrepr::Bytes, Key, U256};
+use common::{
+    path::{Path, MAX_HOPS},
+    utils::is_token_sorted,
+};
+
+use crate::{
+    encode::encode_path,
+    quoter::{quote_exact_input, quote_exact_output, SwapResult},
+    snapshot::PoolSnapshot,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Route {
+    // tokens from input to output, one more than the pools
+    pub tokens: Vec<Key>,
+    // indexes into the pool set the route was found in
+    pub pools: Vec<usize>,
+}
+
+impl Route {
+    pub fn token_in(&self) -> Key {
+        self.tokens[0]
+    }
+
+    pub fn token_out(&self) -> Key {
+        *self.tokens.last().unwrap()
+    }
+
+    pub fn fees(&self, pools: &[PoolSnapshot]) -> Vec<u32> {
+        self.pools.iter().map(|i| pools[*i].fee).collect()
+    }
+
+    pub fn to_path(&self, pools: &[PoolSnapshot]) -> Path {
+        self.fees(pools)
+            .into_iter()
+            .zip(self.tokens[1..].iter())
+            .fold(Path::new(self.tokens[0]), |path, (fee, token)| {
+                path.hop(fee, *token)
+            })
+    }
+
+    // path for exact input swaps, from token_in to token_out
+    pub fn path(&self, pools: &[PoolSnapshot]) -> Bytes {
+        encode_path(&self.to_path(pools), pools)
+    }
+
+    // exact output swaps take the path reversed, from token_out to token_in
+    pub fn reversed_path(&self, pools: &[PoolSnapshot]) -> Bytes {
+        encode_path(&self.to_path(pools).reverse(), pools)
+    }
+
+    // hop by hop simulation, returns the output and the resulting pool states
+    pub fn quote_exact_input(
+        &self,
+        pools: &[PoolSnapshot],
+        amount_in: U256,
+    ) -> Option<(U256, Vec<(usize, SwapResult)>)> {
+        let mut amount = amount_in;
+        let mut results = vec![];
+        for (hop, pool_index) in self.pools.iter().enumerate() {
+            let zero_for_one = is_token_sorted(self.tokens[hop], self.tokens[hop + 1]);
+            let result = quote_exact_input(&pools[*pool_index], zero_for_one, amount);
+            if !result.filled || result.amount_out.is_zero() {
+                return None;
+            }
+            amount = result.amount_out;
+            results.push((*pool_index, result));
+        }
+        Some((amount, results))
+    }
+
+    // walks the route backwards, returns the input and the resulting pool states
+    pub fn quote_exact_output(
+        &self,
+        pools: &[PoolSnapshot],
+        amount_out: U256,
+    ) -> Option<(U256, Vec<(usize, SwapResult)>)> {
+        let mut amount = amount_out;
+        let mut results = vec![];
+        for (hop, pool_index) in self.pools.iter().enumerate().rev() {
+            let zero_for_one = is_token_sorted(self.tokens[hop], self.tokens[hop + 1]);
+            let result = quote_exact_output(&pools[*pool_index], zero_for_one, amount);
+            if !result.filled {
+                return None;
+            }
+            amount = result.amount_in;
+            results.push((*pool_index, result));
+        }
+        Some((amount, results))
+    }
+}
+
+// every route from token_in to token_out using at most max_hops pools, no token visited twice.
+// The router takes at most MAX_HOPS pools, longer routes are never returned
+pub fn find_routes(
+    pools: &[PoolSnapshot],
+    token_in: Key,
+    token_out: Key,
+    max_hops: usize,
+) -> Vec<Route> {
+    let mut routes = vec![];
+    let mut current = Route {
+        tokens: vec![token_in],
+        pools: vec![],
+    };
+    visit(
+        pools,
+        token_out,
+        max_hops.min(MAX_HOPS),
+        &mut current,
+        &mut routes,
+    );
+    routes
+}
+
+fn visit(
+    pools: &[PoolSnapshot],
+    token_out: Key,
+    max_hops: usize,
+    current: &mut Route,
+    routes: &mut Vec<Route>,
+) {
+    if current.pools.len() == max_hops {
+        return;
+    }
+    let token = current.token_out();
+    for (index, pool) in pools.iter().enumerate() {
+        if !pool.has_token(token) {
+            continue;
+        }
+        let next = pool.other_token(token);
+        if current.tokens.contains(&next) {
+            continue;
+        }
+        current.tokens.push(next);
+        current.pools.push(index);
+        if next == token_out {
+            routes.push(current.clone());
+        } else {
+            visit(pools, token_out, max_hops, current, routes);
+        }
+        current.tokens.pop();
+        current.pools.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::U256;
+    use common::path::{decode_first_pool, has_multiple_pools, skip_token};
+
+    use super::find_routes;
+    use crate::fixture::{pool, token, FEE_LOW, FEE_MEDIUM};
+
+    #[test]
+    fn test_finds_direct_and_multi_hop_routes() {
+        let pools = vec![
+            pool(1, 2, FEE_MEDIUM, 1_000_000),
+            pool(1, 2, FEE_LOW, 1_000_000),
+            pool(1, 3, FEE_MEDIUM, 1_000_000),
+            pool(3, 2, FEE_MEDIUM, 1_000_000),
+        ];
+        let routes = find_routes(&pools, token(1), token(2), 2);
+        assert_eq!(routes.len(), 3);
+        assert_eq!(routes[0].pools, vec![0]);
+        assert_eq!(routes[1].pools, vec![1]);
+        assert_eq!(routes[2].pools, vec![2, 3]);
+        assert_eq!(routes[2].tokens, vec![token(1), token(3), token(2)]);
+
+        assert_eq!(find_routes(&pools, token(1), token(2), 1).len(), 2);
+    }
+
+    #[test]
+    fn test_path_matches_router_decoding() {
+        let pools = vec![
+            pool(1, 3, FEE_MEDIUM, 1_000_000),
+            pool(3, 2, FEE_LOW, 1_000_000),
+        ];
+        let route = &find_routes(&pools, token(1), token(2), 2)[0];
+        let path = route.path(&pools);
+        assert!(has_multiple_pools(&path));
+        assert_eq!(decode_first_pool(&path), (token(1), token(3), FEE_MEDIUM));
+        assert_eq!(
+            decode_first_pool(&skip_token(&path)),
+            (token(3), token(2), FEE_LOW)
+        );
+
+        let reversed = route.reversed_path(&pools);
+        assert_eq!(decode_first_pool(&reversed), (token(2), token(3), FEE_LOW));
+    }
+
+    #[test]
+    fn test_quotes_across_hops() {
+        let pools = vec![
+            pool(1, 3, FEE_MEDIUM, 1_000_000_000),
+            pool(3, 2, FEE_MEDIUM, 1_000_000_000),
+        ];
+        let route = &find_routes(&pools, token(1), token(2), 2)[0];
+        let (amount_out, results) = route.quote_exact_input(&pools, 10000.into()).unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(amount_out < U256::from(10000));
+        assert_eq!(results[1].1.amount_out, amount_out);
+
+        let (amount_in, _) = route.quote_exact_output(&pools, amount_out).unwrap();
+        assert!(amount_in <= U256::from(10000));
+    }
+}
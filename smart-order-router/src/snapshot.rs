@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+
+use casper_types::{Key, U256};
+use math::tickmath::{MAX_TICK, MIN_TICK};
+use types::{Slot0, TickInfo};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PoolSnapshot {
+    pub token0: Key,
+    pub token1: Key,
+    pub fee: u32,
+    pub sqrt_price_x96: U256,
+    pub tick: i32,
+    pub liquidity: u128,
+    // liquidity_net of every initialized tick
+    pub ticks: BTreeMap<i32, i128>,
+}
+
+impl PoolSnapshot {
+    pub fn new(
+        token0: Key,
+        token1: Key,
+        fee: u32,
+        sqrt_price_x96: U256,
+        tick: i32,
+        liquidity: u128,
+    ) -> Self {
+        Self {
+            token0,
+            token1,
+            fee,
+            sqrt_price_x96,
+            tick,
+            liquidity,
+            ticks: BTreeMap::new(),
+        }
+    }
+
+    pub fn from_state(
+        token0: Key,
+        token1: Key,
+        fee: u32,
+        slot0: &Slot0,
+        liquidity: u128,
+        ticks: &[(i32, TickInfo)],
+    ) -> Self {
+        let mut snapshot = Self::new(
+            token0,
+            token1,
+            fee,
+            slot0.sqrt_price_x96,
+            slot0.tick,
+            liquidity,
+        );
+        for (tick, info) in ticks.iter().filter(|(_, info)| info.initialized) {
+            snapshot.ticks.insert(*tick, info.liquidity_net.0);
+        }
+        snapshot
+    }
+
+    pub fn with_tick(mut self, tick: i32, liquidity_net: i128) -> Self {
+        self.ticks.insert(tick, liquidity_net);
+        self
+    }
+
+    pub fn has_token(&self, token: Key) -> bool {
+        self.token0 == token || self.token1 == token
+    }
+
+    pub fn other_token(&self, token: Key) -> Key {
+        if self.token0 == token {
+            self.token1
+        } else {
+            self.token0
+        }
+    }
+
+    // same lookup as the pool's tick bitmap but without the one word limit
+    pub fn next_initialized_tick(&self, tick: i32, lte: bool) -> (i32, bool) {
+        if lte {
+            self.ticks
+                .range(..=tick)
+                .next_back()
+                .map(|(tick, _)| (*tick, true))
+                .unwrap_or((MIN_TICK, false))
+        } else {
+            self.ticks
+                .range(tick + 1..)
+                .next()
+                .map(|(tick, _)| (*tick, true))
+                .unwrap_or((MAX_TICK, false))
+        }
+    }
+}
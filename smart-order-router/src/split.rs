@@ -0,0 +1,270 @@
+use casper_types::{Key, U256};
+
+use crate::{
+    quoter::SwapResult,
+    route::{find_routes, Route},
+    snapshot::PoolSnapshot,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RouterConfig {
+    pub max_hops: usize,
+    // the amount is allocated between routes in this many chunks
+    pub parts: u32,
+}
+
+impl Default for RouterConfig {
+    fn default() -> Self {
+        Self {
+            max_hops: 3,
+            parts: 10,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Leg {
+    pub route: Route,
+    pub amount_in: U256,
+    pub amount_out: U256,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SplitQuote {
+    pub legs: Vec<Leg>,
+    pub amount_in: U256,
+    pub amount_out: U256,
+}
+
+fn chunks(amount: U256, parts: u32) -> Vec<U256> {
+    let parts = U256::from(parts.max(1));
+    let chunk = amount / parts;
+    let mut chunks = vec![];
+    let mut remaining = amount;
+    while !remaining.is_zero() {
+        let next = if chunk.is_zero() || remaining < chunk * 2 {
+            remaining
+        } else {
+            chunk
+        };
+        chunks.push(next);
+        remaining -= next;
+    }
+    chunks
+}
+
+fn apply(states: &mut [PoolSnapshot], results: &[(usize, SwapResult)]) {
+    for (pool_index, result) in results {
+        states[*pool_index].apply(result);
+    }
+}
+
+fn add_to_leg(legs: &mut Vec<Leg>, route: &Route, amount_in: U256, amount_out: U256) {
+    match legs.iter_mut().find(|leg| leg.route == *route) {
+        Some(leg) => {
+            leg.amount_in += amount_in;
+            leg.amount_out += amount_out;
+        }
+        None => legs.push(Leg {
+            route: route.clone(),
+            amount_in,
+            amount_out,
+        }),
+    }
+}
+
+fn quote(legs: Vec<Leg>) -> SplitQuote {
+    let amount_in = legs
+        .iter()
+        .fold(U256::zero(), |acc, leg| acc + leg.amount_in);
+    let amount_out = legs
+        .iter()
+        .fold(U256::zero(), |acc, leg| acc + leg.amount_out);
+    SplitQuote {
+        legs,
+        amount_in,
+        amount_out,
+    }
+}
+
+// greedily gives each chunk to the route with the best output given the chunks already allocated
+pub fn best_exact_input(
+    pools: &[PoolSnapshot],
+    token_in: Key,
+    token_out: Key,
+    amount_in: U256,
+    config: &RouterConfig,
+) -> Option<SplitQuote> {
+    let routes = find_routes(pools, token_in, token_out, config.max_hops);
+    let mut states = pools.to_vec();
+    let mut legs: Vec<Leg> = vec![];
+    for chunk in chunks(amount_in, config.parts) {
+        let mut best: Option<(&Route, U256, Vec<(usize, SwapResult)>)> = None;
+        for route in routes.iter() {
+            if let Some((amount_out, results)) = route.quote_exact_input(&states, chunk) {
+                if best
+                    .as_ref()
+                    .map_or(true, |(_, best_out, _)| amount_out > *best_out)
+                {
+                    best = Some((route, amount_out, results));
+                }
+            }
+        }
+        let (route, amount_out, results) = best?;
+        apply(&mut states, &results);
+        add_to_leg(&mut legs, route, chunk, amount_out);
+    }
+    if legs.is_empty() {
+        return None;
+    }
+    Some(quote(legs))
+}
+
+// greedily gives each chunk to the route with the cheapest input given the chunks already allocated
+pub fn best_exact_output(
+    pools: &[PoolSnapshot],
+    token_in: Key,
+    token_out: Key,
+    amount_out: U256,
+    config: &RouterConfig,
+) -> Option<SplitQuote> {
+    let routes = find_routes(pools, token_in, token_out, config.max_hops);
+    let mut states = pools.to_vec();
+    let mut legs: Vec<Leg> = vec![];
+    for chunk in chunks(amount_out, config.parts) {
+        let mut best: Option<(&Route, U256, Vec<(usize, SwapResult)>)> = None;
+        for route in routes.iter() {
+            if let Some((amount_in, results)) = route.quote_exact_output(&states, chunk) {
+                if best
+                    .as_ref()
+                    .map_or(true, |(_, best_in, _)| amount_in < *best_in)
+                {
+                    best = Some((route, amount_in, results));
+                }
+            }
+        }
+        let (route, amount_in, results) = best?;
+        apply(&mut states, &results);
+        add_to_leg(&mut legs, route, amount_in, chunk);
+    }
+    if legs.is_empty() {
+        return None;
+    }
+    Some(quote(legs))
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::U256;
+
+    use super::{best_exact_input, best_exact_output, chunks, RouterConfig};
+    use crate::{
+        fixture::{pool, token, FEE_LOW, FEE_MEDIUM},
+        quoter::quote_exact_input,
+    };
+
+    #[test]
+    fn test_chunks_sum_to_amount() {
+        let parts = chunks(U256::from(105), 10);
+        assert_eq!(parts.len(), 10);
+        assert_eq!(
+            parts.iter().fold(U256::zero(), |a, b| a + b),
+            U256::from(105)
+        );
+        assert_eq!(chunks(U256::from(3), 10), vec![U256::from(3)]);
+        assert!(chunks(U256::zero(), 10).is_empty());
+    }
+
+    #[test]
+    fn test_single_route_yields_one_leg() {
+        let pools = vec![pool(1, 2, FEE_MEDIUM, 1_000_000_000)];
+        let quote = best_exact_input(
+            &pools,
+            token(1),
+            token(2),
+            10000.into(),
+            &RouterConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(quote.legs.len(), 1);
+        assert_eq!(quote.amount_in, U256::from(10000));
+        let single = quote_exact_input(&pools[0], true, 10000.into());
+        // chunked swaps round down once per chunk
+        assert!(quote.amount_out <= single.amount_out);
+        assert!(quote.amount_out + 10 >= single.amount_out);
+    }
+
+    #[test]
+    fn test_splits_between_pools() {
+        let pools = vec![
+            pool(1, 2, FEE_MEDIUM, 1_000_000),
+            pool(1, 2, FEE_MEDIUM, 1_000_000),
+        ];
+        let quote = best_exact_input(
+            &pools,
+            token(1),
+            token(2),
+            100000.into(),
+            &RouterConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(quote.legs.len(), 2);
+        assert_eq!(quote.legs[0].amount_in, U256::from(50000));
+        assert_eq!(quote.legs[1].amount_in, U256::from(50000));
+        let single = quote_exact_input(&pools[0], true, 100000.into());
+        assert!(quote.amount_out > single.amount_out);
+    }
+
+    #[test]
+    fn test_prefers_lower_fee_then_splits() {
+        let pools = vec![
+            pool(1, 2, FEE_MEDIUM, 1_000_000),
+            pool(1, 2, FEE_LOW, 1_000_000),
+        ];
+        let quote = best_exact_input(
+            &pools,
+            token(1),
+            token(2),
+            100000.into(),
+            &RouterConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(quote.legs[0].route.pools, vec![1]);
+        assert!(quote
+            .legs
+            .iter()
+            .all(|leg| leg.amount_in <= quote.legs[0].amount_in));
+    }
+
+    #[test]
+    fn test_exact_output_split() {
+        let pools = vec![
+            pool(1, 2, FEE_MEDIUM, 1_000_000),
+            pool(1, 2, FEE_MEDIUM, 1_000_000),
+        ];
+        let quote = best_exact_output(
+            &pools,
+            token(1),
+            token(2),
+            50000.into(),
+            &RouterConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(quote.legs.len(), 2);
+        assert_eq!(quote.amount_out, U256::from(50000));
+        assert!(quote.amount_in > quote.amount_out);
+    }
+
+    #[test]
+    fn test_no_route() {
+        let pools = vec![pool(1, 2, FEE_MEDIUM, 1_000_000)];
+        assert!(best_exact_input(
+            &pools,
+            token(1),
+            token(3),
+            100.into(),
+            &RouterConfig::default()
+        )
+        .is_none());
+    }
+}
@@ -0,0 +1,135 @@
+use alloc::{string::String, vec, vec::Vec};
+use casper_types::{
+    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter,
+    U256,
+};
+
+use common::{owner, timestamp_testing};
+use types::IncentiveKey;
+
+use crate::store::{
+    get_deposit_ep, get_factory_ep, get_incentive_ep, get_max_incentive_duration_ep,
+    get_max_incentive_start_lead_time_ep, get_reward_ep, get_router_ep, get_stake_ep,
+};
+
+fn add_entry_points(entry_points: &mut EntryPoints, list: &Vec<EntryPoint>) {
+    for e in list {
+        entry_points.add_entry_point(e.clone());
+    }
+}
+
+fn public_entry_point(name: &str, params: Vec<Parameter>, ret: CLType) -> EntryPoint {
+    EntryPoint::new(
+        String::from(name),
+        params,
+        ret,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub(crate) fn default() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    add_entry_points(&mut entry_points, &timestamp_testing::entry_points());
+    add_entry_points(&mut entry_points, &owner::entry_points());
+    entry_points.add_entry_point(get_factory_ep());
+    entry_points.add_entry_point(get_router_ep());
+    entry_points.add_entry_point(get_max_incentive_start_lead_time_ep());
+    entry_points.add_entry_point(get_max_incentive_duration_ep());
+    entry_points.add_entry_point(get_incentive_ep());
+    entry_points.add_entry_point(get_deposit_ep());
+    entry_points.add_entry_point(get_stake_ep());
+    entry_points.add_entry_point(get_reward_ep());
+
+    entry_points.add_entry_point(public_entry_point("init_staker", vec![], CLType::Unit));
+    entry_points.add_entry_point(public_entry_point(
+        "create_incentive",
+        vec![
+            Parameter::new("incentive_key", IncentiveKey::cl_type()),
+            Parameter::new("reward", CLType::U256),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "end_incentive",
+        vec![Parameter::new("incentive_key", IncentiveKey::cl_type())],
+        CLType::U256,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "deposit_token",
+        vec![Parameter::new("token_id", CLType::U256)],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "transfer_deposit",
+        vec![
+            Parameter::new("token_id", CLType::U256),
+            Parameter::new("to", CLType::Key),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "withdraw_token",
+        vec![
+            Parameter::new("token_id", CLType::U256),
+            Parameter::new("to", CLType::Key),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "stake_token",
+        vec![
+            Parameter::new("incentive_key", IncentiveKey::cl_type()),
+            Parameter::new("token_id", CLType::U256),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "unstake_token",
+        vec![
+            Parameter::new("incentive_key", IncentiveKey::cl_type()),
+            Parameter::new("token_id", CLType::U256),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "claim_reward",
+        vec![
+            Parameter::new("reward_token", CLType::Key),
+            Parameter::new("to", CLType::Key),
+            Parameter::new("amount_requested", CLType::U256),
+        ],
+        CLType::U256,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "get_reward_info",
+        vec![
+            Parameter::new("incentive_key", IncentiveKey::cl_type()),
+            Parameter::new("token_id", CLType::U256),
+        ],
+        <(U256, U256)>::cl_type(),
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "get_incentive_id",
+        vec![Parameter::new("incentive_key", IncentiveKey::cl_type())],
+        CLType::String,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "get_stake_id",
+        vec![
+            Parameter::new("token_id", CLType::U256),
+            Parameter::new("incentive_id", CLType::String),
+        ],
+        CLType::String,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "get_reward_id",
+        vec![
+            Parameter::new("reward_token", Key::cl_type()),
+            Parameter::new("owner", Key::cl_type()),
+        ],
+        CLType::String,
+    ));
+
+    entry_points
+}
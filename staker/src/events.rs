@@ -0,0 +1,15 @@
+use casper_event_standard::Schemas;
+use common::staker_events::*;
+pub fn event_schemas() -> Schemas {
+    Schemas::new()
+        .with::<IncentiveCreated>()
+        .with::<IncentiveEnded>()
+        .with::<DepositTransferred>()
+        .with::<TokenStaked>()
+        .with::<TokenUnstaked>()
+        .with::<RewardClaimed>()
+}
+
+pub fn init_events() {
+    casper_event_standard::init(event_schemas());
+}
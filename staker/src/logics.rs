@@ -0,0 +1,361 @@
+use crate::{
+    reward_math::compute_reward_amount,
+    store::{
+        compute_incentive_id, compute_reward_id, compute_stake_id, read_deposit, read_factory,
+        read_incentive, read_max_incentive_duration, read_max_incentive_start_lead_time,
+        read_reward, read_router, read_stake, save_deposit, save_incentive, save_reward,
+        save_stake,
+    },
+};
+use alloc::{
+    string::{String, ToString},
+    vec,
+};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{CLValue, Key, U256};
+use common::{
+    erc20_helpers,
+    error::{require, Error},
+    intf, staker_events,
+};
+use contract_utilities::helpers::{
+    current_block_timestamp, get_immediate_caller_key, get_named_args_2, get_named_args_3,
+    get_self_key, null_key,
+};
+use types::{Deposit, IncentiveKey, Stake};
+
+#[no_mangle]
+pub extern "C" fn create_incentive() {
+    let (key, reward): (IncentiveKey, U256) = get_named_args_2(
+        vec!["incentive_key", "reward"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    let now = current_block_timestamp();
+    require(reward > U256::zero(), Error::ErrInvalidIncentive);
+    require(now <= key.start_time, Error::ErrInvalidIncentive);
+    require(
+        key.start_time - now <= read_max_incentive_start_lead_time(),
+        Error::ErrInvalidIncentive,
+    );
+    require(key.start_time < key.end_time, Error::ErrInvalidIncentive);
+    require(
+        key.end_time - key.start_time <= read_max_incentive_duration(),
+        Error::ErrInvalidIncentive,
+    );
+
+    let incentive_id = compute_incentive_id(&key);
+    let mut incentive = read_incentive(&incentive_id);
+    incentive.total_reward_unclaimed += reward;
+    save_incentive(&incentive_id, &incentive);
+
+    erc20_helpers::transfer_from(
+        key.reward_token,
+        get_immediate_caller_key(),
+        get_self_key(),
+        reward,
+    );
+
+    casper_event_standard::emit(staker_events::IncentiveCreated::new(&key, reward));
+}
+
+#[no_mangle]
+pub extern "C" fn end_incentive() {
+    let key: IncentiveKey = runtime::get_named_arg("incentive_key");
+    require(
+        current_block_timestamp() >= key.end_time,
+        Error::ErrIncentiveNotEnded,
+    );
+
+    let incentive_id = compute_incentive_id(&key);
+    let mut incentive = read_incentive(&incentive_id);
+    let refund = incentive.total_reward_unclaimed;
+    require(refund > U256::zero(), Error::ErrNoRefund);
+    require(
+        incentive.number_of_stakes == 0,
+        Error::ErrIncentiveHasStakes,
+    );
+
+    // issue the refund
+    incentive.total_reward_unclaimed = U256::zero();
+    save_incentive(&incentive_id, &incentive);
+    erc20_helpers::transfer(key.reward_token, key.refundee, refund);
+
+    // note we never clear total_seconds_claimed_x128
+    casper_event_standard::emit(staker_events::IncentiveEnded::new(&key, refund));
+    runtime::ret(CLValue::from_t(refund).unwrap_or_revert());
+}
+
+// the position NFT must be approved to the staker before depositing
+#[no_mangle]
+pub extern "C" fn deposit_token() {
+    let token_id: U256 = runtime::get_named_arg("token_id");
+    let owner = get_immediate_caller_key();
+    let position = intf::get_position(read_router(), token_id);
+    intf::nft_transfer_from(read_router(), owner, get_self_key(), token_id);
+    save_deposit(
+        &token_id,
+        &Deposit {
+            owner,
+            number_of_stakes: 0,
+            tick_lower: position.tick_lower,
+            tick_upper: position.tick_upper,
+        },
+    );
+    casper_event_standard::emit(staker_events::DepositTransferred::new(
+        token_id,
+        null_key(),
+        owner,
+    ));
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_deposit() {
+    let (token_id, to): (U256, Key) = get_named_args_2(
+        vec!["token_id", "to"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    let mut deposit = read_deposit(&token_id);
+    let owner = deposit.owner;
+    require(
+        owner == get_immediate_caller_key(),
+        Error::ErrNotDepositOwner,
+    );
+    deposit.owner = to;
+    save_deposit(&token_id, &deposit);
+    casper_event_standard::emit(staker_events::DepositTransferred::new(token_id, owner, to));
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw_token() {
+    let (token_id, to): (U256, Key) = get_named_args_2(
+        vec!["token_id", "to"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    let deposit = read_deposit(&token_id);
+    require(deposit.number_of_stakes == 0, Error::ErrTokenStillStaked);
+    require(
+        deposit.owner == get_immediate_caller_key(),
+        Error::ErrNotDepositOwner,
+    );
+
+    save_deposit(&token_id, &Deposit::default());
+    casper_event_standard::emit(staker_events::DepositTransferred::new(
+        token_id,
+        deposit.owner,
+        null_key(),
+    ));
+    intf::nft_transfer(read_router(), to, token_id);
+}
+
+#[no_mangle]
+pub extern "C" fn stake_token() {
+    let (key, token_id): (IncentiveKey, U256) = get_named_args_2(
+        vec!["incentive_key", "token_id"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    require(
+        read_deposit(&token_id).owner == get_immediate_caller_key(),
+        Error::ErrNotDepositOwner,
+    );
+    stake_token_internal(&key, token_id);
+}
+
+fn stake_token_internal(key: &IncentiveKey, token_id: U256) {
+    let now = current_block_timestamp();
+    require(now >= key.start_time, Error::ErrIncentiveNotStarted);
+    require(now < key.end_time, Error::ErrIncentiveEnded);
+
+    let incentive_id = compute_incentive_id(key);
+    let mut incentive = read_incentive(&incentive_id);
+    require(
+        incentive.total_reward_unclaimed > U256::zero(),
+        Error::ErrInvalidIncentive,
+    );
+
+    let stake_id = compute_stake_id(&token_id, &incentive_id);
+    require(
+        read_stake(&stake_id).liquidity.is_zero(),
+        Error::ErrTokenAlreadyStaked,
+    );
+
+    let position = intf::get_position(read_router(), token_id);
+    let pool = intf::get_pool_address(
+        read_factory(),
+        &intf::get_pool_key(position.token0, position.token1, position.fee),
+    );
+    require(pool == key.pool, Error::ErrInvalidStakePool);
+    require(!position.liquidity.is_zero(), Error::ErrInvalidStakePool);
+
+    let mut deposit = read_deposit(&token_id);
+    deposit.number_of_stakes += 1;
+    save_deposit(&token_id, &deposit);
+    incentive.number_of_stakes += 1;
+    save_incentive(&incentive_id, &incentive);
+
+    let (_, seconds_per_liquidity_inside_x128, _) =
+        intf::snapshot_cumulatives_inside(pool, position.tick_lower, position.tick_upper);
+    save_stake(
+        &stake_id,
+        &Stake {
+            seconds_per_liquidity_inside_initial_x128: seconds_per_liquidity_inside_x128,
+            liquidity: position.liquidity,
+        },
+    );
+
+    casper_event_standard::emit(staker_events::TokenStaked::new(
+        token_id,
+        key,
+        position.liquidity,
+    ));
+}
+
+#[no_mangle]
+pub extern "C" fn unstake_token() {
+    let (key, token_id): (IncentiveKey, U256) = get_named_args_2(
+        vec!["incentive_key", "token_id"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    let mut deposit = read_deposit(&token_id);
+    // anyone can call unstake_token if the block time is after the end time of the incentive
+    if current_block_timestamp() < key.end_time {
+        require(
+            deposit.owner == get_immediate_caller_key(),
+            Error::ErrNotDepositOwner,
+        );
+    }
+
+    let incentive_id = compute_incentive_id(&key);
+    let stake_id = compute_stake_id(&token_id, &incentive_id);
+    let stake = read_stake(&stake_id);
+    require(!stake.liquidity.is_zero(), Error::ErrTokenNotStaked);
+
+    let mut incentive = read_incentive(&incentive_id);
+    deposit.number_of_stakes -= 1;
+    save_deposit(&token_id, &deposit);
+    incentive.number_of_stakes -= 1;
+
+    let (_, seconds_per_liquidity_inside_x128, _) =
+        intf::snapshot_cumulatives_inside(key.pool, deposit.tick_lower, deposit.tick_upper);
+    let (reward, seconds_inside_x128) = compute_reward_amount(
+        incentive.total_reward_unclaimed,
+        incentive.total_seconds_claimed_x128,
+        key.start_time,
+        key.end_time,
+        stake.liquidity.as_u128(),
+        stake.seconds_per_liquidity_inside_initial_x128,
+        seconds_per_liquidity_inside_x128,
+        current_block_timestamp(),
+    );
+
+    // if this overflows, e.g. after 2^32-1 full liquidity seconds have been claimed,
+    // reward rate will fall drastically so it's safe
+    incentive.total_seconds_claimed_x128 = incentive
+        .total_seconds_claimed_x128
+        .overflowing_add(seconds_inside_x128)
+        .0;
+    // reward is never greater than total reward unclaimed
+    incentive.total_reward_unclaimed -= reward;
+    save_incentive(&incentive_id, &incentive);
+
+    // this only overflows if a token has a total supply greater than type(uint256).max
+    let reward_id = compute_reward_id(&key.reward_token, &deposit.owner);
+    save_reward(&reward_id, &(read_reward(&reward_id) + reward));
+
+    save_stake(&stake_id, &Stake::default());
+    casper_event_standard::emit(staker_events::TokenUnstaked::new(token_id, &key, reward));
+}
+
+#[no_mangle]
+pub extern "C" fn claim_reward() {
+    let (reward_token, to, amount_requested): (Key, Key, U256) = get_named_args_3(
+        vec!["reward_token", "to", "amount_requested"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    let owner = get_immediate_caller_key();
+    let reward_id = compute_reward_id(&reward_token, &owner);
+    let owed = read_reward(&reward_id);
+    let mut reward = owed;
+    if !amount_requested.is_zero() && amount_requested < reward {
+        reward = amount_requested;
+    }
+
+    save_reward(&reward_id, &(owed - reward));
+    erc20_helpers::transfer(reward_token, to, reward);
+
+    casper_event_standard::emit(staker_events::RewardClaimed::new(
+        reward_token,
+        owner,
+        to,
+        reward,
+    ));
+    runtime::ret(CLValue::from_t(reward).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_reward_info() {
+    let (key, token_id): (IncentiveKey, U256) = get_named_args_2(
+        vec!["incentive_key", "token_id"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    let incentive_id = compute_incentive_id(&key);
+    let stake = read_stake(&compute_stake_id(&token_id, &incentive_id));
+    require(!stake.liquidity.is_zero(), Error::ErrTokenNotStaked);
+
+    let deposit = read_deposit(&token_id);
+    let incentive = read_incentive(&incentive_id);
+    let (_, seconds_per_liquidity_inside_x128, _) =
+        intf::snapshot_cumulatives_inside(key.pool, deposit.tick_lower, deposit.tick_upper);
+    let ret = compute_reward_amount(
+        incentive.total_reward_unclaimed,
+        incentive.total_seconds_claimed_x128,
+        key.start_time,
+        key.end_time,
+        stake.liquidity.as_u128(),
+        stake.seconds_per_liquidity_inside_initial_x128,
+        seconds_per_liquidity_inside_x128,
+        current_block_timestamp(),
+    );
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_incentive_id() {
+    let key: IncentiveKey = runtime::get_named_arg("incentive_key");
+    runtime::ret(CLValue::from_t(compute_incentive_id(&key)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_stake_id() {
+    let (token_id, incentive_id): (U256, String) = get_named_args_2(
+        vec!["token_id", "incentive_id"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    runtime::ret(CLValue::from_t(compute_stake_id(&token_id, &incentive_id)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_reward_id() {
+    let (reward_token, owner): (Key, Key) = get_named_args_2(
+        vec!["reward_token", "owner"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    runtime::ret(CLValue::from_t(compute_reward_id(&reward_token, &owner)).unwrap_or_revert());
+}
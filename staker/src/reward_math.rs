@@ -0,0 +1,36 @@
+use casper_types::U256;
+use math::fullmath;
+
+/// @notice Compute the amount of rewards owed given parameters of the incentive and stake
+/// @return reward The amount of rewards owed
+/// @return seconds_inside_x128 The total liquidity seconds inside the position's range for the duration of the stake
+#[allow(clippy::too_many_arguments)]
+pub fn compute_reward_amount(
+    total_reward_unclaimed: U256,
+    total_seconds_claimed_x128: U256,
+    start_time: u64,
+    end_time: u64,
+    liquidity: u128,
+    seconds_per_liquidity_inside_initial_x128: U256,
+    seconds_per_liquidity_inside_x128: U256,
+    current_time: u64,
+) -> (U256, U256) {
+    // this should never be called before the start time
+    assert!(current_time >= start_time);
+
+    // this operation is safe, as the difference cannot be greater than 1/stake.liquidity
+    let seconds_inside_x128 = fullmath::overflow_sub_u160(
+        &seconds_per_liquidity_inside_x128,
+        &seconds_per_liquidity_inside_initial_x128,
+    ) * U256::from(liquidity);
+
+    let total_seconds_unclaimed_x128 =
+        (U256::from(end_time.max(current_time) - start_time) << 128) - total_seconds_claimed_x128;
+
+    let reward = fullmath::mul_div(
+        &total_reward_unclaimed,
+        &seconds_inside_x128,
+        &total_seconds_unclaimed_x128,
+    );
+    (reward, seconds_inside_x128)
+}
@@ -0,0 +1,139 @@
+use alloc::{string::String, vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    CLTyped, CLValue, EntryPoint, EntryPointAccess, EntryPointType, Key, Parameter, U256,
+};
+use common::{error::Error, get_set_dict, get_set_no_set};
+use contract_utilities::helpers::{self, null_key};
+use types::{Deposit, Incentive, IncentiveKey, Stake};
+
+pub fn initialize(
+    factory: Key,
+    router: Key,
+    max_incentive_start_lead_time: u64,
+    max_incentive_duration: u64,
+) {
+    storage::new_dictionary("incentives").unwrap_or_revert_with(Error::FailedToCreateDictionary);
+    storage::new_dictionary("deposits").unwrap_or_revert_with(Error::FailedToCreateDictionary);
+    storage::new_dictionary("stakes").unwrap_or_revert_with(Error::FailedToCreateDictionary);
+    storage::new_dictionary("rewards").unwrap_or_revert_with(Error::FailedToCreateDictionary);
+    save_factory(factory);
+    save_router(router);
+    save_max_incentive_start_lead_time(max_incentive_start_lead_time);
+    save_max_incentive_duration(max_incentive_duration);
+}
+
+get_set_no_set!(
+    factory,
+    "factory",
+    Key,
+    null_key(),
+    save_factory,
+    read_factory,
+    get_factory,
+    get_factory_ep,
+    "get_factory"
+);
+
+get_set_no_set!(
+    router,
+    "router",
+    Key,
+    null_key(),
+    save_router,
+    read_router,
+    get_router,
+    get_router_ep,
+    "get_router"
+);
+
+get_set_no_set!(
+    max_incentive_start_lead_time,
+    "max_incentive_start_lead_time",
+    u64,
+    0,
+    save_max_incentive_start_lead_time,
+    read_max_incentive_start_lead_time,
+    get_max_incentive_start_lead_time,
+    get_max_incentive_start_lead_time_ep,
+    "get_max_incentive_start_lead_time"
+);
+
+get_set_no_set!(
+    max_incentive_duration,
+    "max_incentive_duration",
+    u64,
+    0,
+    save_max_incentive_duration,
+    read_max_incentive_duration,
+    get_max_incentive_duration,
+    get_max_incentive_duration_ep,
+    "get_max_incentive_duration"
+);
+
+get_set_dict!(
+    "incentives",
+    "incentive_id",
+    String,
+    Incentive,
+    Incentive::default(),
+    save_incentive,
+    read_incentive,
+    get_incentive,
+    get_incentive_ep,
+    "get_incentive"
+);
+
+get_set_dict!(
+    "deposits",
+    "token_id",
+    U256,
+    Deposit,
+    Deposit::default(),
+    save_deposit,
+    read_deposit,
+    get_deposit,
+    get_deposit_ep,
+    "get_deposit"
+);
+
+get_set_dict!(
+    "stakes",
+    "stake_id",
+    String,
+    Stake,
+    Stake::default(),
+    save_stake,
+    read_stake,
+    get_stake,
+    get_stake_ep,
+    "get_stake"
+);
+
+get_set_dict!(
+    "rewards",
+    "reward_id",
+    String,
+    U256,
+    U256::zero(),
+    save_reward,
+    read_reward,
+    get_reward,
+    get_reward_ep,
+    "get_reward"
+);
+
+pub fn compute_incentive_id(key: &IncentiveKey) -> String {
+    helpers::encode_key(&helpers::encode_1(key))
+}
+
+pub fn compute_stake_id(token_id: &U256, incentive_id: &String) -> String {
+    helpers::encode_key(&helpers::encode_2(token_id, incentive_id))
+}
+
+pub fn compute_reward_id(reward_token: &Key, owner: &Key) -> String {
+    helpers::encode_key(&helpers::encode_2(reward_token, owner))
+}
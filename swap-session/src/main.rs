@@ -2,143 +2,193 @@
 #![no_main]
 
 extern crate alloc;
-use alloc::string::String;
 
 use alloc::vec::Vec;
 use casper_contract::{self, contract_api::runtime};
 use casper_types::bytesrepr::{Bytes, ToBytes};
 use casper_types::{runtime_args, Key, RuntimeArgs, U256, U512};
 use common::erc20_helpers;
-use common::error::require;
-use common::utils::{new_purse, u256_to_u512, unwrap_wcspr, wrap_cspr};
-use contract_utilities::helpers::{self, get_self_key};
-use types::{ExactInputParams, ExactInputSingleParams, ExactOutputParams, ExactOutputSingleParams};
+use common::error::{require, Error};
+use common::path::{first_token, last_token};
+use common::session::{decode_command, save_session_result, SessionFunds};
+use common::utils::{set_size_32, u256_to_u512, unwrap_wcspr};
+use contract_utilities::helpers::get_self_key;
+use types::session::{SessionCommand, SessionStepResult};
 
-fn try_wrap_cspr() {
+fn try_wrap_cspr(funds: &mut SessionFunds) {
     let amount: U512 = runtime::get_named_arg("amount");
-    wrap_cspr(get_wcspr(), new_purse(amount), amount);
+    funds.wrap_cspr(get_wcspr(), amount);
 }
 
 fn get_wcspr() -> Key {
     runtime::get_named_arg("wcspr")
 }
 
+// exact input paths start with the input token, exact output paths are reversed and end with it
+fn path_token_in(path: &Bytes, exact_input: bool) -> Key {
+    let token = if exact_input {
+        first_token(path)
+    } else {
+        last_token(path)
+    };
+    Key::Hash(set_size_32(token))
+}
+
+// returns the amount out of exact input swaps and the amount in of exact output swaps
+fn swap(
+    funds: &mut SessionFunds,
+    router: Key,
+    entry_point: &str,
+    token_in: Key,
+    amount: U256,
+    data: Bytes,
+    wrap: bool,
+) -> U256 {
+    if wrap {
+        try_wrap_cspr(funds);
+    }
+    funds.approve(token_in, router, amount);
+    runtime::call_versioned_contract::<U256>(
+        router.into_hash().unwrap().into(),
+        None,
+        entry_point,
+        runtime_args! {
+            "data" => data,
+        },
+    )
+}
+
 #[no_mangle]
 pub extern "C" fn call() {
-    let entry_points: Vec<String> = runtime::get_named_arg("entry_points");
     let datas: Vec<Bytes> = runtime::get_named_arg("datas");
-    require(
-        entry_points.len() == datas.len(),
-        common::error::Error::ErrInvalidLiquiditySessionParams,
-    );
     let router: Key = runtime::get_named_arg("router");
+    let mut steps: Vec<SessionStepResult> = Vec::with_capacity(datas.len());
+    let mut funds = SessionFunds::default();
 
-    for i in 0..entry_points.len() {
-        let entry_point = &entry_points[i];
-        let data = &datas[i];
-        if entry_point == "exact_input_single" {
-            let (
-                token_in,
-                token_out,
-                is_cspr_in,
-                fee,
-                recipient,
-                amount_in,
-                amount_out_minimum,
-                sqrt_price_limit_x96,
-                deadline,
-            ): (Key, Key, bool, u32, Key, U256, U256, U256, u64) = helpers::decode_9(data);
-            if is_cspr_in {
-                try_wrap_cspr();
+    for data in datas.iter() {
+        let command = decode_command(data);
+        let mut step = SessionStepResult::new(command.tag());
+        match command {
+            SessionCommand::ExactInputSingle { params, wrap_cspr } => {
+                step.amount0 = params.amount_in;
+                step.amount1 = swap(
+                    &mut funds,
+                    router,
+                    "exact_input_single",
+                    params.token_in,
+                    params.amount_in,
+                    Bytes::from(params.to_bytes().unwrap()),
+                    wrap_cspr,
+                );
+            }
+            SessionCommand::ExactInput { params, wrap_cspr } => {
+                step.amount0 = params.amount_in;
+                step.amount1 = swap(
+                    &mut funds,
+                    router,
+                    "exact_input",
+                    path_token_in(&params.path, true),
+                    params.amount_in,
+                    Bytes::from(params.to_bytes().unwrap()),
+                    wrap_cspr,
+                );
+            }
+            SessionCommand::ExactOutputSingle { params, wrap_cspr } => {
+                step.amount1 = params.amount_out;
+                step.amount0 = swap(
+                    &mut funds,
+                    router,
+                    "exact_output_single",
+                    params.token_in,
+                    params.amount_in_maximum,
+                    Bytes::from(params.to_bytes().unwrap()),
+                    wrap_cspr,
+                );
+            }
+            SessionCommand::ExactOutput { params, wrap_cspr } => {
+                step.amount1 = params.amount_out;
+                step.amount0 = swap(
+                    &mut funds,
+                    router,
+                    "exact_output",
+                    path_token_in(&params.path, false),
+                    params.amount_in_maximum,
+                    Bytes::from(params.to_bytes().unwrap()),
+                    wrap_cspr,
+                );
+            }
+            SessionCommand::ExactInputSplit { params, wrap_cspr } => {
+                require(!params.legs.is_empty(), Error::ErrInvalidSessionCommand);
+                let amount_in = params
+                    .legs
+                    .iter()
+                    .fold(U256::zero(), |total, leg| total + leg.amount_in);
+                step.amount0 = amount_in;
+                step.amount1 = swap(
+                    &mut funds,
+                    router,
+                    "exact_input_split",
+                    path_token_in(&params.legs[0].path, true),
+                    amount_in,
+                    Bytes::from(params.to_bytes().unwrap()),
+                    wrap_cspr,
+                );
             }
-            erc20_helpers::approve(token_in, router, amount_in);
-            runtime::call_versioned_contract::<U256>(
-                router.into_hash().unwrap().into(),
-                None,
-                "exact_input_single",
-                runtime_args! {
-                    "data" => Bytes::from(ExactInputSingleParams { token_in, token_out, fee, recipient, deadline, amount_in, amount_out_minimum, sqrt_price_limit_x96 }.to_bytes().unwrap()),
-                },
-            );
-        } else if entry_point == "exact_input" {
-            let (token_in, path, is_cspr, recipient, amount_in, amount_out_minimum, deadline): (
-                Key,
-                Bytes,
-                bool,
-                Key,
-                U256,
-                U256,
-                u64,
-            ) = helpers::decode_7(data);
-            erc20_helpers::approve(token_in, router, amount_in);
-            if is_cspr {
-                try_wrap_cspr();
+            SessionCommand::ExactOutputSplit { params, wrap_cspr } => {
+                require(!params.legs.is_empty(), Error::ErrInvalidSessionCommand);
+                step.amount1 = params
+                    .legs
+                    .iter()
+                    .fold(U256::zero(), |total, leg| total + leg.amount_out);
+                step.amount0 = swap(
+                    &mut funds,
+                    router,
+                    "exact_output_split",
+                    path_token_in(&params.legs[0].path, false),
+                    params.amount_in_maximum,
+                    Bytes::from(params.to_bytes().unwrap()),
+                    wrap_cspr,
+                );
             }
-            runtime::call_versioned_contract::<U256>(
-                router.into_hash().unwrap().into(),
-                None,
-                "exact_input",
-                runtime_args! {
-                    "data" => Bytes::from(ExactInputParams { path, recipient, deadline, amount_in, amount_out_minimum }.to_bytes().unwrap()),
-                },
-            );
-        } else if entry_point == "exact_output_single" {
-            let (
-                token_in,
-                token_out,
-                is_cspr,
-                fee,
+            SessionCommand::SweepToken {
+                token,
+                amount_minimum,
                 recipient,
-                amount_out,
-                amount_in_maximum,
-                sqrt_price_limit_x96,
-                deadline,
-            ): (Key, Key, bool, u32, Key, U256, U256, U256, u64) = helpers::decode_9(data);
-            erc20_helpers::approve(token_in, router, amount_in_maximum);
-            if is_cspr {
-                try_wrap_cspr();
+            } => {
+                step.amount0 = runtime::call_versioned_contract::<U256>(
+                    router.into_hash().unwrap().into(),
+                    None,
+                    "sweep_token",
+                    runtime_args! {
+                        "token" => token,
+                        "amount_minimum" => amount_minimum,
+                        "recipient" => recipient,
+                    },
+                );
             }
-            runtime::call_versioned_contract::<U256>(
-                router.into_hash().unwrap().into(),
-                None,
-                "exact_output_single",
-                runtime_args! {
-                    "data" => Bytes::from(ExactOutputSingleParams { token_in, token_out, fee, recipient, deadline, amount_out, amount_in_maximum, sqrt_price_limit_x96 }.to_bytes().unwrap()),
-                },
-            );
-        } else if entry_point == "exact_output" {
-            let (token_in, path, is_cspr, recipient, amount_out, amount_in_maximum, deadline): (
-                Key,
-                Bytes,
-                bool,
-                Key,
-                U256,
-                U256,
-                u64,
-            ) = helpers::decode_7(data);
-            erc20_helpers::approve(token_in, router, amount_in_maximum);
-            if is_cspr {
-                try_wrap_cspr();
+            SessionCommand::RefundCspr {} => {
+                step.amount0 = runtime::call_versioned_contract::<U256>(
+                    router.into_hash().unwrap().into(),
+                    None,
+                    "refund_cspr",
+                    runtime_args! {},
+                );
             }
-            runtime::call_versioned_contract::<U256>(
-                router.into_hash().unwrap().into(),
-                None,
-                "exact_output",
-                runtime_args! {
-                    "data" => Bytes::from(ExactOutputParams { path, recipient, deadline, amount_out, amount_in_maximum }.to_bytes().unwrap()),
-                },
-            );
-        } else if entry_point == "unwrap_cspr" {
-            let (amount, recipient): (U256, Key) = helpers::decode_2(data);
-            let wcspr = get_wcspr();
-            let wcspr_balance = erc20_helpers::get_balance(wcspr, get_self_key());
-            require(
-                wcspr_balance >= amount,
-                common::error::Error::ErrInsufficientBalanceWCSPR,
-            );
-            if wcspr_balance > 0.into() {
-                unwrap_wcspr(wcspr, recipient, u256_to_u512(wcspr_balance));
+            SessionCommand::UnwrapCspr { amount, recipient } => {
+                let wcspr = get_wcspr();
+                let wcspr_balance = erc20_helpers::get_balance(wcspr, get_self_key());
+                require(wcspr_balance >= amount, Error::ErrInsufficientBalanceWCSPR);
+                if wcspr_balance > 0.into() {
+                    unwrap_wcspr(wcspr, recipient, u256_to_u512(wcspr_balance));
+                }
+                step.amount0 = wcspr_balance;
             }
+            // liquidity management goes through the liquidity session
+            _ => runtime::revert(Error::ErrInvalidSessionCommand),
         }
+        funds.checkpoint();
+        steps.push(step);
     }
+    funds.settle();
+    save_session_result(steps);
 }
@@ -7,3 +7,10 @@ pub const TEST_CALLEE: &str = "test-callee.wasm";
 pub const ROUTER: &str = "router.wasm";
 pub const WCSPR: &str = "wcspr-token.wasm";
 pub const LIQUIDITY_SESSION: &str = "liquidity-session.wasm";
+pub const SWAP_SESSION: &str = "swap-session.wasm";
+pub const STAKER: &str = "staker.wasm";
+pub const VAULT: &str = "vault.wasm";
+pub const KEEPER: &str = "keeper.wasm";
+pub const MIGRATOR: &str = "migrator.wasm";
+pub const TEST_V2_PAIR: &str = "test-v2-pair.wasm";
+pub const LOCKER: &str = "locker.wasm";
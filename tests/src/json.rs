@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod json {
+    use casper_types::{bytesrepr::Bytes, Key, U128, U256};
+    use common::router_events::IncreaseLiquidity;
+    use contract_utilities::helpers::null_key;
+    use serde_json::json;
+    use types::{
+        i128::I128, i256::I256, session::SessionCommand, ExactInputParams, PoolKey, Slot0, TickInfo,
+    };
+
+    fn hash(byte: u8) -> Key {
+        Key::Hash([byte; 32])
+    }
+
+    #[test]
+    fn test_big_numbers_are_decimal_strings() {
+        let slot0 = Slot0 {
+            sqrt_price_x96: U256::from(2).pow(96.into()),
+            tick: -10,
+            observation_index: 0,
+            observation_cardinality: 1,
+            observation_cardinality_next: 1,
+            fee_protocol: 0,
+        };
+        let value = serde_json::to_value(&slot0).unwrap();
+        assert!(value["sqrt_price_x96"] == json!("79228162514264337593543950336"));
+        assert!(value["tick"] == json!(-10));
+
+        let tick = TickInfo {
+            liquidity_gross: U128::from(500),
+            liquidity_net: I128(-500),
+            ..Default::default()
+        };
+        let value = serde_json::to_value(&tick).unwrap();
+        assert!(value["liquidity_gross"] == json!("500"));
+        assert!(value["liquidity_net"] == json!("-500"));
+        let decoded: TickInfo = serde_json::from_value(value).unwrap();
+        assert!(decoded.liquidity_net.0 == -500);
+
+        let min = -I256::from(U256::MAX >> 1) - I256::from(1);
+        let value = serde_json::to_value(min).unwrap();
+        assert!(
+            value
+                == json!(
+                    "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+                )
+        );
+        assert!(serde_json::from_value::<I256>(value).unwrap() == min);
+    }
+
+    #[test]
+    fn test_keys_are_formatted() {
+        let pool_key = PoolKey {
+            token0: hash(1),
+            token1: hash(2),
+            fee: 3000,
+        };
+        let value = serde_json::to_value(&pool_key).unwrap();
+        assert!(value["token0"] == json!(hash(1).to_formatted_string()));
+        let decoded: PoolKey = serde_json::from_value(value).unwrap();
+        assert!(decoded.token1 == hash(2) && decoded.fee == 3000);
+    }
+
+    #[test]
+    fn test_router_params_round_trip() {
+        let params = ExactInputParams {
+            path: Bytes::from(vec![1, 2, 3]),
+            recipient: hash(3),
+            deadline: 99999999999,
+            amount_in: U256::from(1000),
+            amount_out_minimum: U256::from(990),
+            fee_recipient: null_key(),
+            fee_bips: 0,
+        };
+        let command = SessionCommand::ExactInput {
+            params,
+            wrap_cspr: false,
+        };
+        let text = serde_json::to_string(&command).unwrap();
+        let decoded: SessionCommand = serde_json::from_str(&text).unwrap();
+        assert!(decoded.to_versioned_bytes() == command.to_versioned_bytes());
+        assert!(serde_json::to_string(&decoded).unwrap() == text);
+    }
+
+    #[test]
+    fn test_events_round_trip() {
+        let value = json!({
+            "token_id": "1",
+            "liquidity": "340282366920938463463374607431768211455",
+            "amount0": "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+            "amount1": "0",
+            "timestamp": 1700000000000_u64,
+        });
+        let event: IncreaseLiquidity = serde_json::from_value(value.clone()).unwrap();
+        assert!(event.liquidity == U128::MAX);
+        assert!(event.amount0 == U256::MAX);
+        assert!(serde_json::to_value(&event).unwrap() == value);
+    }
+}
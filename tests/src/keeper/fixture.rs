@@ -0,0 +1,166 @@
+#![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256};
+use contract_utilities::helpers::null_key;
+use types::{session::SessionBuilder, ExactInputParams, MintParams, TriggerParams};
+
+use crate::{
+    constants,
+    pool::fixture::{get_tick_spacing, FEE_MEDIUM},
+    router::fixture::{setup_fixture, TestContext},
+    utils::{self, encode_path, encode_price_sqrt, get_max_tick, get_min_tick, other, wallet},
+};
+
+pub const MAX_KEEPER_FEE_BIPS: u32 = 100;
+pub const TWAP_DURATION: u32 = 60;
+
+pub struct KeeperContext {
+    pub tc: TestContext,
+    pub keeper: Key,
+    pub pool: Key,
+}
+
+pub fn setup_keeper_fixture() -> KeeperContext {
+    let mut tc = setup_fixture();
+    tc.test_env.set_block_time(1000);
+    tc.multicall_liquidity_session(
+        wallet(),
+        SessionBuilder::new().create_and_initialize_pool_if_necessary(
+            tc.token0,
+            tc.token1,
+            FEE_MEDIUM,
+            encode_price_sqrt(1, 1),
+        ),
+        0.into(),
+    );
+    let pool = tc.get_pool(tc.token0, tc.token1, FEE_MEDIUM);
+    tc.test_env.call_contract(
+        Some(wallet()),
+        pool.into_hash().unwrap().into(),
+        "increase_observation_cardinality_next",
+        runtime_args! {
+            "observation_cardinality_next" => 10_u32,
+        },
+        true,
+    );
+
+    tc.test_env.deploy_contract(
+        Some(wallet()),
+        constants::KEEPER,
+        runtime_args! {
+            "contract_name" => "keeper",
+            "factory" => tc.factory,
+            "router" => tc.router,
+            "max_keeper_fee_bips" => MAX_KEEPER_FEE_BIPS,
+        },
+    );
+    let keeper = tc.test_env.get_contract_package_hash(
+        wallet(),
+        &utils::get_contract_package_hash_key("keeper".to_string()),
+    );
+
+    KeeperContext { tc, keeper, pool }
+}
+
+impl KeeperContext {
+    // full range position owned by the wallet, returns its token id
+    pub fn mint_position(&mut self, amount: U256) -> U256 {
+        let tc = &mut self.tc;
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new().mint(
+                MintParams {
+                    token0: tc.token0,
+                    token1: tc.token1,
+                    fee: FEE_MEDIUM,
+                    tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                    tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                    amount0_desired: amount,
+                    amount1_desired: amount,
+                    amount0_min: U256::zero(),
+                    amount1_min: U256::zero(),
+                    recipient: Key::from(wallet()),
+                    deadline: 99999999999_u64,
+                },
+                false,
+            ),
+            0.into(),
+        );
+        let count = tc.cep47_balance_of(wallet().into());
+        tc.token_of_owner_by_index(wallet().into(), count - 1)
+    }
+
+    pub fn trigger_params(&self, token_id: U256, trigger_tick: i32, swap_to: Key) -> TriggerParams {
+        TriggerParams {
+            token_id,
+            trigger_tick,
+            trigger_below: true,
+            twap_duration: TWAP_DURATION,
+            swap_to,
+            max_slippage_bips: 500,
+            keeper_fee_bips: MAX_KEEPER_FEE_BIPS,
+        }
+    }
+
+    pub fn register(&mut self, params: TriggerParams) {
+        self.tc.test_env.call_contract(
+            Some(wallet()),
+            self.tc.router.into_hash().unwrap().into(),
+            "approve",
+            runtime_args! {
+                "spender" => self.keeper,
+                "token_ids" => vec![params.token_id],
+            },
+            true,
+        );
+        self.call(
+            wallet(),
+            "register_trigger",
+            runtime_args! {
+                "params" => params,
+            },
+        );
+    }
+
+    pub fn execute(&mut self, keeper: AccountHash, token_id: U256) {
+        self.call(keeper, "execute", runtime_args! { "token_id" => token_id });
+    }
+
+    pub fn is_executable(&mut self, token_id: U256) -> bool {
+        self.tc.test_env.call_view_function(
+            self.keeper,
+            "is_executable",
+            runtime_args! {
+                "token_id" => token_id,
+            },
+        )
+    }
+
+    // swaps token0 for token1 through the router, moving the price down
+    pub fn swap_exact_input(&mut self, amount_in: U256) {
+        let tc = &mut self.tc;
+        let data = ExactInputParams {
+            path: encode_path(vec![tc.token0, tc.token1], vec![FEE_MEDIUM]),
+            recipient: Key::from(other()),
+            deadline: 99999999999_u64,
+            amount_in,
+            amount_out_minimum: U256::zero(),
+            fee_recipient: null_key(),
+            fee_bips: 0,
+        };
+        tc.multicall_swap_session(
+            other(),
+            SessionBuilder::new().exact_input(data, false),
+            0.into(),
+        );
+    }
+
+    pub fn call(&mut self, account: AccountHash, entry_point: &str, args: RuntimeArgs) {
+        self.tc.test_env.call_contract(
+            Some(account),
+            self.keeper.into_hash().unwrap().into(),
+            entry_point,
+            args,
+            true,
+        );
+    }
+}
@@ -0,0 +1,188 @@
+#[cfg(test)]
+mod keeper {
+    use casper_types::{runtime_args, RuntimeArgs, U256};
+    use contract_utilities::helpers::null_key;
+
+    use crate::{
+        keeper::fixture::{setup_keeper_fixture, MAX_KEEPER_FEE_BIPS, TWAP_DURATION},
+        utils::{expand_to_18_decimals, other, wallet},
+    };
+
+    #[test]
+    fn test_register_escrows_the_position() {
+        let mut kc = setup_keeper_fixture();
+        let token_id = kc.mint_position(expand_to_18_decimals(1));
+        let params = kc.trigger_params(token_id, -1000, null_key());
+        kc.register(params);
+        assert_eq!(kc.tc.cep47_balance_of(kc.keeper), U256::one());
+        assert_eq!(kc.tc.cep47_balance_of(wallet().into()), U256::zero());
+
+        kc.call(
+            wallet(),
+            "cancel_trigger",
+            runtime_args! {
+                "token_id" => token_id,
+            },
+        );
+        assert_eq!(kc.tc.cep47_balance_of(kc.keeper), U256::zero());
+        assert_eq!(kc.tc.cep47_balance_of(wallet().into()), U256::one());
+    }
+
+    #[test]
+    fn test_stop_loss_swaps_proceeds_and_pays_keeper() {
+        let mut kc = setup_keeper_fixture();
+        kc.mint_position(expand_to_18_decimals(10));
+        let token_id = kc.mint_position(expand_to_18_decimals(1) / 10);
+        let params = kc.trigger_params(token_id, -1000, kc.tc.token1);
+        kc.register(params);
+
+        kc.tc.test_env.advance_block_time_by(100);
+        kc.swap_exact_input(expand_to_18_decimals(2));
+        kc.tc.test_env.advance_block_time_by(TWAP_DURATION as u64);
+        assert!(kc.is_executable(token_id));
+
+        let (token0, token1) = (kc.tc.token0, kc.tc.token1);
+        let owner0 = kc.tc.test_env.balance_of(token0, wallet().into());
+        let owner1 = kc.tc.test_env.balance_of(token1, wallet().into());
+        let keeper1 = kc.tc.test_env.balance_of(token1, other().into());
+        kc.execute(other(), token_id);
+
+        // everything ends up in token1, split between the owner and the keeper
+        assert_eq!(kc.tc.test_env.balance_of(token0, wallet().into()), owner0);
+        let owner_received = kc.tc.test_env.balance_of(token1, wallet().into()) - owner1;
+        let keeper_fee = kc.tc.test_env.balance_of(token1, other().into()) - keeper1;
+        assert!(keeper_fee > U256::zero());
+        assert_eq!(
+            keeper_fee,
+            (owner_received + keeper_fee) * MAX_KEEPER_FEE_BIPS / 10000
+        );
+
+        // the emptied position goes back to its owner
+        assert_eq!(kc.tc.cep47_balance_of(kc.keeper), U256::zero());
+        assert_eq!(kc.tc.position(token_id).liquidity.as_u128(), 0);
+        assert!(!kc.is_executable(token_id));
+    }
+
+    #[test]
+    fn test_execute_without_swap_returns_both_tokens() {
+        let mut kc = setup_keeper_fixture();
+        kc.mint_position(expand_to_18_decimals(10));
+        let token_id = kc.mint_position(expand_to_18_decimals(1) / 10);
+        let mut params = kc.trigger_params(token_id, -1000, null_key());
+        params.keeper_fee_bips = 0;
+        kc.register(params);
+
+        kc.tc.test_env.advance_block_time_by(100);
+        kc.swap_exact_input(expand_to_18_decimals(2));
+        kc.tc.test_env.advance_block_time_by(TWAP_DURATION as u64);
+
+        let (token0, token1) = (kc.tc.token0, kc.tc.token1);
+        let owner0 = kc.tc.test_env.balance_of(token0, wallet().into());
+        let owner1 = kc.tc.test_env.balance_of(token1, wallet().into());
+        let keeper0 = kc.tc.test_env.balance_of(token0, other().into());
+        kc.execute(other(), token_id);
+
+        assert!(kc.tc.test_env.balance_of(token0, wallet().into()) > owner0);
+        assert!(kc.tc.test_env.balance_of(token1, wallet().into()) > owner1);
+        assert_eq!(kc.tc.test_env.balance_of(token0, other().into()), keeper0);
+    }
+
+    #[test]
+    fn test_take_profit_stays_armed_when_price_falls() {
+        let mut kc = setup_keeper_fixture();
+        kc.mint_position(expand_to_18_decimals(10));
+        let token_id = kc.mint_position(expand_to_18_decimals(1) / 10);
+        let mut params = kc.trigger_params(token_id, 1000, null_key());
+        params.trigger_below = false;
+        kc.register(params);
+
+        kc.tc.test_env.advance_block_time_by(100);
+        kc.swap_exact_input(expand_to_18_decimals(2));
+        kc.tc.test_env.advance_block_time_by(TWAP_DURATION as u64);
+        assert!(!kc.is_executable(token_id));
+    }
+
+    #[test]
+    #[should_panic = "User(15087)"]
+    fn test_fails_on_spot_move_before_twap_follows() {
+        let mut kc = setup_keeper_fixture();
+        kc.mint_position(expand_to_18_decimals(10));
+        let token_id = kc.mint_position(expand_to_18_decimals(1) / 10);
+        let params = kc.trigger_params(token_id, -1000, kc.tc.token1);
+        kc.register(params);
+
+        kc.tc.test_env.advance_block_time_by(100);
+        kc.swap_exact_input(expand_to_18_decimals(2));
+        kc.execute(other(), token_id);
+    }
+
+    // the withdrawal is bounded at the twap even when nothing is swapped
+    #[test]
+    #[should_panic = "User(15050)"]
+    fn test_fails_on_spot_move_sandwiching_the_withdrawal() {
+        let mut kc = setup_keeper_fixture();
+        kc.mint_position(expand_to_18_decimals(10));
+        let token_id = kc.mint_position(expand_to_18_decimals(1) / 10);
+        kc.register(kc.trigger_params(token_id, -1000, null_key()));
+
+        kc.tc.test_env.advance_block_time_by(100);
+        kc.swap_exact_input(expand_to_18_decimals(2));
+        kc.tc.test_env.advance_block_time_by(TWAP_DURATION as u64);
+        assert!(kc.is_executable(token_id));
+        kc.swap_exact_input(expand_to_18_decimals(5));
+        kc.execute(other(), token_id);
+    }
+
+    #[test]
+    #[should_panic = "User(15088)"]
+    fn test_only_owner_cancels() {
+        let mut kc = setup_keeper_fixture();
+        let token_id = kc.mint_position(expand_to_18_decimals(1));
+        let params = kc.trigger_params(token_id, -1000, null_key());
+        kc.register(params);
+        kc.call(
+            other(),
+            "cancel_trigger",
+            runtime_args! {
+                "token_id" => token_id,
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15089)"]
+    fn test_keeper_fee_is_capped() {
+        let mut kc = setup_keeper_fixture();
+        let token_id = kc.mint_position(expand_to_18_decimals(1));
+        let mut params = kc.trigger_params(token_id, -1000, null_key());
+        params.keeper_fee_bips = MAX_KEEPER_FEE_BIPS + 1;
+        kc.register(params);
+    }
+
+    #[test]
+    #[should_panic = "User(15085)"]
+    fn test_swap_to_must_be_a_pool_token() {
+        let mut kc = setup_keeper_fixture();
+        let token_id = kc.mint_position(expand_to_18_decimals(1));
+        let params = kc.trigger_params(token_id, -1000, kc.tc.token2);
+        kc.register(params);
+    }
+
+    #[test]
+    fn test_owner_sets_max_keeper_fee() {
+        let mut kc = setup_keeper_fixture();
+        kc.call(
+            wallet(),
+            "set_max_keeper_fee_bips",
+            runtime_args! {
+                "max_keeper_fee_bips" => 250_u32,
+            },
+        );
+        let max_keeper_fee_bips: u32 = kc.tc.test_env.call_view_function(
+            kc.keeper,
+            "get_max_keeper_fee_bips",
+            runtime_args! {},
+        );
+        assert_eq!(max_keeper_fee_bips, 250);
+    }
+}
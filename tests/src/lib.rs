@@ -2,7 +2,13 @@
 extern crate alloc;
 mod constants;
 mod gas;
+mod json;
+mod keeper;
+mod locker;
 mod math;
+mod migrator;
 mod pool;
 mod router;
+mod staker;
 mod utils;
+mod vault;
@@ -0,0 +1,155 @@
+#![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U128, U256};
+use contract_utilities::helpers::null_key;
+use types::{session::SessionBuilder, ExactInputParams, Lock, MintParams};
+
+use crate::{
+    constants,
+    pool::fixture::{get_tick_spacing, FEE_MEDIUM},
+    router::fixture::{setup_fixture, TestContext},
+    utils::{self, encode_path, encode_price_sqrt, get_max_tick, get_min_tick, other, wallet},
+};
+
+pub const LOCK_DURATION: u64 = 1000;
+
+pub struct LockerContext {
+    pub tc: TestContext,
+    pub locker: Key,
+}
+
+pub fn setup_locker_fixture() -> LockerContext {
+    let mut tc = setup_fixture();
+    tc.test_env.set_block_time(1000);
+    tc.multicall_liquidity_session(
+        wallet(),
+        SessionBuilder::new().create_and_initialize_pool_if_necessary(
+            tc.token0,
+            tc.token1,
+            FEE_MEDIUM,
+            encode_price_sqrt(1, 1),
+        ),
+        0.into(),
+    );
+
+    tc.test_env.deploy_contract(
+        Some(wallet()),
+        constants::LOCKER,
+        runtime_args! {
+            "contract_name" => "locker",
+            "router" => tc.router,
+        },
+    );
+    let locker = tc.test_env.get_contract_package_hash(
+        wallet(),
+        &utils::get_contract_package_hash_key("locker".to_string()),
+    );
+
+    LockerContext { tc, locker }
+}
+
+impl LockerContext {
+    pub fn now(&self) -> u64 {
+        self.tc.test_env.block_time
+    }
+
+    // full range position owned by the wallet, returns its token id
+    pub fn mint_position(&mut self, amount: U256) -> U256 {
+        let tc = &mut self.tc;
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new().mint(
+                MintParams {
+                    token0: tc.token0,
+                    token1: tc.token1,
+                    fee: FEE_MEDIUM,
+                    tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                    tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                    amount0_desired: amount,
+                    amount1_desired: amount,
+                    amount0_min: U256::zero(),
+                    amount1_min: U256::zero(),
+                    recipient: Key::from(wallet()),
+                    deadline: 99999999999_u64,
+                },
+                false,
+            ),
+            0.into(),
+        );
+        let count = tc.cep47_balance_of(wallet().into());
+        tc.token_of_owner_by_index(wallet().into(), count - 1)
+    }
+
+    // locks the position for LOCK_DURATION with other as the beneficiary
+    pub fn lock(&mut self, token_id: U256, vesting: bool) {
+        self.tc.test_env.call_contract(
+            Some(wallet()),
+            self.tc.router.into_hash().unwrap().into(),
+            "approve",
+            runtime_args! {
+                "spender" => self.locker,
+                "token_ids" => vec![token_id],
+            },
+            true,
+        );
+        let unlock_time = self.now() + LOCK_DURATION;
+        self.call(
+            wallet(),
+            "lock",
+            runtime_args! {
+                "token_id" => token_id,
+                "beneficiary" => Key::from(other()),
+                "unlock_time" => unlock_time,
+                "vesting" => vesting,
+            },
+        );
+    }
+
+    pub fn get_lock(&mut self, token_id: U256) -> Lock {
+        self.tc.test_env.call_view_function(
+            self.locker,
+            "get_lock",
+            runtime_args! {
+                "token_id" => token_id,
+            },
+        )
+    }
+
+    pub fn withdrawable_liquidity(&mut self, token_id: U256) -> U128 {
+        self.tc.test_env.call_view_function(
+            self.locker,
+            "get_withdrawable_liquidity",
+            runtime_args! {
+                "token_id" => token_id,
+            },
+        )
+    }
+
+    // swaps token0 for token1 through the router
+    pub fn swap_exact_input(&mut self, amount_in: U256) {
+        let tc = &mut self.tc;
+        let data = ExactInputParams {
+            path: encode_path(vec![tc.token0, tc.token1], vec![FEE_MEDIUM]),
+            recipient: Key::from(other()),
+            deadline: 99999999999_u64,
+            amount_in,
+            amount_out_minimum: U256::zero(),
+            fee_recipient: null_key(),
+            fee_bips: 0,
+        };
+        tc.multicall_swap_session(
+            other(),
+            SessionBuilder::new().exact_input(data, false),
+            0.into(),
+        );
+    }
+
+    pub fn call(&mut self, account: AccountHash, entry_point: &str, args: RuntimeArgs) {
+        self.tc.test_env.call_contract(
+            Some(account),
+            self.locker.into_hash().unwrap().into(),
+            entry_point,
+            args,
+            true,
+        );
+    }
+}
@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod locker {
+    use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+    use contract_utilities::helpers::null_key;
+
+    use crate::{
+        locker::fixture::{setup_locker_fixture, LOCK_DURATION},
+        utils::{expand_to_18_decimals, other, wallet},
+    };
+
+    #[test]
+    fn test_lock_takes_custody_until_unlock_time() {
+        let mut lc = setup_locker_fixture();
+        let token_id = lc.mint_position(expand_to_18_decimals(1));
+        lc.lock(token_id, false);
+        assert_eq!(lc.tc.cep47_balance_of(lc.locker), U256::one());
+        let lock = lc.get_lock(token_id);
+        assert_eq!(lock.owner, Key::from(wallet()));
+        assert_eq!(lock.unlock_time, lc.now() + LOCK_DURATION);
+
+        lc.tc.test_env.advance_block_time_by(LOCK_DURATION);
+        lc.call(other(), "unlock", runtime_args! { "token_id" => token_id });
+        assert_eq!(lc.tc.cep47_balance_of(lc.locker), U256::zero());
+        assert_eq!(lc.tc.cep47_balance_of(other().into()), U256::one());
+        assert_eq!(lc.get_lock(token_id).owner, null_key());
+    }
+
+    #[test]
+    #[should_panic = "User(15093)"]
+    fn test_fails_to_unlock_early() {
+        let mut lc = setup_locker_fixture();
+        let token_id = lc.mint_position(expand_to_18_decimals(1));
+        lc.lock(token_id, false);
+        lc.tc.test_env.advance_block_time_by(LOCK_DURATION - 1);
+        lc.call(other(), "unlock", runtime_args! { "token_id" => token_id });
+    }
+
+    #[test]
+    fn test_beneficiary_collects_fees_during_lock() {
+        let mut lc = setup_locker_fixture();
+        let token_id = lc.mint_position(expand_to_18_decimals(1));
+        lc.lock(token_id, false);
+        lc.swap_exact_input(expand_to_18_decimals(1) / 10);
+
+        let token0 = lc.tc.token0;
+        let before = lc.tc.test_env.balance_of(token0, other().into());
+        lc.call(
+            other(),
+            "collect_fees",
+            runtime_args! {
+                "token_id" => token_id,
+                "recipient" => Key::from(other()),
+            },
+        );
+        // the position was the only liquidity, it earned the 0.3% fee on the input
+        let fees = lc.tc.test_env.balance_of(token0, other().into()) - before;
+        assert!(fees > U256::zero());
+        assert!(fees <= expand_to_18_decimals(1) * 3 / 10000);
+        assert_eq!(lc.tc.cep47_balance_of(lc.locker), U256::one());
+    }
+
+    #[test]
+    fn test_vesting_releases_liquidity_linearly() {
+        let mut lc = setup_locker_fixture();
+        let token_id = lc.mint_position(expand_to_18_decimals(1));
+        let liquidity = lc.tc.position(token_id).liquidity.as_u128();
+        lc.lock(token_id, true);
+
+        lc.tc.test_env.advance_block_time_by(LOCK_DURATION / 4);
+        assert_eq!(lc.withdrawable_liquidity(token_id).as_u128(), liquidity / 4);
+        let (token0, token1) = (lc.tc.token0, lc.tc.token1);
+        let before0 = lc.tc.test_env.balance_of(token0, other().into());
+        let before1 = lc.tc.test_env.balance_of(token1, other().into());
+        lc.call(
+            other(),
+            "withdraw_vested",
+            runtime_args! {
+                "token_id" => token_id,
+                "recipient" => Key::from(other()),
+                "amount0_min" => U256::zero(),
+                "amount1_min" => U256::zero(),
+            },
+        );
+        assert_eq!(
+            lc.tc.position(token_id).liquidity.as_u128(),
+            liquidity - liquidity / 4
+        );
+        let quarter = expand_to_18_decimals(1) / 4;
+        let received0 = lc.tc.test_env.balance_of(token0, other().into()) - before0;
+        let received1 = lc.tc.test_env.balance_of(token1, other().into()) - before1;
+        assert!(received0 <= quarter && received0 + 10 > quarter);
+        assert!(received1 <= quarter && received1 + 10 > quarter);
+        assert_eq!(lc.withdrawable_liquidity(token_id).as_u128(), 0);
+    }
+
+    #[test]
+    #[should_panic = "User(15093)"]
+    fn test_fails_to_withdraw_without_vesting() {
+        let mut lc = setup_locker_fixture();
+        let token_id = lc.mint_position(expand_to_18_decimals(1));
+        lc.lock(token_id, false);
+        lc.tc.test_env.advance_block_time_by(LOCK_DURATION / 2);
+        lc.call(
+            other(),
+            "withdraw_vested",
+            runtime_args! {
+                "token_id" => token_id,
+                "recipient" => Key::from(other()),
+                "amount0_min" => U256::zero(),
+                "amount1_min" => U256::zero(),
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15094)"]
+    fn test_only_beneficiary_collects() {
+        let mut lc = setup_locker_fixture();
+        let token_id = lc.mint_position(expand_to_18_decimals(1));
+        lc.lock(token_id, false);
+        lc.call(
+            wallet(),
+            "collect_fees",
+            runtime_args! {
+                "token_id" => token_id,
+                "recipient" => Key::from(wallet()),
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15091)"]
+    fn test_fails_to_lock_in_the_past() {
+        let mut lc = setup_locker_fixture();
+        let token_id = lc.mint_position(expand_to_18_decimals(1));
+        let now = lc.now();
+        lc.call(
+            wallet(),
+            "lock",
+            runtime_args! {
+                "token_id" => token_id,
+                "beneficiary" => Key::from(other()),
+                "unlock_time" => now,
+                "vesting" => false,
+            },
+        );
+    }
+}
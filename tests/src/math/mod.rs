@@ -1,6 +1,7 @@
 pub mod bitmath_test;
 pub mod fullmath_test;
 pub mod liquidity_math_test;
+pub mod signed_int_test;
 pub mod sqrt_price_math_test;
 pub mod swap_math_test;
 pub mod test_math_session;
@@ -0,0 +1,95 @@
+use casper_types::{
+    bytesrepr::{FromBytes, ToBytes},
+    CLType, CLTyped, U128, U256,
+};
+use types::{i128::I128, i256::I256, TickInfo};
+
+#[test]
+fn test_i128_is_fixed_width() {
+    for v in [0_i128, 1, -1, i128::MAX, i128::MIN] {
+        let bytes = I128(v).to_bytes().unwrap();
+        assert!(bytes.len() == 16);
+        assert!(I128(v).serialized_length() == bytes.len());
+        let (decoded, rem) = I128::from_bytes(&bytes).unwrap();
+        assert!(decoded.0 == v && rem.is_empty());
+    }
+    assert!(I128::cl_type() == CLType::ByteArray(16));
+}
+
+#[test]
+fn test_i256_is_fixed_width() {
+    let max = I256::from(U256::MAX >> 1);
+    let values = [
+        I256::from(0),
+        I256::from(-1),
+        I256::from(i128::MIN),
+        max,
+        -max - I256::from(1),
+    ];
+    for v in values {
+        let bytes = v.to_bytes().unwrap();
+        assert!(bytes.len() == 32);
+        assert!(v.serialized_length() == bytes.len());
+        let (decoded, rem) = I256::from_bytes(&bytes).unwrap();
+        assert!(decoded == v && rem.is_empty());
+    }
+    assert!(I256::cl_type() == CLType::ByteArray(32));
+}
+
+#[test]
+fn test_i256_u256_conversions() {
+    let max = U256::MAX >> 1;
+    assert!(U256::from(I256::from(max)) == max);
+    assert!(I256::from(max) > I256::from(u128::MAX));
+    assert!(U256::from(I256::from(U256::from(12345))) == U256::from(12345));
+}
+
+#[test]
+#[should_panic]
+fn test_u256_above_i256_max_does_not_convert() {
+    let _ = I256::from(U256::MAX);
+}
+
+#[test]
+#[should_panic]
+fn test_negative_i256_does_not_convert() {
+    let _ = U256::from(I256::from(-1));
+}
+
+#[test]
+fn test_decodes_legacy_values() {
+    // sign byte, 0 when negative, followed by the U128 magnitude
+    let mut legacy = vec![0u8];
+    legacy.append(&mut U128::from(500).to_bytes().unwrap());
+    assert!(I128::from_legacy_bytes(&legacy).unwrap().0 .0 == -500);
+
+    let mut legacy_i256 = 2u32.to_bytes().unwrap();
+    // the high word of a negative value is -1
+    legacy_i256.push(0);
+    legacy_i256.append(&mut U128::one().to_bytes().unwrap());
+    legacy_i256.append(&mut legacy.clone());
+    assert!(I256::from_legacy_bytes(&legacy_i256).unwrap().0 == I256::from(-500));
+
+    let mut legacy_tick = U128::from(500).to_bytes().unwrap();
+    legacy_tick.append(&mut legacy);
+    legacy_tick.append(&mut U256::zero().to_bytes().unwrap());
+    legacy_tick.append(&mut U256::zero().to_bytes().unwrap());
+    legacy_tick.append(&mut 7i64.to_bytes().unwrap());
+    legacy_tick.append(&mut U256::zero().to_bytes().unwrap());
+    legacy_tick.append(&mut 3u32.to_bytes().unwrap());
+    legacy_tick.append(&mut true.to_bytes().unwrap());
+    let (tick, rem) = TickInfo::from_legacy_bytes(&legacy_tick).unwrap();
+    assert!(rem.is_empty());
+    assert!(tick.liquidity_gross == U128::from(500));
+    assert!(tick.liquidity_net.0 == -500);
+    assert!(tick.tick_cumulative_outside == 7);
+    assert!(tick.initialized);
+    assert!(
+        TickInfo::from_bytes(&tick.to_bytes().unwrap())
+            .unwrap()
+            .0
+            .liquidity_net
+            .0
+            == -500
+    );
+}
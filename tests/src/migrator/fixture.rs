@@ -0,0 +1,116 @@
+#![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use types::{MigrateParams, MintResult};
+
+use crate::{
+    constants,
+    pool::fixture::{get_tick_spacing, FEE_MEDIUM},
+    router::fixture::{setup_fixture, TestContext},
+    utils::{self, encode_price_sqrt, expand_to_18_decimals, get_max_tick, get_min_tick, wallet},
+};
+
+pub struct MigratorContext {
+    pub tc: TestContext,
+    pub migrator: Key,
+    pub pair: Key,
+}
+
+pub fn setup_migrator_fixture() -> MigratorContext {
+    let mut tc = setup_fixture();
+    tc.test_env.deploy_contract(
+        Some(wallet()),
+        constants::TEST_V2_PAIR,
+        runtime_args! {
+            "contract_name" => "test_v2_pair",
+            "token0" => tc.token0,
+            "token1" => tc.token1,
+        },
+    );
+    let pair = tc.test_env.get_contract_package_hash(
+        wallet(),
+        &utils::get_contract_package_hash_key("test_v2_pair".to_string()),
+    );
+    tc.test_env.deploy_contract(
+        Some(wallet()),
+        constants::MIGRATOR,
+        runtime_args! {
+            "contract_name" => "migrator",
+            "router" => tc.router,
+            "v2_router" => pair,
+            "v2_factory" => pair,
+        },
+    );
+    let migrator = tc.test_env.get_contract_package_hash(
+        wallet(),
+        &utils::get_contract_package_hash_key("migrator".to_string()),
+    );
+
+    // the wallet provides 10 of each token to the v2 pair and gets 10 lp tokens
+    tc.test_env.approve(tc.token0, wallet(), pair, U256::MAX);
+    tc.test_env.approve(tc.token1, wallet(), pair, U256::MAX);
+    tc.test_env.call_contract(
+        Some(wallet()),
+        pair.into_hash().unwrap().into(),
+        "add_liquidity",
+        runtime_args! {
+            "amount0" => expand_to_18_decimals(10),
+            "amount1" => expand_to_18_decimals(10),
+            "to" => Key::from(wallet()),
+        },
+        true,
+    );
+    tc.test_env.approve(pair, wallet(), migrator, U256::MAX);
+
+    MigratorContext { tc, migrator, pair }
+}
+
+impl MigratorContext {
+    pub fn migrate_params(&self, liquidity: U256, percentage: u8) -> MigrateParams {
+        MigrateParams {
+            pair: self.pair,
+            liquidity_to_migrate: liquidity,
+            amount0_v2_min: U256::zero(),
+            amount1_v2_min: U256::zero(),
+            percentage_to_migrate: percentage,
+            token0: self.tc.token0,
+            token1: self.tc.token1,
+            fee: FEE_MEDIUM,
+            tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+            tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+            sqrt_price_x96: encode_price_sqrt(1, 1),
+            amount0_min: U256::zero(),
+            amount1_min: U256::zero(),
+            recipient: wallet().into(),
+            deadline: 99999999999_u64,
+        }
+    }
+
+    pub fn migrate(&mut self, params: MigrateParams) {
+        self.tc.test_env.call_contract(
+            Some(wallet()),
+            self.migrator.into_hash().unwrap().into(),
+            "migrate",
+            runtime_args! {
+                "params" => params,
+            },
+            true,
+        );
+    }
+
+    pub fn lp_balance(&mut self) -> U256 {
+        self.tc.test_env.balance_of(self.pair, wallet().into())
+    }
+
+    pub fn balances(&mut self) -> (U256, U256) {
+        let (token0, token1) = (self.tc.token0, self.tc.token1);
+        (
+            self.tc.test_env.balance_of(token0, wallet().into()),
+            self.tc.test_env.balance_of(token1, wallet().into()),
+        )
+    }
+
+    pub fn last_token_id(&mut self) -> U256 {
+        let count = self.tc.cep47_balance_of(wallet().into());
+        self.tc.token_of_owner_by_index(wallet().into(), count - 1)
+    }
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod migrator {
+    use casper_types::U256;
+    use types::session::SessionBuilder;
+
+    use crate::{
+        migrator::fixture::setup_migrator_fixture,
+        pool::fixture::FEE_MEDIUM,
+        utils::{encode_price_sqrt, expand_to_18_decimals, wallet},
+    };
+
+    #[test]
+    fn test_migrates_all_liquidity_into_a_new_pool() {
+        let mut mc = setup_migrator_fixture();
+        let (before0, before1) = mc.balances();
+        let params = mc.migrate_params(expand_to_18_decimals(5), 100);
+        mc.migrate(params);
+
+        assert_eq!(mc.lp_balance(), expand_to_18_decimals(5));
+        assert_eq!(mc.tc.cep47_balance_of(wallet().into()), U256::one());
+        let token_id = mc.last_token_id();
+        let position = mc.tc.position(token_id);
+        assert!(position.liquidity.as_u128() > 0);
+        assert_eq!(position.fee, FEE_MEDIUM);
+
+        // only rounding dust is refunded at the same price
+        let (after0, after1) = mc.balances();
+        assert!(after0 - before0 < 10.into());
+        assert!(after1 - before1 < 10.into());
+        assert_eq!(
+            mc.tc.test_env.balance_of(mc.tc.token0, mc.migrator),
+            U256::zero()
+        );
+    }
+
+    #[test]
+    fn test_refunds_the_part_not_migrated() {
+        let mut mc = setup_migrator_fixture();
+        let (before0, before1) = mc.balances();
+        let params = mc.migrate_params(expand_to_18_decimals(4), 50);
+        mc.migrate(params);
+
+        // 4 lp tokens are worth 4 of each token, half of it comes back
+        let (after0, after1) = mc.balances();
+        let half = expand_to_18_decimals(2);
+        assert!(after0 - before0 >= half);
+        assert!(after0 - before0 < half + 10);
+        assert!(after1 - before1 >= half);
+        assert!(after1 - before1 < half + 10);
+    }
+
+    #[test]
+    fn test_refunds_excess_when_the_pool_price_differs() {
+        let mut mc = setup_migrator_fixture();
+        let tc = &mut mc.tc;
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new().create_and_initialize_pool_if_necessary(
+                tc.token0,
+                tc.token1,
+                FEE_MEDIUM,
+                encode_price_sqrt(4, 1),
+            ),
+            0.into(),
+        );
+        let (before0, before1) = mc.balances();
+        let params = mc.migrate_params(expand_to_18_decimals(4), 100);
+        mc.migrate(params);
+
+        // all the token1 is used, three quarters of the token0 is refunded
+        let (after0, after1) = mc.balances();
+        let refund0 = after0 - before0;
+        assert!(refund0 > expand_to_18_decimals(3) - 1000000);
+        assert!(refund0 < expand_to_18_decimals(3) + 1000000);
+        assert!(after1 - before1 < 10.into());
+    }
+
+    #[test]
+    #[should_panic = "User(15090)"]
+    fn test_fails_with_invalid_percentage() {
+        let mut mc = setup_migrator_fixture();
+        let params = mc.migrate_params(expand_to_18_decimals(1), 101);
+        mc.migrate(params);
+    }
+
+    #[test]
+    #[should_panic = "User(15050)"]
+    fn test_fails_below_min_amounts() {
+        let mut mc = setup_migrator_fixture();
+        let mut params = mc.migrate_params(expand_to_18_decimals(2), 50);
+        params.amount0_min = expand_to_18_decimals(2);
+        mc.migrate(params);
+    }
+
+    #[test]
+    #[should_panic = "User(15065)"]
+    fn test_fails_below_v2_min_amounts() {
+        let mut mc = setup_migrator_fixture();
+        let mut params = mc.migrate_params(expand_to_18_decimals(2), 50);
+        params.amount1_v2_min = expand_to_18_decimals(3);
+        mc.migrate(params);
+    }
+
+    #[test]
+    #[should_panic = "User(15090)"]
+    fn test_fails_on_a_pair_the_factory_does_not_know() {
+        let mut mc = setup_migrator_fixture();
+        let mut params = mc.migrate_params(expand_to_18_decimals(1), 100);
+        params.pair = mc.tc.token0;
+        mc.migrate(params);
+    }
+}
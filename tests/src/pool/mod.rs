@@ -3,6 +3,7 @@ pub mod pool_test;
 pub mod pool_test_burn;
 pub mod pool_test_collect;
 pub mod pool_test_fee_protocol;
+pub mod pool_test_indexer;
 pub mod pool_test_miscellaneous_mint_tests;
 pub mod pool_test_observe;
 pub mod pool_test_post_initialize_medium_fee;
@@ -1,7 +1,7 @@
 #![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
 use super::fixture::setup;
 use crate::utils::{call_and_get_any, get_max_liquidity_per_tick};
-use casper_types::{runtime_args, Key, RuntimeArgs, U128};
+use casper_types::{runtime_args, ExecutableDeployItem, Key, RuntimeArgs, U128};
 use types::Observation;
 
 #[test]
@@ -837,3 +837,50 @@ mod test_mint {
         }
     }
 }
+
+#[test]
+fn test_new_pools_use_the_fixed_width_tick_encoding() {
+    let mut tc = setup();
+    let tick_encoding: u8 = tc
+        .test_env
+        .call_view_function(tc.pool, "get_tick_encoding", runtime_args! {});
+    assert!(tick_encoding == 1);
+}
+
+// only pools still on the legacy encoding have ticks to migrate
+#[test]
+#[should_panic = "User(15101)"]
+fn test_migrate_ticks_fails_on_a_new_pool() {
+    let mut tc = setup();
+    tc.test_env.call_contract(
+        Some(crate::utils::wallet()),
+        tc.pool.into_hash().unwrap().into(),
+        "migrate_ticks",
+        runtime_args! {
+            "ticks" => vec![0_i32],
+            "complete" => true,
+        },
+        true,
+    );
+}
+
+// the sdk deploy has to carry exactly what the entry point takes
+#[test]
+fn test_sdk_migrate_ticks_matches_the_entry_point() {
+    let mut tc = setup();
+    let deploy = sdk::pool::migrate_ticks(tc.pool.into_hash().unwrap().into(), vec![0], true);
+    let (entry_point, args) = match deploy {
+        ExecutableDeployItem::StoredVersionedContractByHash {
+            entry_point, args, ..
+        } => (entry_point, args),
+        _ => panic!("expected a versioned contract call"),
+    };
+    let mut sent: Vec<String> = args
+        .named_args()
+        .map(|arg| arg.name().to_string())
+        .collect();
+    let mut expected = tc.test_env.get_entry_point_args(tc.pool, &entry_point);
+    sent.sort();
+    expected.sort();
+    assert!(sent == expected);
+}
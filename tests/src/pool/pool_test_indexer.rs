@@ -0,0 +1,116 @@
+#![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+
+#[cfg(test)]
+mod test_indexer {
+    use std::ops::Div;
+
+    use casper_types::{Key, U128};
+    use indexer::{Indexer, PoolEvent, PoolState};
+
+    use crate::{
+        pool::fixture::{setup, TestContext, FEE_MEDIUM},
+        utils::{encode_price_sqrt, expand_to_18_decimals, wallet},
+    };
+
+    fn before_each() -> TestContext {
+        let mut tc = setup();
+        tc.initialize_pool_price(encode_price_sqrt(1, 10));
+        tc.mint(Key::from(wallet()), tc.min_tick, tc.max_tick, 3161.into());
+        tc
+    }
+
+    fn replay(tc: &mut TestContext) -> Indexer {
+        let mut indexer = Indexer::new();
+        let factory_events: Vec<PoolEvent> = tc.test_env.get_events(tc.factory);
+        indexer.replay_pool_events(tc.factory, &factory_events);
+        let pool_events: Vec<PoolEvent> = tc.test_env.get_events(tc.pool);
+        indexer.replay_pool_events(tc.pool, &pool_events);
+        indexer
+    }
+
+    fn assert_consistent(tc: &mut TestContext, pool: &PoolState) {
+        let slot0 = tc.get_slot0();
+        let mismatches = pool.check(&slot0, |tick| tc.get_tick(tick));
+        assert!(mismatches.is_empty(), "{:?}", mismatches);
+        assert_eq!(pool.check_liquidity(tc.get_liquidity()), None);
+        for (&(owner, tick_lower, tick_upper), position) in pool.positions.iter() {
+            let info = tc.get_position(owner, tick_lower, tick_upper);
+            assert_eq!(position.liquidity, info.liquidity);
+        }
+    }
+
+    #[test]
+    fn test_replays_crossed_ticks_burns_collects_and_flashes() {
+        let mut tc = before_each();
+        let (tick_lower, tick_upper) = (-22980, -22020);
+        tc.mint(
+            Key::from(wallet()),
+            tick_lower,
+            tick_upper,
+            expand_to_18_decimals(1).as_u128().into(),
+        );
+        tc.swap_to_higher_price(encode_price_sqrt(1, 8), wallet().into());
+        tc.swap_to_lower_price(encode_price_sqrt(10, 95), wallet().into());
+        tc.burn(
+            wallet(),
+            tick_lower,
+            tick_upper,
+            expand_to_18_decimals(1).div(2).as_u128().into(),
+        );
+        tc.collect(
+            wallet().into(),
+            tick_lower,
+            tick_upper,
+            U128::MAX,
+            U128::MAX,
+        );
+        let before_flash = replay(&mut tc).pool(tc.pool).unwrap().fees0;
+        tc.flash(1000.into(), 2000.into(), wallet().into(), None, None);
+
+        let indexer = replay(&mut tc);
+        let pool = indexer.pool(tc.pool).unwrap();
+        assert_eq!(pool.token0, tc.token0);
+        assert_eq!(pool.fee, FEE_MEDIUM);
+        assert_eq!(pool.swaps, 2);
+        assert!(pool.ticks.contains_key(&tick_lower) && pool.ticks.contains_key(&tick_upper));
+        assert_eq!(pool.fees0 - before_flash, 3.into());
+        let position = pool
+            .position(wallet().into(), tick_lower, tick_upper)
+            .unwrap();
+        assert!(!position.withdrawn0.is_zero() && !position.withdrawn1.is_zero());
+        let (_, fees1) = position.fees_collected();
+        assert!(!fees1.is_zero());
+        assert_consistent(&mut tc, pool);
+    }
+
+    #[test]
+    fn test_replays_swaps_with_the_protocol_fee_on() {
+        let mut tc = before_each();
+        tc.set_fee_protocol(6, 6);
+        tc.mint(
+            Key::from(wallet()),
+            tc.min_tick + tc.tick_spacing,
+            tc.max_tick - tc.tick_spacing,
+            expand_to_18_decimals(1).as_u128().into(),
+        );
+        tc.swap_exact_0_for_1(expand_to_18_decimals(1).div(10), wallet().into(), None);
+        tc.swap_exact_1_for_0(expand_to_18_decimals(1).div(100), wallet().into(), None);
+        let protocol_fees = tc.get_protocol_fees();
+        tc.collect_protocol(wallet().into(), U128::MAX, U128::MAX);
+        let remaining = tc.get_protocol_fees();
+
+        let indexer = replay(&mut tc);
+        let pool = indexer.pool(tc.pool).unwrap();
+        assert_eq!((pool.fee_protocol0, pool.fee_protocol1), (6, 6));
+        assert!(pool.volume0 >= expand_to_18_decimals(1).div(10));
+        assert_eq!(
+            pool.protocol_fees_collected0,
+            (protocol_fees.token0 - remaining.token0).as_u128().into()
+        );
+        assert_eq!(
+            pool.protocol_fees_collected1,
+            (protocol_fees.token1 - remaining.token1).as_u128().into()
+        );
+        assert_consistent(&mut tc, pool);
+    }
+}
@@ -4,7 +4,10 @@ use casper_types::{
 };
 use std::{collections::BTreeMap, ops::Div};
 use test_env::env::TestEnv;
-use types::Position;
+use types::{
+    session::{SessionBuilder, SessionResult},
+    CompositePosition, CompositeTotals, Position,
+};
 
 use crate::{
     constants,
@@ -149,6 +152,15 @@ pub fn setup_fixture() -> TestContext {
         wallet(),
         &utils::get_contract_package_hash_key("wcspr".to_string()),
     );
+    test_env.call_contract(
+        Some(wallet()),
+        router.into_hash().unwrap().into(),
+        "set_wcspr",
+        runtime_args! {
+            "wcspr" => wcspr
+        },
+        true,
+    );
 
     TestContext {
         test_env,
@@ -264,15 +276,37 @@ impl TestContext {
     pub fn multicall_liquidity_session(
         &mut self,
         caller: AccountHash,
-        entry_points: Vec<&str>,
+        session: SessionBuilder,
+        amount: U512,
+    ) {
+        self.deploy_session(
+            caller,
+            constants::LIQUIDITY_SESSION,
+            session.build(),
+            amount,
+        );
+    }
+
+    pub fn multicall_swap_session(
+        &mut self,
+        caller: AccountHash,
+        session: SessionBuilder,
+        amount: U512,
+    ) {
+        self.deploy_session(caller, constants::SWAP_SESSION, session.build(), amount);
+    }
+
+    pub fn deploy_session(
+        &mut self,
+        caller: AccountHash,
+        session_wasm: &str,
         datas: Vec<Bytes>,
         amount: U512,
     ) {
         self.test_env.deploy_contract(
             Some(caller),
-            constants::LIQUIDITY_SESSION,
+            session_wasm,
             runtime_args! {
-                "entry_points" => entry_points,
                 "datas" => datas,
                 "router" => self.router,
                 "wcspr" => self.wcspr,
@@ -281,6 +315,28 @@ impl TestContext {
         );
     }
 
+    pub fn session_result(&self, caller: AccountHash) -> SessionResult {
+        self.test_env.get_account_result(caller, "session_result")
+    }
+
+    pub fn partner_fees(&mut self, fee_recipient: Key, token: Key) -> U256 {
+        let partner_fee_id: String = self.test_env.call_view_function(
+            self.router,
+            "get_partner_fee_id",
+            runtime_args! {
+                "fee_recipient" => fee_recipient,
+                "token" => token,
+            },
+        );
+        self.test_env.call_view_function(
+            self.router,
+            "get_partner_fees",
+            runtime_args! {
+                "partner_fee_id" => partner_fee_id,
+            },
+        )
+    }
+
     pub fn cep47_balance_of(&mut self, owner: Key) -> U256 {
         self.test_env.call_view_function(
             self.router,
@@ -312,4 +368,24 @@ impl TestContext {
             },
         )
     }
+
+    pub fn composite_position(&mut self, token_id: U256) -> CompositePosition {
+        self.test_env.call_view_function(
+            self.router,
+            "get_composite_position",
+            runtime_args! {
+                "token_id" => token_id,
+            },
+        )
+    }
+
+    pub fn composite_totals(&mut self, token_id: U256) -> CompositeTotals {
+        self.test_env.call_view_function(
+            self.router,
+            "get_composite_totals",
+            runtime_args! {
+                "token_id" => token_id,
+            },
+        )
+    }
 }
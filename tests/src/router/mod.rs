@@ -2,3 +2,5 @@ pub mod fixture;
 pub mod liquidity_amounts;
 pub mod non_fungible_position_manager;
 pub mod path;
+pub mod session;
+pub mod swap_router;
@@ -6,8 +6,7 @@ fn token_id() -> U256 {
 }
 #[cfg(test)]
 mod create_and_initialize_pool_if_necessary {
-    use casper_types::bytesrepr::Bytes;
-    use contract_utilities::helpers;
+    use types::session::SessionBuilder;
 
     use crate::{
         pool::fixture::FEE_MEDIUM,
@@ -46,13 +45,12 @@ mod create_and_initialize_pool_if_necessary {
         let mut tc = setup_fixture();
         tc.multicall_liquidity_session(
             wallet(),
-            vec!["create_and_initialize_pool_if_necessary"],
-            vec![Bytes::from(helpers::encode_4(
-                &tc.token0,
-                &tc.token1,
-                &FEE_MEDIUM,
-                &encode_price_sqrt(1, 1),
-            ))],
+            SessionBuilder::new().create_and_initialize_pool_if_necessary(
+                tc.token0,
+                tc.token1,
+                FEE_MEDIUM,
+                encode_price_sqrt(1, 1),
+            ),
             0.into(),
         );
     }
@@ -61,7 +59,7 @@ mod create_and_initialize_pool_if_necessary {
 #[cfg(test)]
 mod mint {
     use casper_types::{Key, U256};
-    use contract_utilities::helpers;
+    use types::{session::SessionBuilder, MintParams};
 
     use crate::{
         pool::fixture::{get_tick_spacing, FEE_MEDIUM},
@@ -75,22 +73,22 @@ mod mint {
         let mut tc = setup_fixture();
         tc.multicall_liquidity_session(
             wallet(),
-            vec!["mint"],
-            vec![helpers::encode_12(
-                &tc.token0,
-                &tc.token1,
-                &false,
-                &FEE_MEDIUM,
-                &get_min_tick(get_tick_spacing(FEE_MEDIUM)),
-                &get_max_tick(get_tick_spacing(FEE_MEDIUM)),
-                &U256::from(100),
-                &U256::from(100),
-                &U256::from(0),
-                &U256::from(0),
-                &Key::from(wallet()),
-                &99999999999_u64,
-            )
-            .into()],
+            SessionBuilder::new().mint(
+                MintParams {
+                    token0: tc.token0,
+                    token1: tc.token1,
+                    fee: FEE_MEDIUM,
+                    tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                    tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                    amount0_desired: U256::from(100),
+                    amount1_desired: U256::from(100),
+                    amount0_min: U256::from(0),
+                    amount1_min: U256::from(0),
+                    recipient: Key::from(wallet()),
+                    deadline: 99999999999_u64,
+                },
+                false,
+            ),
             0.into(),
         );
     }
@@ -101,35 +99,33 @@ mod mint {
 
         tc.multicall_liquidity_session(
             wallet(),
-            vec!["create_and_initialize_pool_if_necessary"],
-            vec![helpers::encode_4(
-                &tc.token0,
-                &tc.token1,
-                &FEE_MEDIUM,
-                &encode_price_sqrt(1, 1),
-            )
-            .into()],
+            SessionBuilder::new().create_and_initialize_pool_if_necessary(
+                tc.token0,
+                tc.token1,
+                FEE_MEDIUM,
+                encode_price_sqrt(1, 1),
+            ),
             0.into(),
         );
 
         tc.multicall_liquidity_session(
             wallet(),
-            vec!["mint"],
-            vec![helpers::encode_12(
-                &tc.token0,
-                &tc.token1,
-                &false,
-                &FEE_MEDIUM,
-                &get_min_tick(get_tick_spacing(FEE_MEDIUM)),
-                &get_max_tick(get_tick_spacing(FEE_MEDIUM)),
-                &U256::from(15),
-                &U256::from(15),
-                &U256::from(0),
-                &U256::from(0),
-                &Key::from(other()),
-                &99999999999_u64,
-            )
-            .into()],
+            SessionBuilder::new().mint(
+                MintParams {
+                    token0: tc.token0,
+                    token1: tc.token1,
+                    fee: FEE_MEDIUM,
+                    tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                    tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                    amount0_desired: U256::from(15),
+                    amount1_desired: U256::from(15),
+                    amount0_min: U256::from(0),
+                    amount1_min: U256::from(0),
+                    recipient: Key::from(other()),
+                    deadline: 99999999999_u64,
+                },
+                false,
+            ),
             0.into(),
         );
 
@@ -156,31 +152,29 @@ mod mint {
         let mut tc = setup_fixture();
         tc.multicall_liquidity_session(
             wallet(),
-            vec!["create_and_initialize_pool_if_necessary", "mint"],
-            vec![
-                helpers::encode_4(
-                    &tc.token0,
-                    &tc.token1,
-                    &FEE_MEDIUM,
-                    &encode_price_sqrt(1, 1),
-                )
-                .into(),
-                helpers::encode_12(
-                    &tc.token0,
-                    &tc.token1,
-                    &false,
-                    &FEE_MEDIUM,
-                    &get_min_tick(get_tick_spacing(FEE_MEDIUM)),
-                    &get_max_tick(get_tick_spacing(FEE_MEDIUM)),
-                    &U256::from(100),
-                    &U256::from(100),
-                    &U256::from(0),
-                    &U256::from(0),
-                    &Key::from(other()),
-                    &99999999999_u64,
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    tc.token0,
+                    tc.token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
                 )
-                .into(),
-            ],
+                .mint(
+                    MintParams {
+                        token0: tc.token0,
+                        token1: tc.token1,
+                        fee: FEE_MEDIUM,
+                        tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                        tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                        amount0_desired: U256::from(100),
+                        amount1_desired: U256::from(100),
+                        amount0_min: U256::from(0),
+                        amount1_min: U256::from(0),
+                        recipient: Key::from(other()),
+                        deadline: 99999999999_u64,
+                    },
+                    false,
+                ),
             0.into(),
         );
     }
@@ -189,7 +183,7 @@ mod mint {
 #[cfg(test)]
 mod increase_liquidity {
     use casper_types::{Key, U256, U512};
-    use contract_utilities::helpers;
+    use types::{session::SessionBuilder, IncreaseLiquidityParams, MintParams};
 
     use crate::{
         pool::fixture::{get_tick_spacing, FEE_MEDIUM},
@@ -203,31 +197,29 @@ mod increase_liquidity {
         let mut tc = setup_fixture();
         tc.multicall_liquidity_session(
             wallet(),
-            vec!["create_and_initialize_pool_if_necessary", "mint"],
-            vec![
-                helpers::encode_4(
-                    &tc.token0,
-                    &tc.token1,
-                    &FEE_MEDIUM,
-                    &encode_price_sqrt(1, 1),
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    tc.token0,
+                    tc.token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
                 )
-                .into(),
-                helpers::encode_12(
-                    &tc.token0,
-                    &tc.token1,
-                    &false,
-                    &FEE_MEDIUM,
-                    &get_min_tick(get_tick_spacing(FEE_MEDIUM)),
-                    &get_max_tick(get_tick_spacing(FEE_MEDIUM)),
-                    &U256::from(1000),
-                    &U256::from(1000),
-                    &U256::from(0),
-                    &U256::from(0),
-                    &Key::from(other()),
-                    &99999999999_u64,
-                )
-                .into(),
-            ],
+                .mint(
+                    MintParams {
+                        token0: tc.token0,
+                        token1: tc.token1,
+                        fee: FEE_MEDIUM,
+                        tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                        tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                        amount0_desired: U256::from(1000),
+                        amount1_desired: U256::from(1000),
+                        amount0_min: U256::from(0),
+                        amount1_min: U256::from(0),
+                        recipient: Key::from(other()),
+                        deadline: 99999999999_u64,
+                    },
+                    false,
+                ),
             0.into(),
         );
         tc
@@ -238,19 +230,19 @@ mod increase_liquidity {
         let mut tc = before_each();
         tc.multicall_liquidity_session(
             wallet(),
-            vec!["increase_liquidity"],
-            vec![helpers::encode_9(
-                &tc.token0,
-                &tc.token1,
-                &false,
-                &token_id(),
-                &U256::from(100),
-                &U256::from(100),
-                &U256::from(0),
-                &U256::from(0),
-                &99999999999_u64,
-            )
-            .into()],
+            SessionBuilder::new().increase_liquidity(
+                IncreaseLiquidityParams {
+                    token_id: token_id(),
+                    amount0_desired: U256::from(100),
+                    amount1_desired: U256::from(100),
+                    amount0_min: U256::from(0),
+                    amount1_min: U256::from(0),
+                    deadline: 99999999999_u64,
+                    token0: tc.token0,
+                    token1: tc.token1,
+                },
+                false,
+            ),
             0.into(),
         );
         let position = tc.position(token_id());
@@ -264,48 +256,48 @@ mod increase_liquidity {
 
         tc.multicall_liquidity_session(
             wallet(),
-            vec![
-                "create_and_initialize_pool_if_necessary",
-                "mint",
-                "unwrap_cspr",
-            ],
-            vec![
-                helpers::encode_4(&token0, &token1, &FEE_MEDIUM, &encode_price_sqrt(1, 1)).into(),
-                helpers::encode_12(
-                    &token0,
-                    &token1,
-                    &true,
-                    &FEE_MEDIUM,
-                    &get_min_tick(get_tick_spacing(FEE_MEDIUM)),
-                    &get_max_tick(get_tick_spacing(FEE_MEDIUM)),
-                    &U256::from(100),
-                    &U256::from(100),
-                    &U256::from(0),
-                    &U256::from(0),
-                    &Key::from(other()),
-                    &99999999999_u64,
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    token0,
+                    token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
                 )
-                .into(),
-                helpers::encode_2(&U256::zero(), &Key::from(other())).into(),
-            ],
+                .mint(
+                    MintParams {
+                        token0,
+                        token1,
+                        fee: FEE_MEDIUM,
+                        tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                        tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                        amount0_desired: U256::from(100),
+                        amount1_desired: U256::from(100),
+                        amount0_min: U256::from(0),
+                        amount1_min: U256::from(0),
+                        recipient: Key::from(other()),
+                        deadline: 99999999999_u64,
+                    },
+                    true,
+                )
+                .unwrap_cspr(U256::zero(), Key::from(other())),
             U512::from(100),
         );
 
         tc.multicall_liquidity_session(
             wallet(),
-            vec!["increase_liquidity"],
-            vec![helpers::encode_9(
-                &tc.token0,
-                &tc.token1,
-                &false,
-                &token_id(),
-                &U256::from(100),
-                &U256::from(100),
-                &U256::from(0),
-                &U256::from(0),
-                &99999999999_u64,
-            )
-            .into()],
+            SessionBuilder::new().increase_liquidity(
+                IncreaseLiquidityParams {
+                    token_id: token_id(),
+                    amount0_desired: U256::from(100),
+                    amount1_desired: U256::from(100),
+                    amount0_min: U256::from(0),
+                    amount1_min: U256::from(0),
+                    deadline: 99999999999_u64,
+                    token0: tc.token0,
+                    token1: tc.token1,
+                },
+                false,
+            ),
             U512::from(0),
         );
     }
@@ -314,7 +306,7 @@ mod increase_liquidity {
 #[cfg(test)]
 mod decrease_liquidity {
     use casper_types::{Key, U128, U256};
-    use contract_utilities::helpers;
+    use types::{session::SessionBuilder, DecreaseLiquidityParams, MintParams};
 
     use crate::{
         pool::fixture::{get_tick_spacing, FEE_MEDIUM},
@@ -327,31 +319,29 @@ mod decrease_liquidity {
         let mut tc = setup_fixture();
         tc.multicall_liquidity_session(
             wallet(),
-            vec!["create_and_initialize_pool_if_necessary", "mint"],
-            vec![
-                helpers::encode_4(
-                    &tc.token0,
-                    &tc.token1,
-                    &FEE_MEDIUM,
-                    &encode_price_sqrt(1, 1),
-                )
-                .into(),
-                helpers::encode_12(
-                    &tc.token0,
-                    &tc.token1,
-                    &false,
-                    &FEE_MEDIUM,
-                    &get_min_tick(get_tick_spacing(FEE_MEDIUM)),
-                    &get_max_tick(get_tick_spacing(FEE_MEDIUM)),
-                    &U256::from(100),
-                    &U256::from(100),
-                    &U256::from(0),
-                    &U256::from(0),
-                    &Key::from(other()),
-                    &99999999999_u64,
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    tc.token0,
+                    tc.token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
                 )
-                .into(),
-            ],
+                .mint(
+                    MintParams {
+                        token0: tc.token0,
+                        token1: tc.token1,
+                        fee: FEE_MEDIUM,
+                        tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                        tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                        amount0_desired: U256::from(100),
+                        amount1_desired: U256::from(100),
+                        amount0_min: U256::from(0),
+                        amount1_min: U256::from(0),
+                        recipient: Key::from(other()),
+                        deadline: 99999999999_u64,
+                    },
+                    false,
+                ),
             0.into(),
         );
         tc
@@ -364,15 +354,13 @@ mod decrease_liquidity {
         tc.test_env.set_block_time(2);
         tc.multicall_liquidity_session(
             other(),
-            vec!["decrease_liquidity"],
-            vec![helpers::encode_5(
-                &token_id(),
-                &U128::from(50),
-                &U256::from(0),
-                &U256::from(0),
-                &1_u64,
-            )
-            .into()],
+            SessionBuilder::new().decrease_liquidity(DecreaseLiquidityParams {
+                token_id: token_id(),
+                liquidity: U128::from(50),
+                amount0_min: U256::from(0),
+                amount1_min: U256::from(0),
+                deadline: 1_u64,
+            }),
             0.into(),
         );
     }
@@ -383,15 +371,13 @@ mod decrease_liquidity {
         let mut tc = before_each();
         tc.multicall_liquidity_session(
             wallet(),
-            vec!["decrease_liquidity"],
-            vec![helpers::encode_5(
-                &token_id(),
-                &U128::from(50),
-                &U256::from(0),
-                &U256::from(0),
-                &1_u64,
-            )
-            .into()],
+            SessionBuilder::new().decrease_liquidity(DecreaseLiquidityParams {
+                token_id: token_id(),
+                liquidity: U128::from(50),
+                amount0_min: U256::from(0),
+                amount1_min: U256::from(0),
+                deadline: 1_u64,
+            }),
             0.into(),
         );
     }
@@ -401,15 +387,13 @@ mod decrease_liquidity {
         let mut tc = before_each();
         tc.multicall_liquidity_session(
             other(),
-            vec!["decrease_liquidity"],
-            vec![helpers::encode_5(
-                &token_id(),
-                &U128::from(25),
-                &U256::from(0),
-                &U256::from(0),
-                &1_u64,
-            )
-            .into()],
+            SessionBuilder::new().decrease_liquidity(DecreaseLiquidityParams {
+                token_id: token_id(),
+                liquidity: U128::from(25),
+                amount0_min: U256::from(0),
+                amount1_min: U256::from(0),
+                deadline: 1_u64,
+            }),
             0.into(),
         );
         let position = tc.position(token_id());
@@ -421,15 +405,13 @@ mod decrease_liquidity {
         let mut tc = before_each();
         tc.multicall_liquidity_session(
             other(),
-            vec!["decrease_liquidity"],
-            vec![helpers::encode_5(
-                &token_id(),
-                &U128::from(25),
-                &U256::from(0),
-                &U256::from(0),
-                &1_u64,
-            )
-            .into()],
+            SessionBuilder::new().decrease_liquidity(DecreaseLiquidityParams {
+                token_id: token_id(),
+                liquidity: U128::from(25),
+                amount0_min: U256::from(0),
+                amount1_min: U256::from(0),
+                deadline: 1_u64,
+            }),
             0.into(),
         );
         let position = tc.position(token_id());
@@ -442,15 +424,13 @@ mod decrease_liquidity {
         let mut tc = before_each();
         tc.multicall_liquidity_session(
             other(),
-            vec!["decrease_liquidity"],
-            vec![helpers::encode_5(
-                &token_id(),
-                &U128::from(100),
-                &U256::from(0),
-                &U256::from(0),
-                &1_u64,
-            )
-            .into()],
+            SessionBuilder::new().decrease_liquidity(DecreaseLiquidityParams {
+                token_id: token_id(),
+                liquidity: U128::from(100),
+                amount0_min: U256::from(0),
+                amount1_min: U256::from(0),
+                deadline: 1_u64,
+            }),
             0.into(),
         );
         let position = tc.position(token_id());
@@ -463,35 +443,33 @@ mod decrease_liquidity {
         let mut tc = before_each();
         tc.multicall_liquidity_session(
             other(),
-            vec!["mint"],
-            vec![helpers::encode_12(
-                &tc.token0,
-                &tc.token1,
-                &false,
-                &FEE_MEDIUM,
-                &get_min_tick(get_tick_spacing(FEE_MEDIUM)),
-                &get_max_tick(get_tick_spacing(FEE_MEDIUM)),
-                &U256::from(200),
-                &U256::from(200),
-                &U256::from(0),
-                &U256::from(0),
-                &Key::from(other()),
-                &99999999999_u64,
-            )
-            .into()],
+            SessionBuilder::new().mint(
+                MintParams {
+                    token0: tc.token0,
+                    token1: tc.token1,
+                    fee: FEE_MEDIUM,
+                    tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                    tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                    amount0_desired: U256::from(200),
+                    amount1_desired: U256::from(200),
+                    amount0_min: U256::from(0),
+                    amount1_min: U256::from(0),
+                    recipient: Key::from(other()),
+                    deadline: 99999999999_u64,
+                },
+                false,
+            ),
             0.into(),
         );
         tc.multicall_liquidity_session(
             other(),
-            vec!["decrease_liquidity"],
-            vec![helpers::encode_5(
-                &token_id(),
-                &U128::from(101),
-                &U256::from(0),
-                &U256::from(0),
-                &1_u64,
-            )
-            .into()],
+            SessionBuilder::new().decrease_liquidity(DecreaseLiquidityParams {
+                token_id: token_id(),
+                liquidity: U128::from(101),
+                amount0_min: U256::from(0),
+                amount1_min: U256::from(0),
+                deadline: 1_u64,
+            }),
             0.into(),
         );
     }
@@ -502,7 +480,7 @@ mod collect {
     use std::ops::Add;
 
     use casper_types::{Key, U128, U256};
-    use contract_utilities::helpers;
+    use types::{session::SessionBuilder, CollectParams, DecreaseLiquidityParams, MintParams};
 
     use crate::{
         pool::fixture::{get_tick_spacing, FEE_MEDIUM},
@@ -515,31 +493,29 @@ mod collect {
         let mut tc = setup_fixture();
         tc.multicall_liquidity_session(
             wallet(),
-            vec!["create_and_initialize_pool_if_necessary", "mint"],
-            vec![
-                helpers::encode_4(
-                    &tc.token0,
-                    &tc.token1,
-                    &FEE_MEDIUM,
-                    &encode_price_sqrt(1, 1),
-                )
-                .into(),
-                helpers::encode_12(
-                    &tc.token0,
-                    &tc.token1,
-                    &false,
-                    &FEE_MEDIUM,
-                    &get_min_tick(get_tick_spacing(FEE_MEDIUM)),
-                    &get_max_tick(get_tick_spacing(FEE_MEDIUM)),
-                    &U256::from(100),
-                    &U256::from(100),
-                    &U256::from(0),
-                    &U256::from(0),
-                    &Key::from(other()),
-                    &99999999999_u64,
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    tc.token0,
+                    tc.token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
                 )
-                .into(),
-            ],
+                .mint(
+                    MintParams {
+                        token0: tc.token0,
+                        token1: tc.token1,
+                        fee: FEE_MEDIUM,
+                        tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                        tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                        amount0_desired: U256::from(100),
+                        amount1_desired: U256::from(100),
+                        amount0_min: U256::from(0),
+                        amount1_min: U256::from(0),
+                        recipient: Key::from(other()),
+                        deadline: 99999999999_u64,
+                    },
+                    false,
+                ),
             0.into(),
         );
         tc
@@ -551,15 +527,15 @@ mod collect {
         let mut tc = before_each();
         tc.multicall_liquidity_session(
             wallet(),
-            vec!["collect"],
-            vec![helpers::encode_5(
-                &false,
-                &token_id(),
-                &Key::from(wallet()),
-                &U128::from(0),
-                &U128::from(0),
-            )
-            .into()],
+            SessionBuilder::new().collect(
+                CollectParams {
+                    token_id: token_id(),
+                    recipient: Key::from(wallet()),
+                    amount0_max: U128::from(0),
+                    amount1_max: U128::from(0),
+                },
+                false,
+            ),
             0.into(),
         );
     }
@@ -569,15 +545,15 @@ mod collect {
         let mut tc: TestContext = before_each();
         tc.multicall_liquidity_session(
             other(),
-            vec!["collect"],
-            vec![helpers::encode_5(
-                &false,
-                &token_id(),
-                &Key::from(wallet()),
-                &U128::from(0),
-                &U128::from(0),
-            )
-            .into()],
+            SessionBuilder::new().collect(
+                CollectParams {
+                    token_id: token_id(),
+                    recipient: Key::from(wallet()),
+                    amount0_max: U128::from(0),
+                    amount1_max: U128::from(0),
+                },
+                false,
+            ),
             0.into(),
         );
     }
@@ -589,15 +565,15 @@ mod collect {
         let bal1_before = tc.test_env.balance_of(tc.token1, wallet().into());
         tc.multicall_liquidity_session(
             other(),
-            vec!["collect"],
-            vec![helpers::encode_5(
-                &false,
-                &token_id(),
-                &Key::from(wallet()),
-                &U128::MAX,
-                &U128::MAX,
-            )
-            .into()],
+            SessionBuilder::new().collect(
+                CollectParams {
+                    token_id: token_id(),
+                    recipient: Key::from(wallet()),
+                    amount0_max: U128::MAX,
+                    amount1_max: U128::MAX,
+                },
+                false,
+            ),
             0.into(),
         );
         assert!(tc
@@ -615,30 +591,28 @@ mod collect {
         let mut tc: TestContext = before_each();
         tc.multicall_liquidity_session(
             other(),
-            vec!["decrease_liquidity"],
-            vec![helpers::encode_5(
-                &token_id(),
-                &U128::from(50),
-                &U256::from(0),
-                &U256::from(0),
-                &1_u64,
-            )
-            .into()],
+            SessionBuilder::new().decrease_liquidity(DecreaseLiquidityParams {
+                token_id: token_id(),
+                liquidity: U128::from(50),
+                amount0_min: U256::from(0),
+                amount1_min: U256::from(0),
+                deadline: 1_u64,
+            }),
             0.into(),
         );
         let bal0_before = tc.test_env.balance_of(tc.token0, wallet().into());
         let bal1_before = tc.test_env.balance_of(tc.token1, wallet().into());
         tc.multicall_liquidity_session(
             other(),
-            vec!["collect"],
-            vec![helpers::encode_5(
-                &false,
-                &token_id(),
-                &Key::from(wallet()),
-                &U128::MAX,
-                &U128::MAX,
-            )
-            .into()],
+            SessionBuilder::new().collect(
+                CollectParams {
+                    token_id: token_id(),
+                    recipient: Key::from(wallet()),
+                    amount0_max: U128::MAX,
+                    amount1_max: U128::MAX,
+                },
+                false,
+            ),
             0.into(),
         );
         assert!(tc
@@ -655,7 +629,7 @@ mod collect {
 #[cfg(test)]
 mod burn {
     use casper_types::{Key, U256};
-    use contract_utilities::helpers;
+    use types::{session::SessionBuilder, MintParams};
 
     use crate::{
         pool::fixture::{get_tick_spacing, FEE_MEDIUM},
@@ -668,31 +642,29 @@ mod burn {
         let mut tc = setup_fixture();
         tc.multicall_liquidity_session(
             wallet(),
-            vec!["create_and_initialize_pool_if_necessary", "mint"],
-            vec![
-                helpers::encode_4(
-                    &tc.token0,
-                    &tc.token1,
-                    &FEE_MEDIUM,
-                    &encode_price_sqrt(1, 1),
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    tc.token0,
+                    tc.token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
                 )
-                .into(),
-                helpers::encode_12(
-                    &tc.token0,
-                    &tc.token1,
-                    &false,
-                    &FEE_MEDIUM,
-                    &get_min_tick(get_tick_spacing(FEE_MEDIUM)),
-                    &get_max_tick(get_tick_spacing(FEE_MEDIUM)),
-                    &U256::from(100),
-                    &U256::from(100),
-                    &U256::from(0),
-                    &U256::from(0),
-                    &Key::from(other()),
-                    &99999999999_u64,
-                )
-                .into(),
-            ],
+                .mint(
+                    MintParams {
+                        token0: tc.token0,
+                        token1: tc.token1,
+                        fee: FEE_MEDIUM,
+                        tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                        tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                        amount0_desired: U256::from(100),
+                        amount1_desired: U256::from(100),
+                        amount0_min: U256::from(0),
+                        amount1_min: U256::from(0),
+                        recipient: Key::from(other()),
+                        deadline: 99999999999_u64,
+                    },
+                    false,
+                ),
             0.into(),
         );
         tc
@@ -702,12 +674,7 @@ mod burn {
     #[should_panic = "User(15052)"]
     fn test_cannot_be_called_by_other_addresses() {
         let mut tc = before_each();
-        tc.multicall_liquidity_session(
-            wallet(),
-            vec!["burn"],
-            vec![helpers::encode_1(&token_id()).into()],
-            0.into(),
-        );
+        tc.multicall_liquidity_session(wallet(), SessionBuilder::new().burn(token_id()), 0.into());
     }
 
     #[test]
@@ -716,14 +683,1015 @@ mod burn {
         // let mut tc = before_each();
         // tc.multicall_liquidity_session(
         //     wallet(),
-        //     vec!["burn"],
-        //     vec![
-        //         helpers::encode_1(
-        //             &token_id(),
-        //         )
-        //         .into(),
-        //     ],
+        //     SessionBuilder::new().burn(token_id()),
         //     0.into()
         // );
     }
 }
+
+#[cfg(test)]
+mod rebalance {
+    use casper_types::{Key, U256};
+    use types::{session::SessionBuilder, MintParams, RebalanceParams};
+
+    use crate::{
+        pool::fixture::{get_tick_spacing, FEE_MEDIUM},
+        router::fixture::{setup_fixture, TestContext},
+        utils::{encode_price_sqrt, get_max_tick, get_min_tick, null_key, other, wallet},
+    };
+
+    use super::token_id;
+    fn before_each() -> TestContext {
+        let mut tc = setup_fixture();
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    tc.token0,
+                    tc.token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
+                )
+                .mint(
+                    MintParams {
+                        token0: tc.token0,
+                        token1: tc.token1,
+                        fee: FEE_MEDIUM,
+                        tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                        tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                        amount0_desired: U256::from(1000),
+                        amount1_desired: U256::from(1000),
+                        amount0_min: U256::from(0),
+                        amount1_min: U256::from(0),
+                        recipient: Key::from(other()),
+                        deadline: 99999999999_u64,
+                    },
+                    false,
+                ),
+            0.into(),
+        );
+        tc
+    }
+
+    fn rebalance_data(amount_in: U256, reuse_token_id: bool) -> RebalanceParams {
+        RebalanceParams {
+            token_id: token_id(),
+            decrease_amount0_min: U256::from(0),
+            decrease_amount1_min: U256::from(0),
+            tick_lower: -600,
+            tick_upper: 600,
+            zero_for_one: true,
+            amount_in,
+            amount_out_minimum: U256::from(0),
+            sqrt_price_limit_x96: U256::from(0),
+            amount0_min: U256::from(0),
+            amount1_min: U256::from(0),
+            reuse_token_id,
+            recipient: Key::from(other()),
+            deadline: 99999999999,
+        }
+    }
+
+    #[test]
+    #[should_panic = "User(15052)"]
+    fn test_cannot_be_called_by_other_addresses() {
+        let mut tc = before_each();
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new().rebalance(rebalance_data(U256::from(0), true)),
+            0.into(),
+        );
+    }
+
+    #[test]
+    fn test_moves_liquidity_into_the_new_range() {
+        let mut tc = before_each();
+        tc.multicall_liquidity_session(
+            other(),
+            SessionBuilder::new().rebalance(rebalance_data(U256::from(0), true)),
+            0.into(),
+        );
+        let position = tc.position(token_id());
+        assert!(position.tick_lower == -600);
+        assert!(position.tick_upper == 600);
+        assert!(position.liquidity.as_u128() > 1000);
+        assert!(position.tokens_owed0.is_zero() && position.tokens_owed1.is_zero());
+        assert!(tc.cep47_balance_of(other().into()).eq(&1.into()));
+    }
+
+    #[test]
+    fn test_mints_a_new_token_when_not_reused() {
+        let mut tc = before_each();
+        tc.multicall_liquidity_session(
+            other(),
+            SessionBuilder::new().rebalance(rebalance_data(U256::from(0), false)),
+            0.into(),
+        );
+        assert!(tc.position(token_id()).liquidity.is_zero());
+        let position = tc.position(2.into());
+        assert!(position.tick_lower == -600);
+        assert!(position.tick_upper == 600);
+        assert!(tc.cep47_balance_of(other().into()).eq(&1.into()));
+        assert!(tc
+            .token_of_owner_by_index(other().into(), 0.into())
+            .eq(&2.into()));
+    }
+
+    #[test]
+    fn test_swaps_before_minting() {
+        let mut tc = before_each();
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new().mint(
+                MintParams {
+                    token0: tc.token0,
+                    token1: tc.token1,
+                    fee: FEE_MEDIUM,
+                    tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                    tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                    amount0_desired: U256::from(1000000),
+                    amount1_desired: U256::from(1000000),
+                    amount0_min: U256::from(0),
+                    amount1_min: U256::from(0),
+                    recipient: Key::from(wallet()),
+                    deadline: 99999999999_u64,
+                },
+                false,
+            ),
+            0.into(),
+        );
+        let bal1_before = tc.test_env.balance_of(tc.token1, other().into());
+        tc.multicall_liquidity_session(
+            other(),
+            SessionBuilder::new().rebalance(rebalance_data(U256::from(100), true)),
+            0.into(),
+        );
+        let position = tc.position(token_id());
+        assert!(position.tick_lower == -600);
+        assert!(position.liquidity.as_u128() > 1000);
+        assert!(tc.test_env.balance_of(tc.token1, other().into()) >= bal1_before);
+    }
+
+    #[test]
+    #[should_panic = "User(15063)"]
+    fn test_cannot_swap_more_than_collected() {
+        let mut tc = before_each();
+        tc.multicall_liquidity_session(
+            other(),
+            SessionBuilder::new().rebalance(rebalance_data(U256::from(10000), true)),
+            0.into(),
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15106)"]
+    fn test_cannot_mint_the_new_token_to_the_null_key() {
+        let mut tc = before_each();
+        let mut data = rebalance_data(U256::from(0), false);
+        data.recipient = null_key();
+        tc.multicall_liquidity_session(other(), SessionBuilder::new().rebalance(data), 0.into());
+    }
+
+    #[test]
+    #[should_panic = "User(15050)"]
+    fn test_checks_the_decrease_minimums() {
+        let mut tc = before_each();
+        let mut data = rebalance_data(U256::from(0), true);
+        data.decrease_amount0_min = U256::from(1000);
+        tc.multicall_liquidity_session(other(), SessionBuilder::new().rebalance(data), 0.into());
+    }
+}
+
+#[cfg(test)]
+mod zap_mint {
+    use casper_types::{runtime_args, Key, RuntimeArgs, U128, U256};
+    use common::{pool_events::Swap, router_events::IncreaseLiquidity};
+    use math::{liquidity_amounts::get_liquidity_for_amounts, tickmath, zap::get_zap_swap_amount};
+    use types::{i256::I256, session::SessionBuilder, MintParams, Slot0, ZapMintParams};
+
+    use crate::{
+        pool::fixture::{get_tick_spacing, FEE_LOW, FEE_MEDIUM},
+        router::fixture::{setup_fixture, TestContext},
+        utils::{encode_price_sqrt, get_max_tick, get_min_tick, other, wallet},
+    };
+
+    fn before_each() -> TestContext {
+        let mut tc = setup_fixture();
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    tc.token0,
+                    tc.token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
+                )
+                .mint(
+                    MintParams {
+                        token0: tc.token0,
+                        token1: tc.token1,
+                        fee: FEE_MEDIUM,
+                        tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                        tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                        amount0_desired: U256::from(1000000),
+                        amount1_desired: U256::from(1000000),
+                        amount0_min: U256::from(0),
+                        amount1_min: U256::from(0),
+                        recipient: Key::from(wallet()),
+                        deadline: 99999999999_u64,
+                    },
+                    false,
+                ),
+            0.into(),
+        );
+        tc
+    }
+
+    fn zap_data(tc: &TestContext, token_in: Key, amount_in: U256) -> ZapMintParams {
+        ZapMintParams {
+            token_in,
+            token0: tc.token0,
+            token1: tc.token1,
+            fee: FEE_MEDIUM,
+            tick_lower: -600,
+            tick_upper: 600,
+            amount_in,
+            amount_out_minimum: U256::from(0),
+            amount0_min: U256::from(0),
+            amount1_min: U256::from(0),
+            recipient: Key::from(other()),
+            deadline: 99999999999,
+        }
+    }
+
+    // the swap amount the router should pick for a zap into the current pool state
+    fn expected_swap_amount(tc: &mut TestContext, zero_for_one: bool, amount_in: U256) -> U256 {
+        let pool = tc.get_pool(tc.token0, tc.token1, FEE_MEDIUM);
+        let slot0: Slot0 = tc
+            .test_env
+            .call_view_function(pool, "get_slot0", runtime_args! {});
+        let liquidity: U128 =
+            tc.test_env
+                .call_view_function(pool, "get_liquidity", runtime_args! {});
+        get_zap_swap_amount(
+            &slot0.sqrt_price_x96,
+            &tickmath::get_sqrt_ratio_at_tick(-600),
+            &tickmath::get_sqrt_ratio_at_tick(600),
+            liquidity.as_u128(),
+            &amount_in,
+            zero_for_one,
+            FEE_MEDIUM,
+        )
+    }
+
+    // the pool swap made by the zap, emitted right before the mint
+    fn zap_swap(tc: &mut TestContext) -> (U256, U256, U256) {
+        let pool = tc.get_pool(tc.token0, tc.token1, FEE_MEDIUM);
+        let position = tc.test_env.get_event_length(pool) as usize - 2;
+        let swap: Swap = tc.test_env.get_event(pool, position).unwrap();
+        let (amount0, amount1) = (I256::from(&*swap.amount0), I256::from(&*swap.amount1));
+        let abs = |amount: I256| {
+            if amount < I256::from(0) {
+                U256::from(-amount)
+            } else {
+                U256::from(amount)
+            }
+        };
+        (abs(amount0), abs(amount1), swap.sqrt_price_x96)
+    }
+
+    fn liquidity_for(sqrt_price_x96: &U256, amount0: U256, amount1: U256) -> u128 {
+        get_liquidity_for_amounts(
+            sqrt_price_x96,
+            &tickmath::get_sqrt_ratio_at_tick(-600),
+            &tickmath::get_sqrt_ratio_at_tick(600),
+            &amount0,
+            &amount1,
+        )
+    }
+
+    #[test]
+    #[should_panic = "User(15103)"]
+    fn test_fails_if_the_pool_does_not_exist() {
+        let mut tc = before_each();
+        let mut data = zap_data(&tc, tc.token0, U256::from(10000));
+        data.fee = FEE_LOW;
+        tc.multicall_liquidity_session(other(), SessionBuilder::new().zap_mint(data), 0.into());
+    }
+
+    #[test]
+    #[should_panic = "User(15064)"]
+    fn test_fails_if_token_in_is_not_in_the_pool() {
+        let mut tc = before_each();
+        let data = zap_data(&tc, tc.token2, U256::from(10000));
+        tc.multicall_liquidity_session(other(), SessionBuilder::new().zap_mint(data), 0.into());
+    }
+
+    #[test]
+    fn test_mints_a_position_from_token0_only() {
+        let mut tc = before_each();
+        let amount_in = U256::from(10000);
+        let expected_swap = expected_swap_amount(&mut tc, true, amount_in);
+        let bal0_before = tc.test_env.balance_of(tc.token0, other().into());
+        let bal1_before = tc.test_env.balance_of(tc.token1, other().into());
+        let data = zap_data(&tc, tc.token0, amount_in);
+        tc.multicall_liquidity_session(other(), SessionBuilder::new().zap_mint(data), 0.into());
+
+        let (swapped, received, sqrt_price_x96) = zap_swap(&mut tc);
+        assert!(swapped == expected_swap);
+        let minted: IncreaseLiquidity = tc.test_env.get_last_event(tc.router).unwrap();
+        let liquidity = liquidity_for(&sqrt_price_x96, amount_in - swapped, received);
+        assert!(minted.liquidity.as_u128() == liquidity);
+
+        assert!(tc.cep47_balance_of(other().into()).eq(&1.into()));
+        let position = tc.position(2.into());
+        assert!(position.tick_lower == -600);
+        assert!(position.tick_upper == 600);
+        assert!(position.liquidity.as_u128() == liquidity);
+        // whatever the mint did not use is refunded
+        assert!(
+            tc.test_env.balance_of(tc.token0, other().into())
+                == bal0_before - swapped - minted.amount0
+        );
+        assert!(
+            tc.test_env.balance_of(tc.token1, other().into())
+                == bal1_before + received - minted.amount1
+        );
+        assert!(tc.test_env.balance_of(tc.token0, tc.router).is_zero());
+        assert!(tc.test_env.balance_of(tc.token1, tc.router).is_zero());
+    }
+
+    #[test]
+    fn test_mints_a_position_from_token1_only() {
+        let mut tc = before_each();
+        let amount_in = U256::from(10000);
+        let expected_swap = expected_swap_amount(&mut tc, false, amount_in);
+        let bal0_before = tc.test_env.balance_of(tc.token0, other().into());
+        let bal1_before = tc.test_env.balance_of(tc.token1, other().into());
+        let data = zap_data(&tc, tc.token1, amount_in);
+        tc.multicall_liquidity_session(other(), SessionBuilder::new().zap_mint(data), 0.into());
+
+        let (received, swapped, sqrt_price_x96) = zap_swap(&mut tc);
+        assert!(swapped == expected_swap);
+        let minted: IncreaseLiquidity = tc.test_env.get_last_event(tc.router).unwrap();
+        let liquidity = liquidity_for(&sqrt_price_x96, received, amount_in - swapped);
+        assert!(minted.liquidity.as_u128() == liquidity);
+
+        assert!(tc.cep47_balance_of(other().into()).eq(&1.into()));
+        assert!(tc.position(2.into()).liquidity.as_u128() == liquidity);
+        assert!(
+            tc.test_env.balance_of(tc.token0, other().into())
+                == bal0_before + received - minted.amount0
+        );
+        assert!(
+            tc.test_env.balance_of(tc.token1, other().into())
+                == bal1_before - swapped - minted.amount1
+        );
+        assert!(tc.test_env.balance_of(tc.token0, tc.router).is_zero());
+        assert!(tc.test_env.balance_of(tc.token1, tc.router).is_zero());
+    }
+}
+
+#[cfg(test)]
+mod composite {
+    use casper_types::{Key, U128, U256};
+    use contract_utilities::helpers::null_key;
+    use types::{
+        session::SessionBuilder, CollectParams, CompositeAmounts, CompositeDecreaseLiquidityParams,
+        CompositeIncreaseLiquidityParams, CompositeMintParams, CompositeRangeParams,
+        ExactInputParams, MintParams,
+    };
+
+    use crate::{
+        pool::fixture::{get_tick_spacing, FEE_MEDIUM},
+        router::fixture::{setup_fixture, TestContext},
+        utils::{encode_path, encode_price_sqrt, get_max_tick, get_min_tick, other, wallet},
+    };
+
+    fn composite_token_id() -> U256 {
+        2.into()
+    }
+
+    fn before_each() -> TestContext {
+        let mut tc = setup_fixture();
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    tc.token0,
+                    tc.token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
+                )
+                .mint(
+                    MintParams {
+                        token0: tc.token0,
+                        token1: tc.token1,
+                        fee: FEE_MEDIUM,
+                        tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                        tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                        amount0_desired: U256::from(1000000),
+                        amount1_desired: U256::from(1000000),
+                        amount0_min: U256::from(0),
+                        amount1_min: U256::from(0),
+                        recipient: Key::from(wallet()),
+                        deadline: 99999999999_u64,
+                    },
+                    false,
+                ),
+            0.into(),
+        );
+        tc
+    }
+
+    fn range(tick_lower: i32, tick_upper: i32, amount: u64) -> CompositeRangeParams {
+        CompositeRangeParams {
+            tick_lower,
+            tick_upper,
+            amount0_desired: amount.into(),
+            amount1_desired: amount.into(),
+            amount0_min: 0.into(),
+            amount1_min: 0.into(),
+        }
+    }
+
+    fn amounts(amount: u64) -> CompositeAmounts {
+        CompositeAmounts {
+            amount0_desired: amount.into(),
+            amount1_desired: amount.into(),
+            amount0_min: 0.into(),
+            amount1_min: 0.into(),
+        }
+    }
+
+    fn composite_mint(tc: &mut TestContext, ranges: Vec<CompositeRangeParams>) {
+        let data = CompositeMintParams {
+            token0: tc.token0,
+            token1: tc.token1,
+            fee: FEE_MEDIUM,
+            ranges,
+            recipient: other().into(),
+            deadline: 99999999999,
+        };
+        tc.multicall_liquidity_session(
+            other(),
+            SessionBuilder::new().composite_mint(data),
+            0.into(),
+        );
+    }
+
+    // a range below, around and above the current price
+    fn mint_three_ranges(tc: &mut TestContext) {
+        composite_mint(
+            tc,
+            vec![
+                range(-1200, -600, 10000),
+                range(-600, 600, 10000),
+                range(600, 1200, 10000),
+            ],
+        );
+    }
+
+    fn decrease_data(liquidities: Vec<U128>) -> CompositeDecreaseLiquidityParams {
+        CompositeDecreaseLiquidityParams {
+            token_id: composite_token_id(),
+            liquidities,
+            amount0_min: 0.into(),
+            amount1_min: 0.into(),
+            deadline: 99999999999,
+        }
+    }
+
+    fn collect_data(recipient: Key) -> CollectParams {
+        CollectParams {
+            token_id: composite_token_id(),
+            recipient,
+            amount0_max: U128::MAX,
+            amount1_max: U128::MAX,
+        }
+    }
+
+    #[test]
+    fn test_mints_a_token_holding_every_range() {
+        let mut tc = before_each();
+        mint_three_ranges(&mut tc);
+
+        assert!(tc.cep47_balance_of(other().into()).eq(&1.into()));
+        let position = tc.composite_position(composite_token_id());
+        assert!(position.token0 == tc.token0 && position.token1 == tc.token1);
+        assert!(position.ranges.len() == 3);
+        assert!(position.ranges[0].tick_lower == -1200);
+        assert!(position.ranges[2].tick_upper == 1200);
+        assert!(position
+            .ranges
+            .iter()
+            .all(|range| range.liquidity > U128::zero()));
+        assert!(tc.position(composite_token_id()).liquidity.is_zero());
+    }
+
+    #[test]
+    #[should_panic = "User(15095)"]
+    fn test_fails_without_ranges() {
+        let mut tc = before_each();
+        composite_mint(&mut tc, vec![]);
+    }
+
+    #[test]
+    #[should_panic = "User(15095)"]
+    fn test_fails_with_too_many_ranges() {
+        let mut tc = before_each();
+        let ranges = (0..21).map(|_| range(-600, 600, 100)).collect();
+        composite_mint(&mut tc, ranges);
+    }
+
+    #[test]
+    fn test_increases_the_liquidity_of_each_range() {
+        let mut tc = before_each();
+        mint_three_ranges(&mut tc);
+        let before = tc.composite_position(composite_token_id());
+
+        let data = CompositeIncreaseLiquidityParams {
+            token_id: composite_token_id(),
+            amounts: vec![amounts(5000), amounts(0), amounts(5000)],
+            deadline: 99999999999,
+        };
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new().composite_increase_liquidity(data),
+            0.into(),
+        );
+
+        let after = tc.composite_position(composite_token_id());
+        assert!(after.ranges[0].liquidity > before.ranges[0].liquidity);
+        assert!(after.ranges[1].liquidity == before.ranges[1].liquidity);
+        assert!(after.ranges[2].liquidity > before.ranges[2].liquidity);
+    }
+
+    #[test]
+    #[should_panic = "User(15095)"]
+    fn test_increase_fails_if_amounts_do_not_match_the_ranges() {
+        let mut tc = before_each();
+        mint_three_ranges(&mut tc);
+        let data = CompositeIncreaseLiquidityParams {
+            token_id: composite_token_id(),
+            amounts: vec![amounts(5000)],
+            deadline: 99999999999,
+        };
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new().composite_increase_liquidity(data),
+            0.into(),
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15052)"]
+    fn test_decrease_cannot_be_called_by_other_addresses() {
+        let mut tc = before_each();
+        mint_three_ranges(&mut tc);
+        let liquidity = tc.composite_position(composite_token_id()).ranges[1].liquidity;
+        let data = decrease_data(vec![U128::zero(), liquidity, U128::zero()]);
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new().composite_decrease_liquidity(data),
+            0.into(),
+        );
+    }
+
+    #[test]
+    fn test_decreases_the_selected_ranges() {
+        let mut tc = before_each();
+        mint_three_ranges(&mut tc);
+        let before = tc.composite_position(composite_token_id());
+        let half = before.ranges[1].liquidity / 2;
+        let data = decrease_data(vec![U128::zero(), half, U128::zero()]);
+        tc.multicall_liquidity_session(
+            other(),
+            SessionBuilder::new().composite_decrease_liquidity(data),
+            0.into(),
+        );
+
+        let after = tc.composite_position(composite_token_id());
+        assert!(after.ranges[0].liquidity == before.ranges[0].liquidity);
+        assert!(after.ranges[1].liquidity == before.ranges[1].liquidity - half);
+        assert!(after.ranges[1].tokens_owed0 > U128::zero());
+        assert!(after.ranges[1].tokens_owed1 > U128::zero());
+    }
+
+    #[test]
+    fn test_collects_fees_earned_by_the_ranges() {
+        let mut tc = before_each();
+        mint_three_ranges(&mut tc);
+        let swap_data = ExactInputParams {
+            path: encode_path(vec![tc.token0, tc.token1], vec![FEE_MEDIUM]),
+            recipient: Key::from(wallet()),
+            deadline: 99999999999,
+            amount_in: U256::from(10000),
+            amount_out_minimum: U256::zero(),
+            fee_recipient: null_key(),
+            fee_bips: 0,
+        };
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().exact_input(swap_data, false),
+            0.into(),
+        );
+
+        let bal0_before = tc.test_env.balance_of(tc.token0, other().into());
+        tc.multicall_liquidity_session(
+            other(),
+            SessionBuilder::new().composite_collect(collect_data(other().into())),
+            0.into(),
+        );
+        assert!(tc.test_env.balance_of(tc.token0, other().into()) > bal0_before);
+        let position = tc.composite_position(composite_token_id());
+        assert!(position
+            .ranges
+            .iter()
+            .all(|range| range.tokens_owed0.is_zero() && range.tokens_owed1.is_zero()));
+    }
+
+    #[test]
+    fn test_totals_aggregate_every_range() {
+        let mut tc = before_each();
+        mint_three_ranges(&mut tc);
+        let position = tc.composite_position(composite_token_id());
+        let totals = tc.composite_totals(composite_token_id());
+
+        let liquidity = position
+            .ranges
+            .iter()
+            .fold(U128::zero(), |total, range| total + range.liquidity);
+        assert!(totals.liquidity == liquidity);
+        assert!(totals.amount0 > U256::zero() && totals.amount1 > U256::zero());
+        assert!(totals.tokens_owed0.is_zero() && totals.tokens_owed1.is_zero());
+    }
+
+    #[test]
+    #[should_panic = "User(15056)"]
+    fn test_cannot_be_burned_while_there_is_still_liquidity() {
+        let mut tc = before_each();
+        mint_three_ranges(&mut tc);
+        tc.multicall_liquidity_session(
+            other(),
+            SessionBuilder::new().burn(composite_token_id()),
+            0.into(),
+        );
+    }
+
+    #[test]
+    fn test_can_be_burned_once_cleared() {
+        let mut tc = before_each();
+        mint_three_ranges(&mut tc);
+        let liquidities = tc
+            .composite_position(composite_token_id())
+            .ranges
+            .iter()
+            .map(|range| range.liquidity)
+            .collect();
+        tc.multicall_liquidity_session(
+            other(),
+            SessionBuilder::new()
+                .composite_decrease_liquidity(decrease_data(liquidities))
+                .composite_collect(collect_data(other().into()))
+                .burn(composite_token_id()),
+            0.into(),
+        );
+        assert!(tc.cep47_balance_of(other().into()).is_zero());
+        assert!(tc
+            .composite_position(composite_token_id())
+            .ranges
+            .is_empty());
+    }
+}
+
+#[cfg(test)]
+mod split_merge {
+    use casper_types::{Key, U128, U256};
+    use types::{session::SessionBuilder, DecreaseLiquidityParams, MintParams};
+
+    use crate::{
+        pool::fixture::{get_tick_spacing, FEE_MEDIUM},
+        router::fixture::{setup_fixture, TestContext},
+        utils::{encode_price_sqrt, get_max_tick, get_min_tick, other, wallet},
+    };
+
+    use super::token_id;
+    fn before_each() -> TestContext {
+        let mut tc = setup_fixture();
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    tc.token0,
+                    tc.token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
+                )
+                .mint(
+                    MintParams {
+                        token0: tc.token0,
+                        token1: tc.token1,
+                        fee: FEE_MEDIUM,
+                        tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                        tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                        amount0_desired: U256::from(1000),
+                        amount1_desired: U256::from(1000),
+                        amount0_min: U256::from(0),
+                        amount1_min: U256::from(0),
+                        recipient: Key::from(other()),
+                        deadline: 99999999999_u64,
+                    },
+                    false,
+                ),
+            0.into(),
+        );
+        tc
+    }
+
+    fn split(tc: &mut TestContext, liquidity: u128) {
+        tc.multicall_liquidity_session(
+            other(),
+            SessionBuilder::new().split(token_id(), U128::from(liquidity)),
+            0.into(),
+        );
+    }
+
+    fn merge(tc: &mut TestContext, token_ids: Vec<U256>) {
+        tc.multicall_liquidity_session(other(), SessionBuilder::new().merge(token_ids), 0.into());
+    }
+
+    #[test]
+    #[should_panic = "User(15052)"]
+    fn test_cannot_be_split_by_other_addresses() {
+        let mut tc = before_each();
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new().split(token_id(), U128::from(400)),
+            0.into(),
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15096)"]
+    fn test_cannot_split_off_all_the_liquidity() {
+        let mut tc = before_each();
+        split(&mut tc, 1000);
+    }
+
+    #[test]
+    fn test_split_moves_liquidity_into_a_new_token() {
+        let mut tc = before_each();
+        split(&mut tc, 400);
+
+        let position = tc.position(token_id());
+        let new_position = tc.position(2.into());
+        assert!(position.liquidity == 600.into());
+        assert!(new_position.liquidity == 400.into());
+        assert!(new_position.tick_lower == position.tick_lower);
+        assert!(new_position.tick_upper == position.tick_upper);
+        assert!(new_position.fee_growth_inside0_last_x128 == position.fee_growth_inside0_last_x128);
+        assert!(tc.cep47_balance_of(other().into()).eq(&2.into()));
+    }
+
+    #[test]
+    fn test_split_carries_a_share_of_the_tokens_owed() {
+        let mut tc = before_each();
+        tc.multicall_liquidity_session(
+            other(),
+            SessionBuilder::new().decrease_liquidity(DecreaseLiquidityParams {
+                token_id: token_id(),
+                liquidity: U128::from(200),
+                amount0_min: U256::from(0),
+                amount1_min: U256::from(0),
+                deadline: 99999999999_u64,
+            }),
+            0.into(),
+        );
+        let owed0 = tc.position(token_id()).tokens_owed0;
+        split(&mut tc, 400);
+
+        let position = tc.position(token_id());
+        let new_position = tc.position(2.into());
+        assert!(new_position.tokens_owed0 == owed0 / 2);
+        assert!(position.tokens_owed0 + new_position.tokens_owed0 == owed0);
+    }
+
+    #[test]
+    fn test_merge_folds_tokens_into_the_first() {
+        let mut tc = before_each();
+        split(&mut tc, 400);
+        merge(&mut tc, vec![token_id(), 2.into()]);
+
+        assert!(tc.position(token_id()).liquidity == 1000.into());
+        assert!(tc.position(2.into()).liquidity.is_zero());
+        assert!(tc.cep47_balance_of(other().into()).eq(&1.into()));
+    }
+
+    #[test]
+    #[should_panic = "User(15097)"]
+    fn test_cannot_merge_a_token_with_itself() {
+        let mut tc = before_each();
+        merge(&mut tc, vec![token_id(), token_id()]);
+    }
+
+    #[test]
+    #[should_panic = "User(15097)"]
+    fn test_cannot_merge_different_ranges() {
+        let mut tc = before_each();
+        tc.multicall_liquidity_session(
+            other(),
+            SessionBuilder::new().mint(
+                MintParams {
+                    token0: tc.token0,
+                    token1: tc.token1,
+                    fee: FEE_MEDIUM,
+                    tick_lower: -600_i32,
+                    tick_upper: 600_i32,
+                    amount0_desired: U256::from(1000),
+                    amount1_desired: U256::from(1000),
+                    amount0_min: U256::from(0),
+                    amount1_min: U256::from(0),
+                    recipient: Key::from(other()),
+                    deadline: 99999999999_u64,
+                },
+                false,
+            ),
+            0.into(),
+        );
+        merge(&mut tc, vec![token_id(), 2.into()]);
+    }
+}
+
+#[cfg(test)]
+mod exit_position {
+    use casper_types::{Key, U256, U512};
+    use types::{session::SessionBuilder, ExitPositionParams, MintParams};
+
+    use crate::{
+        pool::fixture::{get_tick_spacing, FEE_MEDIUM},
+        router::fixture::{setup_fixture, TestContext},
+        utils::{encode_price_sqrt, get_max_tick, get_min_tick, other, sort_tokens, wallet},
+    };
+
+    use super::token_id;
+    fn before_each() -> TestContext {
+        let mut tc = setup_fixture();
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    tc.token0,
+                    tc.token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
+                )
+                .mint(
+                    MintParams {
+                        token0: tc.token0,
+                        token1: tc.token1,
+                        fee: FEE_MEDIUM,
+                        tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                        tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                        amount0_desired: U256::from(1000),
+                        amount1_desired: U256::from(1000),
+                        amount0_min: U256::from(0),
+                        amount1_min: U256::from(0),
+                        recipient: Key::from(other()),
+                        deadline: 99999999999_u64,
+                    },
+                    false,
+                ),
+            0.into(),
+        );
+        tc
+    }
+
+    fn exit_data(
+        amount_min: u64,
+        recipient: Key,
+        unwrap_cspr: bool,
+        deadline: u64,
+    ) -> ExitPositionParams {
+        ExitPositionParams {
+            token_id: token_id(),
+            amount0_min: U256::from(amount_min),
+            amount1_min: U256::from(amount_min),
+            recipient,
+            unwrap_cspr,
+            deadline,
+        }
+    }
+
+    #[test]
+    #[should_panic = "User(15052)"]
+    fn test_cannot_be_called_by_other_addresses() {
+        let mut tc = before_each();
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new().exit_position(exit_data(0, wallet().into(), false, 99999999999)),
+            0.into(),
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15049)"]
+    fn test_fails_if_past_deadline() {
+        let mut tc = before_each();
+        tc.test_env.set_block_time(2);
+        tc.multicall_liquidity_session(
+            other(),
+            SessionBuilder::new().exit_position(exit_data(0, other().into(), false, 1)),
+            0.into(),
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15050)"]
+    fn test_fails_below_the_minimum_amounts() {
+        let mut tc = before_each();
+        tc.multicall_liquidity_session(
+            other(),
+            SessionBuilder::new().exit_position(exit_data(
+                1000,
+                other().into(),
+                false,
+                99999999999,
+            )),
+            0.into(),
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15104)"]
+    fn test_cannot_unwrap_cspr_to_a_contract() {
+        let mut tc = before_each();
+        let recipient = tc.router;
+        tc.multicall_liquidity_session(
+            other(),
+            SessionBuilder::new().exit_position(exit_data(0, recipient, true, 99999999999)),
+            0.into(),
+        );
+    }
+
+    #[test]
+    fn test_closes_the_position_and_pays_the_recipient() {
+        let mut tc = before_each();
+        let bal0_before = tc.test_env.balance_of(tc.token0, wallet().into());
+        let bal1_before = tc.test_env.balance_of(tc.token1, wallet().into());
+        tc.multicall_liquidity_session(
+            other(),
+            SessionBuilder::new().exit_position(exit_data(0, wallet().into(), false, 99999999999)),
+            0.into(),
+        );
+
+        assert!(tc.cep47_balance_of(other().into()).is_zero());
+        assert!(tc.position(token_id()).liquidity.is_zero());
+        assert!(tc.test_env.balance_of(tc.token0, wallet().into()) == bal0_before + 999);
+        assert!(tc.test_env.balance_of(tc.token1, wallet().into()) == bal1_before + 999);
+        assert!(tc.test_env.balance_of(tc.token0, tc.router).is_zero());
+        assert!(tc.test_env.balance_of(tc.token1, tc.router).is_zero());
+    }
+
+    #[test]
+    fn test_can_unwrap_cspr() {
+        let mut tc = setup_fixture();
+        let (token0, token1) = sort_tokens(tc.token0, tc.wcspr);
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    token0,
+                    token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
+                )
+                .mint(
+                    MintParams {
+                        token0,
+                        token1,
+                        fee: FEE_MEDIUM,
+                        tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                        tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                        amount0_desired: U256::from(1000),
+                        amount1_desired: U256::from(1000),
+                        amount0_min: U256::from(0),
+                        amount1_min: U256::from(0),
+                        recipient: Key::from(other()),
+                        deadline: 99999999999_u64,
+                    },
+                    true,
+                ),
+            U512::from(1000),
+        );
+
+        let cspr_before = tc.test_env.get_account_cspr_balance(wallet());
+        tc.multicall_liquidity_session(
+            other(),
+            SessionBuilder::new().exit_position(exit_data(0, wallet().into(), true, 99999999999)),
+            0.into(),
+        );
+        assert!(tc.test_env.get_account_cspr_balance(wallet()) == cspr_before + 999);
+        assert!(tc.test_env.balance_of(tc.wcspr, tc.router).is_zero());
+    }
+}
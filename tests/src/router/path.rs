@@ -97,4 +97,124 @@ mod path {
         assert!(token_b == token_addresses()[2]);
         assert!(fee == FEE_MEDIUM);
     }
-}
\ No newline at end of file
+
+    #[test]
+    #[should_panic = "User(15102)"]
+    fn test_views_revert_on_a_truncated_path() {
+        let mut tc = setup_fixture();
+        let _: (Key, Key, u32) = tc.test_env.call_view_function(
+            tc.router,
+            "decode_first_pool",
+            runtime_args! {
+                "path" => Bytes::from(encoded_path()[..40].to_vec()),
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod path_builder {
+    use casper_types::{account::AccountHash, Key};
+    use common::path::{Path, MAX_HOPS};
+
+    use crate::{pool::fixture::FEE_MEDIUM, utils::encode_path};
+
+    fn token(i: u8) -> Key {
+        Key::Hash([i; 32])
+    }
+
+    fn enabled(fee: u32) -> bool {
+        fee == FEE_MEDIUM
+    }
+
+    #[test]
+    fn test_encodes_like_encode_path() {
+        let path = Path::new(token(1))
+            .hop(FEE_MEDIUM, token(2))
+            .hop(FEE_MEDIUM, token(3));
+        let encoded = path.encode().unwrap();
+        assert!(encoded == encode_path(vec![token(1), token(2), token(3)], vec![FEE_MEDIUM; 2]));
+        assert!(Path::decode(&encoded).ok() == Some(path));
+    }
+
+    #[test]
+    fn test_reverses_tokens_and_fees() {
+        let path = Path::new(token(1))
+            .hop(500, token(2))
+            .hop(FEE_MEDIUM, token(3));
+        let reversed = path.reverse();
+        assert!(
+            reversed
+                == Path::new(token(3))
+                    .hop(FEE_MEDIUM, token(2))
+                    .hop(500, token(1))
+        );
+        assert!(reversed.first_token() == path.last_token());
+        assert!(reversed.num_pools() == 2);
+    }
+
+    #[test]
+    fn test_rejects_malformed_bytes() {
+        let encoded = encode_path(vec![token(1), token(2)], vec![FEE_MEDIUM]);
+        assert!(Path::decode(&encoded[..31]).is_err());
+        assert!(Path::decode(&encoded[..encoded.len() - 1]).is_err());
+        assert!(Path::decode(&encoded[..36]).is_err());
+    }
+
+    #[test]
+    fn test_validates_hops() {
+        assert!(Path::new(token(1))
+            .hop(FEE_MEDIUM, token(2))
+            .validate(enabled)
+            .is_ok());
+        assert!(Path::new(token(1)).validate(enabled).is_err());
+        assert!(Path::new(token(1))
+            .hop(500, token(2))
+            .validate(enabled)
+            .is_err());
+        assert!(Path::new(token(1))
+            .hop(500, token(2))
+            .validate_hops()
+            .is_ok());
+        assert!(Path::new(token(1))
+            .hop(FEE_MEDIUM, token(1))
+            .validate(enabled)
+            .is_err());
+        assert!(Path::new(token(1))
+            .hop(FEE_MEDIUM, Key::Account(AccountHash::new([2; 32])))
+            .validate(enabled)
+            .is_err());
+    }
+
+    #[test]
+    fn test_cannot_encode_other_key_variants() {
+        let path = Path::new(token(1)).hop(FEE_MEDIUM, Key::Account(AccountHash::new([2; 32])));
+        assert!(path.encode().is_err());
+    }
+
+    #[test]
+    fn test_walks_the_pools() {
+        let path = Path::new(token(1))
+            .hop(500, token(2))
+            .hop(FEE_MEDIUM, token(3));
+        assert!(path.first_pool() == (token(1), token(2), 500));
+        let rest = path.skip_token();
+        assert!(rest == Path::new(token(2)).hop(FEE_MEDIUM, token(3)));
+        assert!(rest.first_pool() == (token(2), token(3), FEE_MEDIUM));
+        assert!(path.pools() == vec![path.first_pool(), rest.first_pool()]);
+    }
+
+    #[test]
+    fn test_rejects_repeated_pools_and_long_paths() {
+        let repeated = Path::new(token(1))
+            .hop(FEE_MEDIUM, token(2))
+            .hop(FEE_MEDIUM, token(1));
+        assert!(repeated.validate(enabled).is_err());
+
+        let mut long = Path::new(token(0));
+        for i in 1..=MAX_HOPS as u8 + 1 {
+            long = long.hop(FEE_MEDIUM, token(i));
+        }
+        assert!(long.validate(enabled).is_err());
+    }
+}
@@ -0,0 +1,352 @@
+#[cfg(test)]
+mod session {
+    use casper_types::{bytesrepr::Bytes, Key, U256};
+    use types::{
+        session::{SessionBuilder, SessionCommand, SessionCommandError, SESSION_COMMAND_VERSION},
+        MintParams,
+    };
+
+    use crate::{
+        constants,
+        pool::fixture::FEE_MEDIUM,
+        router::fixture::setup_fixture,
+        utils::{encode_price_sqrt, other, wallet},
+    };
+
+    fn mint_command() -> SessionCommand {
+        SessionCommand::Mint {
+            params: MintParams {
+                token0: Key::Hash([1u8; 32]),
+                token1: Key::Hash([2u8; 32]),
+                fee: FEE_MEDIUM,
+                tick_lower: -600,
+                tick_upper: 600,
+                amount0_desired: U256::from(100),
+                amount1_desired: U256::from(100),
+                amount0_min: U256::zero(),
+                amount1_min: U256::zero(),
+                recipient: Key::from(other()),
+                deadline: 99999999999,
+            },
+            wrap_cspr: true,
+        }
+    }
+
+    #[test]
+    fn test_commands_round_trip() {
+        let bytes = mint_command().to_versioned_bytes();
+        assert!(bytes[0] == SESSION_COMMAND_VERSION);
+        let decoded = SessionCommand::from_versioned_bytes(&bytes).unwrap();
+        assert!(decoded.to_versioned_bytes() == bytes);
+        match decoded {
+            SessionCommand::Mint { params, wrap_cspr } => {
+                assert!(wrap_cspr);
+                assert!(params.tick_lower == -600);
+                assert!(params.recipient == Key::from(other()));
+            }
+            _ => panic!("decoded the wrong command"),
+        }
+    }
+
+    #[test]
+    fn test_rejects_unknown_versions_and_commands() {
+        let mut bytes = mint_command().to_versioned_bytes();
+        bytes[0] = SESSION_COMMAND_VERSION + 1;
+        assert!(
+            SessionCommand::from_versioned_bytes(&bytes).err()
+                == Some(SessionCommandError::UnsupportedVersion(
+                    SESSION_COMMAND_VERSION + 1
+                ))
+        );
+        assert!(
+            SessionCommand::from_versioned_bytes(&[SESSION_COMMAND_VERSION, 255]).err()
+                == Some(SessionCommandError::InvalidCommand)
+        );
+
+        let mut bytes = mint_command().to_versioned_bytes();
+        bytes.push(0);
+        assert!(
+            SessionCommand::from_versioned_bytes(&bytes).err()
+                == Some(SessionCommandError::InvalidCommand)
+        );
+    }
+
+    #[test]
+    fn test_builder_versions_every_command() {
+        let datas = SessionBuilder::new()
+            .refund_cspr()
+            .unwrap_cspr(U256::zero(), Key::from(other()))
+            .build();
+        assert!(datas.len() == 2);
+        assert!(datas.iter().all(|data| data[0] == SESSION_COMMAND_VERSION));
+    }
+
+    #[test]
+    #[should_panic = "User(15099)"]
+    fn test_session_fails_on_an_unknown_version() {
+        let mut tc = setup_fixture();
+        let mut data = SessionCommand::RefundCspr {}.to_versioned_bytes();
+        data[0] = SESSION_COMMAND_VERSION + 1;
+        tc.deploy_session(
+            wallet(),
+            constants::LIQUIDITY_SESSION,
+            vec![Bytes::from(data)],
+            0.into(),
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15100)"]
+    fn test_session_fails_on_an_unknown_command() {
+        let mut tc = setup_fixture();
+        tc.deploy_session(
+            wallet(),
+            constants::SWAP_SESSION,
+            vec![Bytes::from(vec![SESSION_COMMAND_VERSION, 255])],
+            0.into(),
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15100)"]
+    fn test_swap_session_rejects_liquidity_commands() {
+        let mut tc = setup_fixture();
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().create_and_initialize_pool_if_necessary(
+                tc.token0,
+                tc.token1,
+                FEE_MEDIUM,
+                encode_price_sqrt(1, 1),
+            ),
+            0.into(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod session_result {
+    use casper_types::{Key, U256};
+    use contract_utilities::helpers::null_key;
+    use types::{session::SessionBuilder, ExactInputParams, MintParams};
+
+    use crate::{
+        pool::fixture::{get_tick_spacing, FEE_MEDIUM},
+        router::fixture::{setup_fixture, TestContext},
+        utils::{encode_path, encode_price_sqrt, get_max_tick, get_min_tick, other, wallet},
+    };
+
+    fn mint(tc: &TestContext, recipient: Key) -> SessionBuilder {
+        SessionBuilder::new()
+            .create_and_initialize_pool_if_necessary(
+                tc.token0,
+                tc.token1,
+                FEE_MEDIUM,
+                encode_price_sqrt(1, 1),
+            )
+            .mint(
+                MintParams {
+                    token0: tc.token0,
+                    token1: tc.token1,
+                    fee: FEE_MEDIUM,
+                    tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                    tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                    amount0_desired: U256::from(1000000),
+                    amount1_desired: U256::from(1000000),
+                    amount0_min: U256::from(0),
+                    amount1_min: U256::from(0),
+                    recipient,
+                    deadline: 99999999999,
+                },
+                false,
+            )
+    }
+
+    #[test]
+    fn test_liquidity_session_reports_every_step() {
+        let mut tc = setup_fixture();
+        let session = mint(&tc, other().into());
+        tc.multicall_liquidity_session(wallet(), session, 0.into());
+
+        let result = tc.session_result(wallet());
+        assert!(result.steps.len() == 2);
+        assert!(result.steps[0].token_id.is_zero());
+        let minted = &result.steps[1];
+        let position = tc.position(1.into());
+        assert!(minted.token_id == U256::one());
+        assert!(minted.liquidity == position.liquidity);
+        assert!(minted.amount0 == U256::from(1000000));
+        assert!(minted.amount1 == U256::from(1000000));
+    }
+
+    #[test]
+    fn test_swap_session_reports_the_amounts() {
+        let mut tc = setup_fixture();
+        let session = mint(&tc, wallet().into());
+        tc.multicall_liquidity_session(wallet(), session, 0.into());
+
+        let bal1_before = tc.test_env.balance_of(tc.token1, other().into());
+        let swap = SessionBuilder::new().exact_input(
+            ExactInputParams {
+                path: encode_path(vec![tc.token0, tc.token1], vec![FEE_MEDIUM]),
+                recipient: Key::from(other()),
+                deadline: 99999999999,
+                amount_in: U256::from(1000),
+                amount_out_minimum: U256::zero(),
+                fee_recipient: null_key(),
+                fee_bips: 0,
+            },
+            false,
+        );
+        let tag = swap.commands()[0].tag();
+        tc.multicall_swap_session(wallet(), swap, 0.into());
+
+        let result = tc.session_result(wallet());
+        assert!(result.steps.len() == 1);
+        assert!(result.steps[0].command == tag);
+        assert!(result.steps[0].amount0 == U256::from(1000));
+        assert!(
+            result.steps[0].amount1
+                == tc.test_env.balance_of(tc.token1, other().into()) - bal1_before
+        );
+    }
+}
+
+#[cfg(test)]
+mod session_funds {
+    use casper_types::{Key, U256, U512};
+    use contract_utilities::helpers::null_key;
+    use types::{session::SessionBuilder, ExactInputParams, ExactOutputParams, MintParams};
+
+    use crate::{
+        pool::fixture::{get_tick_spacing, FEE_MEDIUM},
+        router::fixture::setup_fixture,
+        utils::{
+            encode_path, encode_price_sqrt, get_max_tick, get_min_tick, other, sort_tokens, wallet,
+        },
+    };
+
+    fn mint_params(token0: Key, token1: Key, amount: u64) -> MintParams {
+        MintParams {
+            token0,
+            token1,
+            fee: FEE_MEDIUM,
+            tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+            tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+            amount0_desired: U256::from(amount),
+            amount1_desired: U256::from(amount),
+            amount0_min: U256::from(0),
+            amount1_min: U256::from(0),
+            recipient: Key::from(other()),
+            deadline: 99999999999,
+        }
+    }
+
+    #[test]
+    fn test_revokes_the_unused_allowance() {
+        let mut tc = setup_fixture();
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    tc.token0,
+                    tc.token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
+                )
+                .mint(mint_params(tc.token0, tc.token1, 1000000), false),
+            0.into(),
+        );
+
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().exact_output(
+                ExactOutputParams {
+                    path: encode_path(vec![tc.token1, tc.token0], vec![FEE_MEDIUM]),
+                    recipient: Key::from(other()),
+                    deadline: 99999999999,
+                    amount_out: U256::from(1000),
+                    amount_in_maximum: U256::from(5000),
+                    fee_recipient: null_key(),
+                    fee_bips: 0,
+                },
+                false,
+            ),
+            0.into(),
+        );
+        assert!(tc
+            .test_env
+            .allowance(tc.token0, wallet().into(), tc.router)
+            .is_zero());
+        assert!(tc
+            .test_env
+            .allowance(tc.token1, wallet().into(), tc.router)
+            .is_zero());
+    }
+
+    #[test]
+    fn test_refunds_the_unused_wrapped_cspr() {
+        let mut tc = setup_fixture();
+        let (token0, token1) = sort_tokens(tc.token0, tc.wcspr);
+        let wcspr_before = tc.test_env.balance_of(tc.wcspr, wallet().into());
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    token0,
+                    token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
+                )
+                .mint(mint_params(token0, token1, 100), true),
+            U512::from(1000),
+        );
+        assert!(tc.test_env.balance_of(tc.wcspr, wallet().into()) == wcspr_before);
+        assert!(tc
+            .test_env
+            .allowance(tc.wcspr, wallet().into(), tc.router)
+            .is_zero());
+        assert!(tc.position(1.into()).liquidity.as_u128() == 100);
+    }
+
+    #[test]
+    fn test_keeps_the_wrapped_cspr_received_from_swaps() {
+        let mut tc = setup_fixture();
+        let (token0, token1) = sort_tokens(tc.token0, tc.wcspr);
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    token0,
+                    token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
+                )
+                .mint(mint_params(token0, token1, 1000000), true),
+            U512::from(1000000),
+        );
+
+        let swap = |token_in: Key, token_out: Key, amount_in: u64| ExactInputParams {
+            path: encode_path(vec![token_in, token_out], vec![FEE_MEDIUM]),
+            recipient: Key::from(wallet()),
+            deadline: 99999999999,
+            amount_in: U256::from(amount_in),
+            amount_out_minimum: U256::zero(),
+            fee_recipient: null_key(),
+            fee_bips: 0,
+        };
+        let wcspr_before = tc.test_env.balance_of(tc.wcspr, wallet().into());
+        // the wrapped CSPR is spent by the first swap, the second one pays out wCSPR
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new()
+                .exact_input(swap(tc.wcspr, tc.token0, 1000), true)
+                .exact_input(swap(tc.token0, tc.wcspr, 500), false),
+            U512::from(1000),
+        );
+
+        let received = tc.session_result(wallet()).steps[1].amount1;
+        assert!(!received.is_zero());
+        assert!(tc.test_env.balance_of(tc.wcspr, wallet().into()) == wcspr_before + received);
+    }
+}
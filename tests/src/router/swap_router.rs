@@ -0,0 +1,834 @@
+#[cfg(test)]
+mod sweep_token {
+    use casper_types::{Key, U256};
+    use contract_utilities::helpers::null_key;
+    use types::{session::SessionBuilder, ExactInputParams, MintParams};
+
+    use crate::{
+        pool::fixture::{get_tick_spacing, FEE_MEDIUM},
+        router::fixture::{setup_fixture, TestContext},
+        utils::{encode_path, encode_price_sqrt, get_max_tick, get_min_tick, other, wallet},
+    };
+
+    fn before_each() -> TestContext {
+        let mut tc = setup_fixture();
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    tc.token0,
+                    tc.token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
+                )
+                .mint(
+                    MintParams {
+                        token0: tc.token0,
+                        token1: tc.token1,
+                        fee: FEE_MEDIUM,
+                        tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                        tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                        amount0_desired: U256::from(1000000),
+                        amount1_desired: U256::from(1000000),
+                        amount0_min: U256::from(0),
+                        amount1_min: U256::from(0),
+                        recipient: Key::from(wallet()),
+                        deadline: 99999999999_u64,
+                    },
+                    false,
+                ),
+            0.into(),
+        );
+        tc
+    }
+
+    fn swap_to_router_data(tc: &TestContext, amount_in: U256) -> ExactInputParams {
+        ExactInputParams {
+            path: encode_path(vec![tc.token0, tc.token1], vec![FEE_MEDIUM]),
+            recipient: null_key(),
+            deadline: 99999999999,
+            amount_in,
+            amount_out_minimum: U256::from(0),
+            fee_recipient: null_key(),
+            fee_bips: 0,
+        }
+    }
+
+    #[test]
+    fn test_sweeps_swap_output_held_by_the_router() {
+        let mut tc = before_each();
+        let bal1_before = tc.test_env.balance_of(tc.token1, other().into());
+        let swap_data = swap_to_router_data(&tc, U256::from(1000));
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new()
+                .exact_input(swap_data, false)
+                .sweep_token(tc.token1, U256::from(1), Key::from(other())),
+            0.into(),
+        );
+        assert!(tc.test_env.balance_of(tc.token1, other().into()) > bal1_before);
+        assert!(tc.test_env.balance_of(tc.token0, tc.router).is_zero());
+        assert!(tc.test_env.balance_of(tc.token1, tc.router).is_zero());
+    }
+
+    #[test]
+    #[should_panic = "User(15065)"]
+    fn test_fails_if_balance_is_below_minimum() {
+        let mut tc = before_each();
+        let swap_data = swap_to_router_data(&tc, U256::from(1000));
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new()
+                .exact_input(swap_data, false)
+                .sweep_token(tc.token1, U256::from(1000), Key::from(other())),
+            0.into(),
+        );
+    }
+
+    #[test]
+    fn test_refund_cspr_without_balance() {
+        let mut tc = before_each();
+        tc.multicall_swap_session(wallet(), SessionBuilder::new().refund_cspr(), 0.into());
+        assert!(tc.test_env.balance_of(tc.wcspr, tc.router).is_zero());
+    }
+}
+
+#[cfg(test)]
+mod split {
+    use std::ops::Add;
+
+    use casper_types::{Key, U256};
+    use types::{
+        session::SessionBuilder, ExactInputLeg, ExactInputSplitParams, ExactOutputLeg,
+        ExactOutputSplitParams, MintParams,
+    };
+
+    use crate::{
+        pool::fixture::{get_tick_spacing, FEE_LOW, FEE_MEDIUM},
+        router::fixture::{setup_fixture, TestContext},
+        utils::{encode_path, encode_price_sqrt, get_max_tick, get_min_tick, other, wallet},
+    };
+
+    fn before_each() -> TestContext {
+        let mut tc = setup_fixture();
+        for fee in [FEE_MEDIUM, FEE_LOW] {
+            tc.multicall_liquidity_session(
+                wallet(),
+                SessionBuilder::new()
+                    .create_and_initialize_pool_if_necessary(
+                        tc.token0,
+                        tc.token1,
+                        fee,
+                        encode_price_sqrt(1, 1),
+                    )
+                    .mint(
+                        MintParams {
+                            token0: tc.token0,
+                            token1: tc.token1,
+                            fee,
+                            tick_lower: get_min_tick(get_tick_spacing(fee)),
+                            tick_upper: get_max_tick(get_tick_spacing(fee)),
+                            amount0_desired: U256::from(1000000),
+                            amount1_desired: U256::from(1000000),
+                            amount0_min: U256::from(0),
+                            amount1_min: U256::from(0),
+                            recipient: Key::from(wallet()),
+                            deadline: 99999999999_u64,
+                        },
+                        false,
+                    ),
+                0.into(),
+            );
+        }
+        tc
+    }
+
+    fn input_legs(tc: &TestContext, token_out: Key) -> Vec<ExactInputLeg> {
+        vec![
+            ExactInputLeg {
+                path: encode_path(vec![tc.token0, tc.token1], vec![FEE_MEDIUM]),
+                amount_in: U256::from(1000),
+            },
+            ExactInputLeg {
+                path: encode_path(vec![tc.token0, token_out], vec![FEE_LOW]),
+                amount_in: U256::from(1000),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_exact_input_across_fee_tiers() {
+        let mut tc = before_each();
+        let bal0_before = tc.test_env.balance_of(tc.token0, wallet().into());
+        let bal1_before = tc.test_env.balance_of(tc.token1, other().into());
+        let data = ExactInputSplitParams {
+            legs: input_legs(&tc, tc.token1),
+            recipient: Key::from(other()),
+            deadline: 99999999999_u64,
+            amount_out_minimum: U256::from(1900),
+        };
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().exact_input_split(data, false),
+            0.into(),
+        );
+        assert!(tc
+            .test_env
+            .balance_of(tc.token0, wallet().into())
+            .add(2000)
+            .eq(&bal0_before));
+        assert!(tc.test_env.balance_of(tc.token1, other().into()) >= bal1_before.add(1900));
+        assert!(tc.test_env.balance_of(tc.token0, tc.router).is_zero());
+        assert!(tc.test_env.balance_of(tc.token1, tc.router).is_zero());
+    }
+
+    #[test]
+    #[should_panic = "User(15067)"]
+    fn test_fails_if_legs_do_not_share_tokens() {
+        let mut tc = before_each();
+        let data = ExactInputSplitParams {
+            legs: input_legs(&tc, tc.token2),
+            recipient: Key::from(other()),
+            deadline: 99999999999_u64,
+            amount_out_minimum: U256::from(0),
+        };
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().exact_input_split(data, false),
+            0.into(),
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15059)"]
+    fn test_fails_if_aggregate_output_is_too_low() {
+        let mut tc = before_each();
+        let data = ExactInputSplitParams {
+            legs: input_legs(&tc, tc.token1),
+            recipient: Key::from(other()),
+            deadline: 99999999999_u64,
+            amount_out_minimum: U256::from(2000),
+        };
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().exact_input_split(data, false),
+            0.into(),
+        );
+    }
+
+    #[test]
+    fn test_exact_output_across_fee_tiers() {
+        let mut tc = before_each();
+        let bal1_before = tc.test_env.balance_of(tc.token1, other().into());
+        let data = ExactOutputSplitParams {
+            legs: vec![
+                ExactOutputLeg {
+                    path: encode_path(vec![tc.token1, tc.token0], vec![FEE_MEDIUM]),
+                    amount_out: U256::from(500),
+                },
+                ExactOutputLeg {
+                    path: encode_path(vec![tc.token1, tc.token0], vec![FEE_LOW]),
+                    amount_out: U256::from(500),
+                },
+            ],
+            recipient: Key::from(other()),
+            deadline: 99999999999_u64,
+            amount_in_maximum: U256::from(1100),
+        };
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().exact_output_split(data, false),
+            0.into(),
+        );
+        assert!(tc
+            .test_env
+            .balance_of(tc.token1, other().into())
+            .eq(&bal1_before.add(1000)));
+        assert!(tc.test_env.balance_of(tc.token0, tc.router).is_zero());
+    }
+}
+
+#[cfg(test)]
+mod exact_output {
+    use casper_types::{Key, U256};
+    use contract_utilities::helpers::null_key;
+    use types::{session::SessionBuilder, ExactOutputParams, ExactOutputSingleParams, MintParams};
+
+    use crate::{
+        pool::fixture::{get_tick_spacing, FEE_MEDIUM},
+        router::fixture::{setup_fixture, TestContext},
+        utils::{
+            encode_path, encode_price_sqrt, get_max_tick, get_min_tick, other, sort_tokens, wallet,
+        },
+    };
+
+    fn create_pool(tc: &mut TestContext, token_a: Key, token_b: Key) {
+        let (token0, token1) = sort_tokens(token_a, token_b);
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    token0,
+                    token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
+                )
+                .mint(
+                    MintParams {
+                        token0,
+                        token1,
+                        fee: FEE_MEDIUM,
+                        tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                        tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                        amount0_desired: U256::from(1000000),
+                        amount1_desired: U256::from(1000000),
+                        amount0_min: U256::from(0),
+                        amount1_min: U256::from(0),
+                        recipient: Key::from(wallet()),
+                        deadline: 99999999999_u64,
+                    },
+                    false,
+                ),
+            0.into(),
+        );
+    }
+
+    fn before_each() -> TestContext {
+        let mut tc = setup_fixture();
+        let (token0, token1, token2) = (tc.token0, tc.token1, tc.token2);
+        create_pool(&mut tc, token0, token1);
+        create_pool(&mut tc, token1, token2);
+        tc
+    }
+
+    #[test]
+    fn test_exact_output_single() {
+        let mut tc = before_each();
+        let bal0_before = tc.test_env.balance_of(tc.token0, wallet().into());
+        let bal1_before = tc.test_env.balance_of(tc.token1, other().into());
+        let data = ExactOutputSingleParams {
+            token_in: tc.token0,
+            token_out: tc.token1,
+            fee: FEE_MEDIUM,
+            recipient: Key::from(other()),
+            deadline: 99999999999_u64,
+            amount_out: U256::from(1000),
+            amount_in_maximum: U256::from(1100),
+            sqrt_price_limit_x96: U256::zero(),
+            fee_recipient: null_key(),
+            fee_bips: 0,
+        };
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().exact_output_single(data, false),
+            0.into(),
+        );
+
+        assert!(tc.test_env.balance_of(tc.token1, other().into()) - bal1_before == 1000.into());
+        let spent = bal0_before - tc.test_env.balance_of(tc.token0, wallet().into());
+        assert!(spent > 1000.into() && spent <= 1100.into());
+    }
+
+    // the pools of an exact output path are paid from the output back to the input
+    #[test]
+    fn test_exact_output_across_two_pools() {
+        let mut tc = before_each();
+        let bal0_before = tc.test_env.balance_of(tc.token0, wallet().into());
+        let bal2_before = tc.test_env.balance_of(tc.token2, other().into());
+        let data = ExactOutputParams {
+            path: encode_path(
+                vec![tc.token2, tc.token1, tc.token0],
+                vec![FEE_MEDIUM, FEE_MEDIUM],
+            ),
+            recipient: Key::from(other()),
+            deadline: 99999999999_u64,
+            amount_out: U256::from(1000),
+            amount_in_maximum: U256::from(1100),
+            fee_recipient: null_key(),
+            fee_bips: 0,
+        };
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().exact_output(data, false),
+            0.into(),
+        );
+
+        assert!(tc.test_env.balance_of(tc.token2, other().into()) - bal2_before == 1000.into());
+        let spent = bal0_before - tc.test_env.balance_of(tc.token0, wallet().into());
+        assert!(spent > 1000.into() && spent <= 1100.into());
+        for token in [tc.token0, tc.token1, tc.token2] {
+            assert!(tc.test_env.balance_of(token, tc.router).is_zero());
+        }
+    }
+}
+
+#[cfg(test)]
+mod swap_events {
+    use casper_types::{Key, U256};
+    use common::router_events::{SwapExactInput, SwapExactOutput};
+    use contract_utilities::helpers::null_key;
+    use types::{
+        session::SessionBuilder, ExactInputLeg, ExactInputParams, ExactInputSplitParams,
+        ExactOutputParams, MintParams,
+    };
+
+    use crate::{
+        pool::fixture::{get_tick_spacing, FEE_MEDIUM},
+        router::fixture::{setup_fixture, TestContext},
+        utils::{encode_path, encode_price_sqrt, get_max_tick, get_min_tick, other, wallet},
+    };
+
+    fn before_each() -> TestContext {
+        let mut tc = setup_fixture();
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    tc.token0,
+                    tc.token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
+                )
+                .mint(
+                    MintParams {
+                        token0: tc.token0,
+                        token1: tc.token1,
+                        fee: FEE_MEDIUM,
+                        tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                        tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                        amount0_desired: U256::from(1000000),
+                        amount1_desired: U256::from(1000000),
+                        amount0_min: U256::from(0),
+                        amount1_min: U256::from(0),
+                        recipient: Key::from(wallet()),
+                        deadline: 99999999999_u64,
+                    },
+                    false,
+                ),
+            0.into(),
+        );
+        tc
+    }
+
+    #[test]
+    fn test_exact_input_emits_the_swap() {
+        let mut tc = before_each();
+        let path = encode_path(vec![tc.token0, tc.token1], vec![FEE_MEDIUM]);
+        let bal1_before = tc.test_env.balance_of(tc.token1, other().into());
+        let data = ExactInputParams {
+            path: path.clone(),
+            recipient: Key::from(other()),
+            deadline: 99999999999_u64,
+            amount_in: U256::from(1000),
+            amount_out_minimum: U256::from(0),
+            fee_recipient: null_key(),
+            fee_bips: 0,
+        };
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().exact_input(data, false),
+            0.into(),
+        );
+
+        let event: SwapExactInput = tc.test_env.get_last_event(tc.router).unwrap();
+        assert!(event.payer == Key::from(wallet()));
+        assert!(event.recipient == Key::from(other()));
+        assert!(event.path == path);
+        assert!(event.amount_in == U256::from(1000));
+        assert!(
+            event.amount_out == tc.test_env.balance_of(tc.token1, other().into()) - bal1_before
+        );
+        assert!(event.referrer == null_key());
+    }
+
+    #[test]
+    fn test_exact_output_emits_the_swap() {
+        let mut tc = before_each();
+        let path = encode_path(vec![tc.token1, tc.token0], vec![FEE_MEDIUM]);
+        let bal0_before = tc.test_env.balance_of(tc.token0, wallet().into());
+        let data = ExactOutputParams {
+            path: path.clone(),
+            recipient: Key::from(other()),
+            deadline: 99999999999_u64,
+            amount_out: U256::from(1000),
+            amount_in_maximum: U256::from(1100),
+            fee_recipient: null_key(),
+            fee_bips: 0,
+        };
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().exact_output(data, false),
+            0.into(),
+        );
+
+        let event: SwapExactOutput = tc.test_env.get_last_event(tc.router).unwrap();
+        assert!(event.payer == Key::from(wallet()));
+        assert!(event.recipient == Key::from(other()));
+        assert!(event.path == path);
+        assert!(event.amount_out == U256::from(1000));
+        assert!(
+            event.amount_in == bal0_before - tc.test_env.balance_of(tc.token0, wallet().into())
+        );
+    }
+
+    #[test]
+    fn test_exact_input_split_emits_one_swap_per_leg() {
+        let mut tc = before_each();
+        let path = encode_path(vec![tc.token0, tc.token1], vec![FEE_MEDIUM]);
+        let legs = vec![
+            ExactInputLeg {
+                path: path.clone(),
+                amount_in: U256::from(1000),
+            },
+            ExactInputLeg {
+                path: path.clone(),
+                amount_in: U256::from(2000),
+            },
+        ];
+        let data = ExactInputSplitParams {
+            legs,
+            recipient: Key::from(other()),
+            deadline: 99999999999_u64,
+            amount_out_minimum: U256::from(0),
+        };
+        let events_before = tc.test_env.get_event_length(tc.router);
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().exact_input_split(data, false),
+            0.into(),
+        );
+
+        assert!(tc.test_env.get_event_length(tc.router) == events_before + 2);
+        let first: SwapExactInput = tc
+            .test_env
+            .get_event(tc.router, events_before as usize)
+            .unwrap();
+        let second: SwapExactInput = tc.test_env.get_last_event(tc.router).unwrap();
+        assert!(first.amount_in == U256::from(1000));
+        assert!(second.amount_in == U256::from(2000));
+    }
+}
+
+#[cfg(test)]
+mod checked_path {
+    use casper_types::{Key, U256};
+    use contract_utilities::helpers::null_key;
+    use types::{
+        session::SessionBuilder, ExactInputParams, ExactInputSingleParams, ExactOutputSingleParams,
+        MintParams,
+    };
+
+    use crate::{
+        pool::fixture::{get_tick_spacing, FEE_LOW, FEE_MEDIUM},
+        router::fixture::{setup_fixture, TestContext},
+        utils::{encode_path, encode_price_sqrt, get_max_tick, get_min_tick, other, wallet},
+    };
+
+    fn before_each() -> TestContext {
+        let mut tc = setup_fixture();
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    tc.token0,
+                    tc.token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
+                )
+                .mint(
+                    MintParams {
+                        token0: tc.token0,
+                        token1: tc.token1,
+                        fee: FEE_MEDIUM,
+                        tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                        tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                        amount0_desired: U256::from(1000000),
+                        amount1_desired: U256::from(1000000),
+                        amount0_min: U256::from(0),
+                        amount1_min: U256::from(0),
+                        recipient: Key::from(wallet()),
+                        deadline: 99999999999_u64,
+                    },
+                    false,
+                ),
+            0.into(),
+        );
+        tc
+    }
+
+    fn exact_input(tc: &mut TestContext, path: Vec<u8>) {
+        let data = ExactInputParams {
+            path: path.into(),
+            recipient: Key::from(other()),
+            deadline: 99999999999_u64,
+            amount_in: U256::from(1000),
+            amount_out_minimum: U256::from(0),
+            fee_recipient: null_key(),
+            fee_bips: 0,
+        };
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().exact_input(data, false),
+            0.into(),
+        );
+    }
+
+    #[test]
+    fn test_exact_input_single() {
+        let mut tc = before_each();
+        let bal1_before = tc.test_env.balance_of(tc.token1, other().into());
+        let data = ExactInputSingleParams {
+            token_in: tc.token0,
+            token_out: tc.token1,
+            fee: FEE_MEDIUM,
+            recipient: Key::from(other()),
+            deadline: 99999999999_u64,
+            amount_in: U256::from(1000),
+            amount_out_minimum: U256::from(990),
+            sqrt_price_limit_x96: U256::zero(),
+            fee_recipient: null_key(),
+            fee_bips: 0,
+        };
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().exact_input_single(data, false),
+            0.into(),
+        );
+        assert!(tc.test_env.balance_of(tc.token1, other().into()) - bal1_before >= U256::from(990));
+    }
+
+    #[test]
+    #[should_panic = "User(15102)"]
+    fn test_exact_output_single_fails_on_a_disabled_fee_tier() {
+        let mut tc = before_each();
+        let data = ExactOutputSingleParams {
+            token_in: tc.token0,
+            token_out: tc.token1,
+            fee: 1234,
+            recipient: Key::from(other()),
+            deadline: 99999999999_u64,
+            amount_out: U256::from(1000),
+            amount_in_maximum: U256::from(1100),
+            sqrt_price_limit_x96: U256::zero(),
+            fee_recipient: null_key(),
+            fee_bips: 0,
+        };
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().exact_output_single(data, false),
+            0.into(),
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15102)"]
+    fn test_fails_on_a_truncated_path() {
+        let mut tc = before_each();
+        let path = encode_path(vec![tc.token0, tc.token1], vec![FEE_MEDIUM]);
+        exact_input(&mut tc, path[..path.len() - 1].to_vec());
+    }
+
+    #[test]
+    #[should_panic = "User(15102)"]
+    fn test_fails_on_a_repeated_pool() {
+        let mut tc = before_each();
+        let path = encode_path(
+            vec![tc.token0, tc.token1, tc.token0, tc.token1],
+            vec![FEE_MEDIUM, FEE_LOW, FEE_MEDIUM],
+        );
+        exact_input(&mut tc, path.to_vec());
+    }
+}
+
+#[cfg(test)]
+mod partner_fee {
+    use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+    use common::router_events::{SwapExactInput, SwapExactOutput};
+    use types::{session::SessionBuilder, ExactInputParams, ExactOutputParams, MintParams};
+
+    use crate::{
+        pool::fixture::{get_tick_spacing, FEE_MEDIUM},
+        router::fixture::{setup_fixture, TestContext},
+        utils::{encode_path, encode_price_sqrt, get_max_tick, get_min_tick, other, wallet},
+    };
+
+    fn before_each() -> TestContext {
+        let mut tc = setup_fixture();
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new()
+                .create_and_initialize_pool_if_necessary(
+                    tc.token0,
+                    tc.token1,
+                    FEE_MEDIUM,
+                    encode_price_sqrt(1, 1),
+                )
+                .mint(
+                    MintParams {
+                        token0: tc.token0,
+                        token1: tc.token1,
+                        fee: FEE_MEDIUM,
+                        tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                        tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                        amount0_desired: U256::from(1000000),
+                        amount1_desired: U256::from(1000000),
+                        amount0_min: U256::from(0),
+                        amount1_min: U256::from(0),
+                        recipient: Key::from(wallet()),
+                        deadline: 99999999999_u64,
+                    },
+                    false,
+                ),
+            0.into(),
+        );
+        set_max_partner_fee_bips(&mut tc, 100);
+        tc
+    }
+
+    fn set_max_partner_fee_bips(tc: &mut TestContext, max_partner_fee_bips: u32) {
+        tc.test_env.call_contract(
+            Some(wallet()),
+            tc.router.into_hash().unwrap().into(),
+            "set_max_partner_fee_bips",
+            runtime_args! {
+                "max_partner_fee_bips" => max_partner_fee_bips,
+            },
+            true,
+        );
+    }
+
+    fn exact_input(tc: &mut TestContext, fee_bips: u32) {
+        let data = ExactInputParams {
+            path: encode_path(vec![tc.token0, tc.token1], vec![FEE_MEDIUM]),
+            recipient: Key::from(wallet()),
+            deadline: 99999999999_u64,
+            amount_in: U256::from(10000),
+            amount_out_minimum: U256::from(0),
+            fee_recipient: other().into(),
+            fee_bips,
+        };
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().exact_input(data, false),
+            0.into(),
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15002)"]
+    fn test_only_owner_sets_the_maximum() {
+        let mut tc = before_each();
+        tc.test_env.call_contract(
+            Some(other()),
+            tc.router.into_hash().unwrap().into(),
+            "set_max_partner_fee_bips",
+            runtime_args! {
+                "max_partner_fee_bips" => 200_u32,
+            },
+            true,
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15098)"]
+    fn test_fails_above_the_maximum() {
+        let mut tc = before_each();
+        exact_input(&mut tc, 101);
+    }
+
+    #[test]
+    fn test_exact_input_takes_the_fee_from_the_output() {
+        let mut tc = before_each();
+        let bal1_before = tc.test_env.balance_of(tc.token1, wallet().into());
+        exact_input(&mut tc, 50);
+
+        let event: SwapExactInput = tc.test_env.get_last_event(tc.router).unwrap();
+        let fee = tc.partner_fees(other().into(), tc.token1);
+        assert!(event.referrer == Key::from(other()));
+        assert!(
+            tc.test_env.balance_of(tc.token1, wallet().into()) == bal1_before + event.amount_out
+        );
+        assert!(fee == (event.amount_out + fee) * 50 / 10000);
+        assert!(tc.test_env.balance_of(tc.token1, tc.router) == fee);
+    }
+
+    #[test]
+    fn test_exact_output_takes_the_fee_from_the_input() {
+        let mut tc = before_each();
+        let bal0_before = tc.test_env.balance_of(tc.token0, wallet().into());
+        let data = ExactOutputParams {
+            path: encode_path(vec![tc.token1, tc.token0], vec![FEE_MEDIUM]),
+            recipient: Key::from(wallet()),
+            deadline: 99999999999_u64,
+            amount_out: U256::from(10000),
+            amount_in_maximum: U256::from(11000),
+            fee_recipient: other().into(),
+            fee_bips: 50,
+        };
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().exact_output(data, false),
+            0.into(),
+        );
+
+        let event: SwapExactOutput = tc.test_env.get_last_event(tc.router).unwrap();
+        let fee = tc.partner_fees(other().into(), tc.token0);
+        assert!(fee > U256::zero());
+        assert!(
+            event.amount_in == bal0_before - tc.test_env.balance_of(tc.token0, wallet().into())
+        );
+        assert!(fee == (event.amount_in - fee) * 50 / 10000);
+        assert!(tc.test_env.balance_of(tc.token0, tc.router) == fee);
+    }
+
+    #[test]
+    fn test_partner_claims_the_accumulated_fees() {
+        let mut tc = before_each();
+        exact_input(&mut tc, 50);
+        exact_input(&mut tc, 50);
+        let fee = tc.partner_fees(other().into(), tc.token1);
+        let bal1_before = tc.test_env.balance_of(tc.token1, other().into());
+
+        tc.test_env.call_contract(
+            Some(other()),
+            tc.router.into_hash().unwrap().into(),
+            "claim_partner_fees",
+            runtime_args! {
+                "token" => tc.token1,
+                "recipient" => Key::from(other()),
+            },
+            true,
+        );
+        assert!(tc.test_env.balance_of(tc.token1, other().into()) == bal1_before + fee);
+        assert!(tc.partner_fees(other().into(), tc.token1).is_zero());
+    }
+
+    #[test]
+    fn test_sweeps_leave_the_accrued_fees() {
+        let mut tc = before_each();
+        exact_input(&mut tc, 50);
+        let fee = tc.partner_fees(other().into(), tc.token1);
+        assert!(!fee.is_zero());
+
+        tc.multicall_swap_session(
+            wallet(),
+            SessionBuilder::new().sweep_token(tc.token1, U256::zero(), Key::from(wallet())),
+            0.into(),
+        );
+        assert!(tc.test_env.balance_of(tc.token1, tc.router) == fee);
+
+        let bal1_before = tc.test_env.balance_of(tc.token1, other().into());
+        tc.test_env.call_contract(
+            Some(other()),
+            tc.router.into_hash().unwrap().into(),
+            "claim_partner_fees",
+            runtime_args! {
+                "token" => tc.token1,
+                "recipient" => Key::from(other()),
+            },
+            true,
+        );
+        assert!(tc.test_env.balance_of(tc.token1, other().into()) == bal1_before + fee);
+        assert!(tc.test_env.balance_of(tc.token1, tc.router).is_zero());
+    }
+}
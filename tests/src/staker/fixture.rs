@@ -0,0 +1,184 @@
+#![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use types::{session::SessionBuilder, IncentiveKey, MintParams};
+
+use crate::{
+    constants,
+    pool::fixture::{get_tick_spacing, FEE_MEDIUM},
+    router::fixture::{setup_fixture, TestContext},
+    utils::{self, encode_price_sqrt, expand_to_18_decimals, get_max_tick, get_min_tick, wallet},
+};
+
+pub const MAX_INCENTIVE_START_LEAD_TIME: u64 = 2592000;
+pub const MAX_INCENTIVE_DURATION: u64 = 63072000;
+
+pub struct StakerContext {
+    pub tc: TestContext,
+    pub staker: Key,
+    pub pool: Key,
+}
+
+pub fn setup_staker_fixture() -> StakerContext {
+    let mut tc = setup_fixture();
+    tc.test_env.set_block_time(1000);
+    tc.multicall_liquidity_session(
+        wallet(),
+        SessionBuilder::new().create_and_initialize_pool_if_necessary(
+            tc.token0,
+            tc.token1,
+            FEE_MEDIUM,
+            encode_price_sqrt(1, 1),
+        ),
+        0.into(),
+    );
+    let pool = tc.get_pool(tc.token0, tc.token1, FEE_MEDIUM);
+
+    tc.test_env.deploy_contract(
+        Some(wallet()),
+        constants::STAKER,
+        runtime_args! {
+            "contract_name" => "staker",
+            "factory" => tc.factory,
+            "router" => tc.router,
+            "max_incentive_start_lead_time" => MAX_INCENTIVE_START_LEAD_TIME,
+            "max_incentive_duration" => MAX_INCENTIVE_DURATION,
+        },
+    );
+    let staker = tc.test_env.get_contract_package_hash(
+        wallet(),
+        &utils::get_contract_package_hash_key("staker".to_string()),
+    );
+    tc.test_env.approve(tc.token2, wallet(), staker, U256::MAX);
+
+    StakerContext { tc, staker, pool }
+}
+
+impl StakerContext {
+    pub fn now(&self) -> u64 {
+        self.tc.test_env.block_time
+    }
+
+    // full range position owned by the wallet, returns its token id
+    pub fn mint_position(&mut self, amount: U256) -> U256 {
+        let tc = &mut self.tc;
+        tc.multicall_liquidity_session(
+            wallet(),
+            SessionBuilder::new().mint(
+                MintParams {
+                    token0: tc.token0,
+                    token1: tc.token1,
+                    fee: FEE_MEDIUM,
+                    tick_lower: get_min_tick(get_tick_spacing(FEE_MEDIUM)),
+                    tick_upper: get_max_tick(get_tick_spacing(FEE_MEDIUM)),
+                    amount0_desired: amount,
+                    amount1_desired: amount,
+                    amount0_min: U256::zero(),
+                    amount1_min: U256::zero(),
+                    recipient: Key::from(wallet()),
+                    deadline: 99999999999_u64,
+                },
+                false,
+            ),
+            0.into(),
+        );
+        let count = tc.cep47_balance_of(wallet().into());
+        tc.token_of_owner_by_index(wallet().into(), count - 1)
+    }
+
+    pub fn incentive_key(&self, start_time: u64, end_time: u64) -> IncentiveKey {
+        IncentiveKey {
+            reward_token: self.tc.token2,
+            pool: self.pool,
+            start_time,
+            end_time,
+            refundee: wallet().into(),
+        }
+    }
+
+    pub fn create_incentive(&mut self, key: &IncentiveKey, reward: U256) {
+        self.call(
+            "create_incentive",
+            runtime_args! {
+                "incentive_key" => key.clone(),
+                "reward" => reward,
+            },
+        );
+    }
+
+    pub fn deposit(&mut self, token_id: U256) {
+        self.tc.test_env.call_contract(
+            Some(wallet()),
+            self.tc.router.into_hash().unwrap().into(),
+            "approve",
+            runtime_args! {
+                "spender" => self.staker,
+                "token_ids" => vec![token_id],
+            },
+            true,
+        );
+        self.call("deposit_token", runtime_args! { "token_id" => token_id });
+    }
+
+    pub fn stake(&mut self, key: &IncentiveKey, token_id: U256) {
+        self.call(
+            "stake_token",
+            runtime_args! {
+                "incentive_key" => key.clone(),
+                "token_id" => token_id,
+            },
+        );
+    }
+
+    pub fn unstake(&mut self, key: &IncentiveKey, token_id: U256) {
+        self.call(
+            "unstake_token",
+            runtime_args! {
+                "incentive_key" => key.clone(),
+                "token_id" => token_id,
+            },
+        );
+    }
+
+    pub fn reward_info(&mut self, key: &IncentiveKey, token_id: U256) -> (U256, U256) {
+        self.tc.test_env.call_view_function(
+            self.staker,
+            "get_reward_info",
+            runtime_args! {
+                "incentive_key" => key.clone(),
+                "token_id" => token_id,
+            },
+        )
+    }
+
+    pub fn rewards(&mut self, owner: Key) -> U256 {
+        let reward_id: String = self.tc.test_env.call_view_function(
+            self.staker,
+            "get_reward_id",
+            runtime_args! {
+                "reward_token" => self.tc.token2,
+                "owner" => owner,
+            },
+        );
+        self.tc.test_env.call_view_function(
+            self.staker,
+            "get_reward",
+            runtime_args! {
+                "reward_id" => reward_id,
+            },
+        )
+    }
+
+    pub fn call(&mut self, entry_point: &str, args: RuntimeArgs) {
+        self.tc.test_env.call_contract(
+            Some(wallet()),
+            self.staker.into_hash().unwrap().into(),
+            entry_point,
+            args,
+            true,
+        );
+    }
+}
+
+pub fn reward_amount() -> U256 {
+    expand_to_18_decimals(1000)
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod staker {
+    use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+
+    use crate::{
+        staker::fixture::{reward_amount, setup_staker_fixture},
+        utils::{expand_to_18_decimals, other, wallet},
+    };
+
+    #[test]
+    fn test_accrues_rewards_by_seconds_inside() {
+        let mut sc = setup_staker_fixture();
+        let token_id = sc.mint_position(expand_to_18_decimals(1));
+        let start_time = sc.now() + 100;
+        let key = sc.incentive_key(start_time, start_time + 1000);
+        sc.create_incentive(&key, reward_amount());
+        sc.deposit(token_id);
+        assert_eq!(sc.tc.cep47_balance_of(sc.staker), U256::one());
+
+        sc.tc.test_env.advance_block_time_by(100);
+        sc.stake(&key, token_id);
+        sc.tc.test_env.advance_block_time_by(500);
+
+        // the only staked position covers all the in range liquidity for half the incentive
+        let (reward, _) = sc.reward_info(&key, token_id);
+        let half = reward_amount() / 2;
+        assert!(reward <= half);
+        assert!(reward > half - expand_to_18_decimals(1) / 1000000);
+
+        sc.unstake(&key, token_id);
+        assert_eq!(sc.rewards(wallet().into()), reward);
+
+        let before = sc.tc.test_env.balance_of(sc.tc.token2, other().into());
+        sc.call(
+            "claim_reward",
+            runtime_args! {
+                "reward_token" => sc.tc.token2,
+                "to" => Key::from(other()),
+                "amount_requested" => U256::zero(),
+            },
+        );
+        assert_eq!(
+            sc.tc.test_env.balance_of(sc.tc.token2, other().into()),
+            before + reward
+        );
+        assert_eq!(sc.rewards(wallet().into()), U256::zero());
+
+        sc.call(
+            "withdraw_token",
+            runtime_args! {
+                "token_id" => token_id,
+                "to" => Key::from(wallet()),
+            },
+        );
+        assert_eq!(sc.tc.cep47_balance_of(sc.staker), U256::zero());
+        assert_eq!(sc.tc.cep47_balance_of(wallet().into()), U256::one());
+    }
+
+    #[test]
+    fn test_end_incentive_refunds_unclaimed_rewards() {
+        let mut sc = setup_staker_fixture();
+        let start_time = sc.now() + 100;
+        let key = sc.incentive_key(start_time, start_time + 1000);
+        let before = sc.tc.test_env.balance_of(sc.tc.token2, wallet().into());
+        sc.create_incentive(&key, reward_amount());
+        assert_eq!(
+            sc.tc.test_env.balance_of(sc.tc.token2, sc.staker),
+            reward_amount()
+        );
+
+        sc.tc.test_env.advance_block_time_by(1100);
+        sc.call(
+            "end_incentive",
+            runtime_args! {
+                "incentive_key" => key,
+            },
+        );
+        assert_eq!(
+            sc.tc.test_env.balance_of(sc.tc.token2, wallet().into()),
+            before
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15069)"]
+    fn test_fails_to_stake_before_start() {
+        let mut sc = setup_staker_fixture();
+        let token_id = sc.mint_position(expand_to_18_decimals(1));
+        let start_time = sc.now() + 100;
+        let key = sc.incentive_key(start_time, start_time + 1000);
+        sc.create_incentive(&key, reward_amount());
+        sc.deposit(token_id);
+        sc.stake(&key, token_id);
+    }
+
+    #[test]
+    #[should_panic = "User(15077)"]
+    fn test_fails_to_withdraw_staked_token() {
+        let mut sc = setup_staker_fixture();
+        let token_id = sc.mint_position(expand_to_18_decimals(1));
+        let start_time = sc.now() + 100;
+        let key = sc.incentive_key(start_time, start_time + 1000);
+        sc.create_incentive(&key, reward_amount());
+        sc.deposit(token_id);
+        sc.tc.test_env.advance_block_time_by(100);
+        sc.stake(&key, token_id);
+        sc.call(
+            "withdraw_token",
+            runtime_args! {
+                "token_id" => token_id,
+                "to" => Key::from(wallet()),
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15072)"]
+    fn test_fails_to_end_incentive_with_stakes() {
+        let mut sc = setup_staker_fixture();
+        let token_id = sc.mint_position(expand_to_18_decimals(1));
+        let start_time = sc.now() + 100;
+        let key = sc.incentive_key(start_time, start_time + 1000);
+        sc.create_incentive(&key, reward_amount());
+        sc.deposit(token_id);
+        sc.tc.test_env.advance_block_time_by(100);
+        sc.stake(&key, token_id);
+        sc.tc.test_env.advance_block_time_by(1000);
+        sc.call(
+            "end_incentive",
+            runtime_args! {
+                "incentive_key" => key,
+            },
+        );
+    }
+}
@@ -0,0 +1,170 @@
+#![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256};
+use contract_utilities::helpers::null_key;
+use types::{session::SessionBuilder, ExactInputParams};
+
+use crate::{
+    constants,
+    pool::fixture::FEE_MEDIUM,
+    router::fixture::{setup_fixture, TestContext},
+    utils::{self, encode_path, encode_price_sqrt, other, wallet},
+};
+
+pub const TICK_LOWER: i32 = -1200;
+pub const TICK_UPPER: i32 = 1200;
+pub const MAX_TWAP_DEVIATION: i32 = 100;
+pub const TWAP_DURATION: u32 = 60;
+// locked by the first deposit, MINIMUM_SHARES in vault/src/logics.rs
+pub const MINIMUM_SHARES: u64 = 1000;
+
+pub struct VaultContext {
+    pub tc: TestContext,
+    pub vault: Key,
+    pub pool: Key,
+}
+
+pub fn setup_vault_fixture() -> VaultContext {
+    let mut tc = setup_fixture();
+    tc.test_env.set_block_time(1000);
+    tc.multicall_liquidity_session(
+        wallet(),
+        SessionBuilder::new().create_and_initialize_pool_if_necessary(
+            tc.token0,
+            tc.token1,
+            FEE_MEDIUM,
+            encode_price_sqrt(1, 1),
+        ),
+        0.into(),
+    );
+    let pool = tc.get_pool(tc.token0, tc.token1, FEE_MEDIUM);
+    tc.test_env.call_contract(
+        Some(wallet()),
+        pool.into_hash().unwrap().into(),
+        "increase_observation_cardinality_next",
+        runtime_args! {
+            "observation_cardinality_next" => 10_u32,
+        },
+        true,
+    );
+
+    tc.test_env.deploy_contract(
+        Some(wallet()),
+        constants::VAULT,
+        runtime_args! {
+            "contract_name" => "vault",
+            "name" => "Vault T0-T1",
+            "symbol" => "VLP",
+            "pool" => pool,
+            "tick_lower" => TICK_LOWER,
+            "tick_upper" => TICK_UPPER,
+            "manager" => Key::from(wallet()),
+            "max_twap_deviation" => MAX_TWAP_DEVIATION,
+            "twap_duration" => TWAP_DURATION,
+            "max_total_supply" => U256::zero(),
+        },
+    );
+    let vault = tc.test_env.get_contract_package_hash(
+        wallet(),
+        &utils::get_contract_package_hash_key("vault".to_string()),
+    );
+    for account in [wallet(), other()] {
+        tc.test_env.approve(tc.token0, account, vault, U256::MAX);
+        tc.test_env.approve(tc.token1, account, vault, U256::MAX);
+    }
+
+    VaultContext { tc, vault, pool }
+}
+
+impl VaultContext {
+    pub fn deposit(&mut self, account: AccountHash, amount0: U256, amount1: U256) {
+        self.call(
+            account,
+            "deposit",
+            runtime_args! {
+                "amount0_desired" => amount0,
+                "amount1_desired" => amount1,
+                "amount0_min" => U256::zero(),
+                "amount1_min" => U256::zero(),
+                "to" => Key::from(account),
+            },
+        );
+    }
+
+    pub fn withdraw(&mut self, account: AccountHash, shares: U256) {
+        self.call(
+            account,
+            "withdraw",
+            runtime_args! {
+                "shares" => shares,
+                "amount0_min" => U256::zero(),
+                "amount1_min" => U256::zero(),
+                "to" => Key::from(account),
+            },
+        );
+    }
+
+    pub fn rebalance(&mut self, tick_lower: i32, tick_upper: i32) {
+        self.call(
+            wallet(),
+            "rebalance",
+            runtime_args! {
+                "tick_lower" => tick_lower,
+                "tick_upper" => tick_upper,
+            },
+        );
+    }
+
+    // swaps token0 for token1 through the router, moving the price down
+    pub fn swap_exact_input(&mut self, amount_in: U256) {
+        let tc = &mut self.tc;
+        let data = ExactInputParams {
+            path: encode_path(vec![tc.token0, tc.token1], vec![FEE_MEDIUM]),
+            recipient: Key::from(other()),
+            deadline: 99999999999_u64,
+            amount_in,
+            amount_out_minimum: U256::zero(),
+            fee_recipient: null_key(),
+            fee_bips: 0,
+        };
+        tc.multicall_swap_session(
+            other(),
+            SessionBuilder::new().exact_input(data, false),
+            0.into(),
+        );
+    }
+
+    pub fn shares_of(&mut self, account: AccountHash) -> U256 {
+        self.tc.test_env.call_view_function(
+            self.vault,
+            "balance_of",
+            runtime_args! {
+                "address" => Key::from(account),
+            },
+        )
+    }
+
+    pub fn total_amounts(&mut self) -> (U256, U256) {
+        self.tc
+            .test_env
+            .call_view_function(self.vault, "get_total_amounts", runtime_args! {})
+    }
+
+    pub fn view<T: casper_types::bytesrepr::FromBytes + casper_types::CLTyped>(
+        &mut self,
+        entry_point: &str,
+    ) -> T {
+        self.tc
+            .test_env
+            .call_view_function(self.vault, entry_point, runtime_args! {})
+    }
+
+    pub fn call(&mut self, account: AccountHash, entry_point: &str, args: RuntimeArgs) {
+        self.tc.test_env.call_contract(
+            Some(account),
+            self.vault.into_hash().unwrap().into(),
+            entry_point,
+            args,
+            true,
+        );
+    }
+}
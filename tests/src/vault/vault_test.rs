@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod vault {
+    use casper_types::{U128, U256};
+
+    use crate::{
+        utils::{expand_to_18_decimals, other, wallet},
+        vault::fixture::{setup_vault_fixture, MINIMUM_SHARES, TICK_LOWER, TICK_UPPER},
+    };
+
+    #[test]
+    fn test_first_deposit_mints_shares_and_liquidity() {
+        let mut vc = setup_vault_fixture();
+        vc.deposit(wallet(), expand_to_18_decimals(1), expand_to_18_decimals(1));
+        assert_eq!(
+            vc.shares_of(wallet()),
+            expand_to_18_decimals(1) - MINIMUM_SHARES
+        );
+        assert_eq!(vc.view::<U256>("total_supply"), expand_to_18_decimals(1));
+        assert!(vc.view::<U128>("get_liquidity") > U128::zero());
+
+        // at price 1 the symmetric range uses both tokens equally
+        let (total0, total1) = vc.total_amounts();
+        assert!(total0 <= expand_to_18_decimals(1) && total0 + 10 >= expand_to_18_decimals(1));
+        assert!(total1 <= expand_to_18_decimals(1) && total1 + 10 >= expand_to_18_decimals(1));
+    }
+
+    #[test]
+    fn test_deposits_are_proportional_to_holdings() {
+        let mut vc = setup_vault_fixture();
+        vc.deposit(wallet(), expand_to_18_decimals(1), expand_to_18_decimals(1));
+        vc.deposit(
+            other(),
+            expand_to_18_decimals(1) / 2,
+            expand_to_18_decimals(1),
+        );
+        // the second depositor is limited by token0
+        let shares = vc.shares_of(other());
+        assert!(shares <= expand_to_18_decimals(1) / 2);
+        assert!(shares + 100 >= expand_to_18_decimals(1) / 2);
+    }
+
+    #[test]
+    fn test_withdraw_returns_holdings_pro_rata() {
+        let mut vc = setup_vault_fixture();
+        vc.deposit(wallet(), expand_to_18_decimals(1), expand_to_18_decimals(1));
+        let balance0 = vc.tc.test_env.balance_of(vc.tc.token0, wallet().into());
+        let balance1 = vc.tc.test_env.balance_of(vc.tc.token1, wallet().into());
+        let shares = vc.shares_of(wallet());
+        vc.withdraw(wallet(), shares / 2);
+
+        let received0 = vc.tc.test_env.balance_of(vc.tc.token0, wallet().into()) - balance0;
+        let received1 = vc.tc.test_env.balance_of(vc.tc.token1, wallet().into()) - balance1;
+        // the locked shares keep their part of the holdings
+        let expected = (expand_to_18_decimals(1) - MINIMUM_SHARES) / 2;
+        assert!(received0 <= expected && received0 + 10 >= expected);
+        assert!(received1 <= expected && received1 + 10 >= expected);
+        assert_eq!(vc.shares_of(wallet()), shares - shares / 2);
+    }
+
+    #[test]
+    fn test_withdraw_includes_collected_fees() {
+        let mut vc = setup_vault_fixture();
+        vc.deposit(wallet(), expand_to_18_decimals(1), expand_to_18_decimals(1));
+        let balance0 = vc.tc.test_env.balance_of(vc.tc.token0, wallet().into());
+        let balance1 = vc.tc.test_env.balance_of(vc.tc.token1, wallet().into());
+        vc.swap_exact_input(expand_to_18_decimals(1) / 10);
+
+        let shares = vc.shares_of(wallet());
+        vc.withdraw(wallet(), shares);
+        let received0 = vc.tc.test_env.balance_of(vc.tc.token0, wallet().into()) - balance0;
+        let received1 = vc.tc.test_env.balance_of(vc.tc.token1, wallet().into()) - balance1;
+
+        // the vault was the only liquidity, it gets the swap input back including the 0.3% fee
+        let amount_in = expand_to_18_decimals(1) / 10;
+        assert!(received0 > expand_to_18_decimals(1) + amount_in * 997 / 1000);
+        assert!(received0 + MINIMUM_SHARES * 2 >= expand_to_18_decimals(1) + amount_in);
+        assert!(received1 < expand_to_18_decimals(1));
+        assert_eq!(vc.view::<U256>("total_supply"), U256::from(MINIMUM_SHARES));
+    }
+
+    #[test]
+    #[should_panic = "User(15079)"]
+    fn test_first_deposit_must_exceed_the_locked_shares() {
+        let mut vc = setup_vault_fixture();
+        vc.deposit(
+            wallet(),
+            U256::from(MINIMUM_SHARES),
+            U256::from(MINIMUM_SHARES),
+        );
+    }
+
+    #[test]
+    #[should_panic = "User(15082)"]
+    fn test_cannot_withdraw_from_an_empty_vault() {
+        let mut vc = setup_vault_fixture();
+        vc.withdraw(wallet(), U256::one());
+    }
+
+    #[test]
+    fn test_rebalance_moves_the_range() {
+        let mut vc = setup_vault_fixture();
+        vc.deposit(wallet(), expand_to_18_decimals(1), expand_to_18_decimals(1));
+        let (total0, total1) = vc.total_amounts();
+        vc.tc.test_env.advance_block_time_by(120);
+        vc.rebalance(TICK_LOWER * 2, TICK_UPPER * 2);
+
+        assert_eq!(vc.view::<i32>("get_tick_lower"), TICK_LOWER * 2);
+        assert_eq!(vc.view::<i32>("get_tick_upper"), TICK_UPPER * 2);
+        let (after0, after1) = vc.total_amounts();
+        assert!(after0 <= total0 && after0 + 10 >= total0);
+        assert!(after1 <= total1 && after1 + 10 >= total1);
+    }
+
+    #[test]
+    #[should_panic = "User(15081)"]
+    fn test_rebalance_fails_when_price_deviates_from_twap() {
+        let mut vc = setup_vault_fixture();
+        vc.deposit(wallet(), expand_to_18_decimals(1), expand_to_18_decimals(1));
+        vc.tc.test_env.advance_block_time_by(120);
+        vc.swap_exact_input(expand_to_18_decimals(1) / 5);
+        vc.rebalance(TICK_LOWER * 2, TICK_UPPER * 2);
+    }
+
+    #[test]
+    #[should_panic = "User(15080)"]
+    fn test_only_manager_rebalances() {
+        let mut vc = setup_vault_fixture();
+        vc.tc.test_env.advance_block_time_by(120);
+        vc.call(
+            other(),
+            "rebalance",
+            casper_types::runtime_args! {
+                "tick_lower" => TICK_LOWER,
+                "tick_upper" => TICK_UPPER,
+            },
+        );
+    }
+
+    // the twap guard constrains the manager, only the owner can loosen it
+    #[test]
+    #[should_panic = "User(15002)"]
+    fn test_manager_cannot_change_the_twap_guard() {
+        let mut vc = setup_vault_fixture();
+        vc.call(
+            wallet(),
+            "set_manager",
+            casper_types::runtime_args! {
+                "manager" => casper_types::Key::from(other()),
+            },
+        );
+        vc.call(
+            other(),
+            "set_max_twap_deviation",
+            casper_types::runtime_args! {
+                "max_twap_deviation" => i32::MAX,
+            },
+        );
+    }
+}
@@ -13,6 +13,8 @@ use casper_types::{
     URef, SECP256K1_TAG, U256, U512,
 };
 
+use sdk::events::{self, ContractEvent, EventDecoder, EventInstance, Schemas};
+
 use crate::gas;
 
 /// The key under which the events are stored.
@@ -220,6 +222,22 @@ impl TestEnv {
         T::from_bytes(b.as_slice()).unwrap().0
     }
 
+    // values stored as raw bytes under a named key of the account, like the sessions' results
+    pub fn get_account_result<T: FromBytes>(&self, account: AccountHash, key_name: &str) -> T {
+        let named_keys = self.get_named_keys(account);
+        let ret = named_keys.get(key_name).expect("should have result");
+        let b = self
+            .builder
+            .query(None, *ret, &[])
+            .unwrap()
+            .as_cl_value()
+            .unwrap()
+            .clone()
+            .into_t::<Bytes>()
+            .unwrap();
+        T::from_bytes(b.as_slice()).unwrap().0
+    }
+
     pub fn get_named_key_value<T: FromBytes + CLTyped>(
         &mut self,
         contract_package_hash: Key,
@@ -245,6 +263,18 @@ impl TestEnv {
         *contract_hash
     }
 
+    pub fn get_entry_point_args(&mut self, package_hash: Key, entry_point: &str) -> Vec<String> {
+        let contract_hash = self.get_active_contract_hash(package_hash);
+        let contract = self.builder.get_contract(contract_hash).unwrap();
+        contract
+            .entry_point(entry_point)
+            .expect("should have entry point")
+            .args()
+            .iter()
+            .map(|arg| arg.name().to_string())
+            .collect()
+    }
+
     pub fn approve(&mut self, token: Key, owner: AccountHash, spender: Key, amount: U256) {
         self.call_contract(
             Some(owner),
@@ -281,20 +311,57 @@ impl TestEnv {
         )
     }
 
+    pub fn allowance(&mut self, token: Key, owner: Key, spender: Key) -> U256 {
+        self.call_view_function(
+            token,
+            "allowance",
+            runtime_args! {
+                "owner" => owner,
+                "spender" => spender
+            },
+        )
+    }
+
     pub fn get_event_length(&mut self, contract_package: Key) -> u32 {
         self.get_named_key_value(contract_package, EVENTS_LENGTH)
     }
 
-    pub fn get_last_event<T: FromBytes>(&mut self, contract_package: Key) -> Option<T> {
+    pub fn get_last_event<T: EventInstance + FromBytes>(
+        &mut self,
+        contract_package: Key,
+    ) -> Option<T> {
         let events_length: u32 = self.get_event_length(contract_package);
         self.get_event(contract_package, events_length as usize - 1)
     }
 
-    pub fn get_event<T: FromBytes>(
+    // decoded against the schemas stored by the contract, panics when `T` is not the event found
+    pub fn get_event<T: EventInstance + FromBytes>(
         &mut self,
         contract_package: Key,
         event_position: usize,
     ) -> Option<T> {
+        let schemas: Schemas = self.get_named_key_value(contract_package, EVENTS_SCHEMA);
+        let bytes = self.get_event_bytes(contract_package, event_position)?;
+        Some(events::decode_event(&schemas, bytes.as_slice()).unwrap())
+    }
+
+    pub fn get_events<E: ContractEvent>(&mut self, contract_package: Key) -> Vec<E> {
+        let schemas: Schemas = self.get_named_key_value(contract_package, EVENTS_SCHEMA);
+        let events_length = self.get_event_length(contract_package) as usize;
+        let events: Vec<Bytes> = (0..events_length)
+            .map(|position| self.get_event_bytes(contract_package, position).unwrap())
+            .collect();
+        EventDecoder::<E>::with_schemas(schemas)
+            .decode_all(&events)
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    pub fn get_event_bytes(
+        &mut self,
+        contract_package: Key,
+        event_position: usize,
+    ) -> Option<Bytes> {
         let contract_hash: ContractHash = self.get_active_contract_hash(contract_package);
 
         let dictionary_seed_uref: URef = *self
@@ -312,16 +379,13 @@ impl TestEnv {
             dictionary_seed_uref,
             &event_position.to_string(),
         ) {
-            Ok(val) => {
-                let bytes = val
-                    .as_cl_value()
+            Ok(val) => Some(
+                val.as_cl_value()
                     .unwrap()
                     .clone()
                     .into_t::<Bytes>()
-                    .unwrap();
-                let value: T = T::from_bytes(bytes.as_slice()).unwrap().0;
-                Some(value)
-            }
+                    .unwrap(),
+            ),
             Err(_) => None,
         }
     }
@@ -0,0 +1,93 @@
+use alloc::{string::String, vec, vec::Vec};
+use casper_types::{
+    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter, U256,
+};
+
+fn public_entry_point(name: &str, params: Vec<Parameter>, ret: CLType) -> EntryPoint {
+    EntryPoint::new(
+        String::from(name),
+        params,
+        ret,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub(crate) fn default() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+
+    entry_points.add_entry_point(public_entry_point("init", vec![], CLType::Unit));
+    entry_points.add_entry_point(public_entry_point(
+        "add_liquidity",
+        vec![
+            Parameter::new("amount0", CLType::U256),
+            Parameter::new("amount1", CLType::U256),
+            Parameter::new("to", CLType::Key),
+        ],
+        CLType::U256,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "remove_liquidity",
+        vec![
+            Parameter::new("token_a", CLType::Key),
+            Parameter::new("token_b", CLType::Key),
+            Parameter::new("liquidity", CLType::U256),
+            Parameter::new("amount_a_min", CLType::U256),
+            Parameter::new("amount_b_min", CLType::U256),
+            Parameter::new("to", CLType::Key),
+            Parameter::new("deadline", CLType::U64),
+        ],
+        <(U256, U256)>::cl_type(),
+    ));
+    entry_points.add_entry_point(public_entry_point("token0", vec![], CLType::Key));
+    entry_points.add_entry_point(public_entry_point("token1", vec![], CLType::Key));
+    entry_points.add_entry_point(public_entry_point(
+        "get_pair",
+        vec![
+            Parameter::new("token_a", CLType::Key),
+            Parameter::new("token_b", CLType::Key),
+        ],
+        CLType::Key,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "balance_of",
+        vec![Parameter::new("address", CLType::Key)],
+        CLType::U256,
+    ));
+    entry_points.add_entry_point(public_entry_point("total_supply", vec![], CLType::U256));
+    entry_points.add_entry_point(public_entry_point(
+        "allowance",
+        vec![
+            Parameter::new("owner", CLType::Key),
+            Parameter::new("spender", CLType::Key),
+        ],
+        CLType::U256,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "approve",
+        vec![
+            Parameter::new("spender", CLType::Key),
+            Parameter::new("amount", CLType::U256),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "transfer",
+        vec![
+            Parameter::new("recipient", CLType::Key),
+            Parameter::new("amount", CLType::U256),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "transfer_from",
+        vec![
+            Parameter::new("owner", CLType::Key),
+            Parameter::new("recipient", CLType::Key),
+            Parameter::new("amount", CLType::U256),
+        ],
+        CLType::Unit,
+    ));
+
+    entry_points
+}
@@ -0,0 +1,204 @@
+use crate::store::{
+    read_allowance, read_balance, read_token0, read_token1, read_total_supply, save_allowance,
+    save_balance, save_total_supply,
+};
+use alloc::{string::ToString, vec};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{CLValue, Key, U256};
+use common::{
+    erc20_helpers,
+    error::{require, Error},
+};
+use contract_utilities::helpers::{
+    current_block_timestamp, get_immediate_caller_key, get_named_args_2, get_named_args_3,
+    get_named_args_7, get_self_key, null_key,
+};
+
+fn reserves() -> (U256, U256) {
+    (
+        erc20_helpers::get_balance(read_token0(), get_self_key()),
+        erc20_helpers::get_balance(read_token1(), get_self_key()),
+    )
+}
+
+fn move_balance(from: Key, to: Key, amount: U256) {
+    let balance = read_balance(&from);
+    require(balance >= amount, Error::ErrInsufficientBalance);
+    save_balance(&from, balance - amount);
+    save_balance(&to, read_balance(&to) + amount);
+}
+
+fn spend_allowance(owner: Key, spender: Key, amount: U256) {
+    let allowance = read_allowance(&owner, &spender);
+    require(allowance >= amount, Error::ErrInsufficientAllowance);
+    save_allowance(&owner, &spender, allowance - amount);
+}
+
+#[no_mangle]
+pub extern "C" fn add_liquidity() {
+    let (amount0, amount1, to): (U256, U256, Key) = get_named_args_3(
+        vec!["amount0", "amount1", "to"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    let (reserve0, reserve1) = reserves();
+    let total_supply = read_total_supply();
+    let liquidity = if total_supply.is_zero() {
+        (amount0 * amount1).integer_sqrt()
+    } else {
+        (amount0 * total_supply / reserve0).min(amount1 * total_supply / reserve1)
+    };
+    require(!liquidity.is_zero(), Error::ErrInvalidLiquidity);
+
+    let caller = get_immediate_caller_key();
+    erc20_helpers::transfer_from(read_token0(), caller, get_self_key(), amount0);
+    erc20_helpers::transfer_from(read_token1(), caller, get_self_key(), amount1);
+    save_total_supply(total_supply + liquidity);
+    save_balance(&to, read_balance(&to) + liquidity);
+    runtime::ret(CLValue::from_t(liquidity).unwrap_or_revert());
+}
+
+// pulls the lp tokens from the caller like a v2 router, then burns them
+#[no_mangle]
+pub extern "C" fn remove_liquidity() {
+    let (token_a, token_b, liquidity, amount_a_min, amount_b_min, to, deadline): (
+        Key,
+        Key,
+        U256,
+        U256,
+        U256,
+        Key,
+        u64,
+    ) = get_named_args_7(
+        vec![
+            "token_a",
+            "token_b",
+            "liquidity",
+            "amount_a_min",
+            "amount_b_min",
+            "to",
+            "deadline",
+        ]
+        .into_iter()
+        .map(|x| x.to_string())
+        .collect(),
+    );
+    require(
+        current_block_timestamp() <= deadline,
+        Error::ErrTransactionTooOld,
+    );
+    let (token0, token1) = (read_token0(), read_token1());
+    require(
+        (token_a == token0 && token_b == token1) || (token_a == token1 && token_b == token0),
+        Error::ErrInvalidTokenOrder,
+    );
+
+    let caller = get_immediate_caller_key();
+    let pair = get_self_key();
+    spend_allowance(caller, pair, liquidity);
+    move_balance(caller, pair, liquidity);
+
+    let (reserve0, reserve1) = reserves();
+    let total_supply = read_total_supply();
+    let amount0 = liquidity * reserve0 / total_supply;
+    let amount1 = liquidity * reserve1 / total_supply;
+    save_balance(&pair, read_balance(&pair) - liquidity);
+    save_total_supply(total_supply - liquidity);
+    erc20_helpers::transfer(token0, to, amount0);
+    erc20_helpers::transfer(token1, to, amount1);
+
+    let (amount_a, amount_b) = if token_a == token0 {
+        (amount0, amount1)
+    } else {
+        (amount1, amount0)
+    };
+    require(amount_a >= amount_a_min, Error::ErrInsufficientToken);
+    require(amount_b >= amount_b_min, Error::ErrInsufficientToken);
+    runtime::ret(CLValue::from_t((amount_a, amount_b)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn token0() {
+    runtime::ret(CLValue::from_t(read_token0()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn token1() {
+    runtime::ret(CLValue::from_t(read_token1()).unwrap_or_revert());
+}
+
+// the factory interface only knows this pair
+#[no_mangle]
+pub extern "C" fn get_pair() {
+    let (token_a, token_b): (Key, Key) = get_named_args_2(
+        vec!["token_a", "token_b"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    let (token0, token1) = (read_token0(), read_token1());
+    let pair =
+        if (token_a == token0 && token_b == token1) || (token_a == token1 && token_b == token0) {
+            get_self_key()
+        } else {
+            null_key()
+        };
+    runtime::ret(CLValue::from_t(pair).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    let address: Key = runtime::get_named_arg("address");
+    runtime::ret(CLValue::from_t(read_balance(&address)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply() {
+    runtime::ret(CLValue::from_t(read_total_supply()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn allowance() {
+    let (owner, spender): (Key, Key) = get_named_args_2(
+        vec!["owner", "spender"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    runtime::ret(CLValue::from_t(read_allowance(&owner, &spender)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let (spender, amount): (Key, U256) = get_named_args_2(
+        vec!["spender", "amount"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    save_allowance(&get_immediate_caller_key(), &spender, amount);
+}
+
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let (recipient, amount): (Key, U256) = get_named_args_2(
+        vec!["recipient", "amount"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    move_balance(get_immediate_caller_key(), recipient, amount);
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let (owner, recipient, amount): (Key, Key, U256) = get_named_args_3(
+        vec!["owner", "recipient", "amount"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    spend_allowance(owner, get_immediate_caller_key(), amount);
+    move_balance(owner, recipient, amount);
+}
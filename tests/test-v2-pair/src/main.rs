@@ -0,0 +1,55 @@
+#![no_main]
+#![no_std]
+// minimal constant product pair, also serving its own router and factory interfaces, used to
+// test migrations
+extern crate alloc;
+mod entry_points;
+
+pub mod logics;
+pub mod store;
+
+use alloc::{format, string::String};
+use casper_contract::contract_api::runtime;
+use casper_types::{contracts::NamedKeys, runtime_args, Key, RuntimeArgs};
+use common::error::Error;
+use common::upgrade;
+use contract_utilities::helpers;
+
+#[no_mangle]
+pub extern "C" fn init() {
+    if helpers::get_key::<Key>("contract_hash").is_some() {
+        runtime::revert(Error::ContractAlreadyInitialized);
+    }
+
+    let contract_hash: Key = runtime::get_named_arg("contract_hash");
+    let contract_package_hash: Key = runtime::get_named_arg("contract_package_hash");
+    let token0: Key = runtime::get_named_arg("token0");
+    let token1: Key = runtime::get_named_arg("token1");
+    helpers::set_key("contract_hash", contract_hash);
+    helpers::set_key("contract_package_hash", contract_package_hash);
+    store::initialize(token0, token1);
+}
+
+#[no_mangle]
+fn call() {
+    let contract_name: String = runtime::get_named_arg("contract_name");
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        let token0: Key = runtime::get_named_arg("token0");
+        let token1: Key = runtime::get_named_arg("token1");
+        let (contract_hash, contract_package_hash) =
+            upgrade::install_contract(contract_name, entry_points::default(), NamedKeys::new());
+
+        runtime::call_contract::<()>(
+            contract_hash,
+            "init",
+            runtime_args! {
+                "contract_hash" => Key::from(contract_hash),
+                "contract_package_hash" => Key::from(contract_package_hash),
+                "token0" => token0,
+                "token1" => token1,
+            },
+        );
+    } else {
+        upgrade::upgrade_contract(contract_name, entry_points::default(), NamedKeys::new());
+    }
+}
@@ -0,0 +1,60 @@
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{Key, U256};
+use common::error::Error;
+use contract_utilities::helpers::{self, null_key};
+
+pub fn initialize(token0: Key, token1: Key) {
+    storage::new_dictionary("balances").unwrap_or_revert_with(Error::FailedToCreateDictionary);
+    storage::new_dictionary("allowances").unwrap_or_revert_with(Error::FailedToCreateDictionary);
+    helpers::set_key("token0", token0);
+    helpers::set_key("token1", token1);
+    save_total_supply(U256::zero());
+}
+
+pub fn read_token0() -> Key {
+    helpers::get_key("token0").unwrap_or(null_key())
+}
+
+pub fn read_token1() -> Key {
+    helpers::get_key("token1").unwrap_or(null_key())
+}
+
+pub fn save_total_supply(total_supply: U256) {
+    helpers::set_key("total_supply", total_supply);
+}
+
+pub fn read_total_supply() -> U256 {
+    helpers::get_key("total_supply").unwrap_or_default()
+}
+
+pub fn save_balance(owner: &Key, balance: U256) {
+    helpers::write_dictionary_value_from_key(
+        "balances",
+        &helpers::encode_key(&helpers::encode_1(owner)),
+        balance,
+    );
+}
+
+pub fn read_balance(owner: &Key) -> U256 {
+    helpers::get_dictionary_value_from_key(
+        "balances",
+        &helpers::encode_key(&helpers::encode_1(owner)),
+    )
+    .unwrap_or_default()
+}
+
+pub fn save_allowance(owner: &Key, spender: &Key, amount: U256) {
+    helpers::write_dictionary_value_from_key(
+        "allowances",
+        &helpers::encode_key(&helpers::encode_2(owner, spender)),
+        amount,
+    );
+}
+
+pub fn read_allowance(owner: &Key, spender: &Key) -> U256 {
+    helpers::get_dictionary_value_from_key(
+        "allowances",
+        &helpers::encode_key(&helpers::encode_2(owner, spender)),
+    )
+    .unwrap_or_default()
+}
@@ -1,52 +1,69 @@
-use alloc::{string::ToString, vec::Vec};
+use alloc::vec::Vec;
 use casper_types::{
-    bytesrepr::{FromBytes, ToBytes},
-    CLTyped, U128,
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, U128,
 };
-use core::ops;
+use core::{convert::TryFrom, ops};
 
+pub const I128_SERIALIZED_LENGTH: usize = 16;
+
+// 16 bytes of little endian two's complement
 #[derive(Debug, Clone, Copy, Default)]
 pub struct I128(pub i128);
 
 impl FromBytes for I128 {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
-        let (signed, bytes) = u8::from_bytes(bytes)?;
-        let (val, bytes) = U128::from_bytes(bytes)?;
-        let val = val.as_u128();
-        let val = if signed == 0 {
-            -(val as i128)
-        } else {
-            val as i128
-        };
-        Ok((I128(val), bytes))
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (le_bytes, bytes) = <[u8; I128_SERIALIZED_LENGTH]>::from_bytes(bytes)?;
+        Ok((I128(i128::from_le_bytes(le_bytes)), bytes))
     }
 }
 
 impl ToBytes for I128 {
-    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
-        let signed: u8 = if self.0 < 0 { 0 } else { 1 };
-        let val = self.0.unsigned_abs();
-        let mut vec = Vec::with_capacity(self.serialized_length());
-        vec.append(&mut signed.to_bytes()?);
-        vec.append(&mut U128::from(val).to_bytes()?);
-        Ok(vec)
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        Ok(self.0.to_le_bytes().to_vec())
     }
 
     fn serialized_length(&self) -> usize {
-        let val = self.0.unsigned_abs();
-        1 + U128::from(val).serialized_length()
+        I128_SERIALIZED_LENGTH
+    }
+}
+
+// rendered as a decimal string like the casper big numbers
+#[cfg(feature = "serde")]
+impl serde::Serialize for I128 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for I128 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = alloc::string::String::deserialize(deserializer)?;
+        value.parse().map(I128).map_err(serde::de::Error::custom)
     }
 }
 
 impl CLTyped for I128 {
-    fn cl_type() -> casper_types::CLType {
-        casper_types::CLType::Any
+    fn cl_type() -> CLType {
+        CLType::ByteArray(I128_SERIALIZED_LENGTH as u32)
+    }
+}
+
+impl I128 {
+    // values written before the fixed width encoding: a sign byte (0 when negative) and a U128
+    pub fn from_legacy_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (signed, bytes) = u8::from_bytes(bytes)?;
+        let (val, bytes) = U128::from_bytes(bytes)?;
+        let val = val.as_u128() as i128;
+        let val = if signed == 0 { val.wrapping_neg() } else { val };
+        Ok((I128(val), bytes))
     }
 }
 
 impl From<u128> for I128 {
     fn from(v: u128) -> Self {
-        I128(v.to_string().parse().unwrap())
+        I128(i128::try_from(v).unwrap())
     }
 }
 
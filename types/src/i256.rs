@@ -1,21 +1,21 @@
-use crate::i128::{self as i128p, I128};
-use alloc::{string::ToString, vec, vec::Vec};
+use crate::i128::I128;
+use alloc::vec::Vec;
 use casper_types::{
-    bytesrepr::{FromBytes, ToBytes},
-    CLTyped, U256,
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, U256,
 };
 use core::ops;
 
+pub const I256_SERIALIZED_LENGTH: usize = 32;
+
+// 32 bytes of little endian two's complement
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct I256(pub ethnum::i256);
 
 impl FromBytes for I256 {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
-        let (words, bytes) = Vec::<I128>::from_bytes(bytes)?;
-        let word0 = words[0].0;
-        let word1 = words[1].0;
-        let v: ethnum::prelude::I256 = ethnum::i256::from_words(word0, word1);
-        Ok((I256(v), bytes))
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (le_bytes, bytes) = <[u8; I256_SERIALIZED_LENGTH]>::from_bytes(bytes)?;
+        Ok((I256(ethnum::i256::from_le_bytes(le_bytes)), bytes))
     }
 }
 
@@ -26,33 +26,67 @@ impl Default for I256 {
 }
 
 impl ToBytes for I256 {
-    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
-        let (word0, word1) = self.0.into_words();
-        let word0 = i128p::I128(word0);
-        let word1 = i128p::I128(word1);
-        vec![word0, word1].to_bytes()
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        Ok(self.0.to_le_bytes().to_vec())
     }
 
     fn serialized_length(&self) -> usize {
-        self.0.to_le_bytes().len()
+        I256_SERIALIZED_LENGTH
+    }
+}
+
+// rendered as a decimal string like the casper big numbers
+#[cfg(feature = "serde")]
+impl serde::Serialize for I256 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for I256 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = alloc::string::String::deserialize(deserializer)?;
+        ethnum::i256::from_str_radix(&value, 10)
+            .map(I256)
+            .map_err(serde::de::Error::custom)
     }
 }
 
 impl CLTyped for I256 {
-    fn cl_type() -> casper_types::CLType {
-        casper_types::CLType::Any
+    fn cl_type() -> CLType {
+        CLType::ByteArray(I256_SERIALIZED_LENGTH as u32)
+    }
+}
+
+impl I256 {
+    // values written before the fixed width encoding: a list of the two legacy I128 words
+    pub fn from_legacy_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (words, bytes) = u32::from_bytes(bytes)?;
+        if words != 2 {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        let (high, bytes) = I128::from_legacy_bytes(bytes)?;
+        let (low, bytes) = I128::from_legacy_bytes(bytes)?;
+        Ok((I256(ethnum::i256::from_words(high.0, low.0)), bytes))
     }
 }
 
+// both conversions panic when the value does not fit, like the decimal parsing they replace
 impl From<U256> for I256 {
     fn from(v: U256) -> Self {
-        I256(ethnum::i256::from_str_radix(&v.to_string(), 10).unwrap())
+        let mut le_bytes = [0u8; I256_SERIALIZED_LENGTH];
+        v.to_little_endian(&mut le_bytes);
+        let v = ethnum::i256::from_le_bytes(le_bytes);
+        assert!(!v.is_negative(), "U256 does not fit in I256");
+        I256(v)
     }
 }
 
 impl From<I256> for U256 {
     fn from(v: I256) -> Self {
-        U256::from_dec_str(&v.0.to_string()).unwrap()
+        assert!(!v.0.is_negative(), "negative I256 does not fit in U256");
+        U256::from_little_endian(&v.0.to_le_bytes())
     }
 }
 
@@ -3,13 +3,18 @@
 extern crate alloc;
 pub mod i128;
 pub mod i256;
+pub mod session;
 use crate::i128::I128;
 use crate::i256::I256;
 use alloc::vec::Vec;
-use casper_types::{bytesrepr::Bytes, Key, U128, U256};
+use casper_types::{
+    bytesrepr::{self, Bytes, FromBytes},
+    CLType, Key, U128, U256,
+};
 use casper_types_derive::{CLTyped, FromBytes, ToBytes};
 use contract_utilities::helpers::null_key;
 #[derive(Clone, CLTyped, ToBytes, FromBytes, Default, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Observation {
     pub block_timestamp: u64,
     pub tick_cumulative: i64,
@@ -18,6 +23,7 @@ pub struct Observation {
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositionInfo {
     pub liquidity: U128,
     pub fee_growth_inside0_last_x128: U256,
@@ -27,6 +33,7 @@ pub struct PositionInfo {
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TickInfo {
     pub liquidity_gross: U128,
     pub liquidity_net: I128,
@@ -38,7 +45,52 @@ pub struct TickInfo {
     pub initialized: bool,
 }
 
+impl TickInfo {
+    // ticks saved before `I128` switched to its fixed width encoding
+    pub fn from_legacy_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (liquidity_gross, bytes) = U128::from_bytes(bytes)?;
+        let (liquidity_net, bytes) = I128::from_legacy_bytes(bytes)?;
+        let (fee_growth_outside0_x128, bytes) = U256::from_bytes(bytes)?;
+        let (fee_growth_outside1_x128, bytes) = U256::from_bytes(bytes)?;
+        let (tick_cumulative_outside, bytes) = i64::from_bytes(bytes)?;
+        let (seconds_per_liquidity_outside_x128, bytes) = U256::from_bytes(bytes)?;
+        let (seconds_outside, bytes) = u32::from_bytes(bytes)?;
+        let (initialized, bytes) = bool::from_bytes(bytes)?;
+        Ok((
+            TickInfo {
+                liquidity_gross,
+                liquidity_net,
+                fee_growth_outside0_x128,
+                fee_growth_outside1_x128,
+                tick_cumulative_outside,
+                seconds_per_liquidity_outside_x128,
+                seconds_outside,
+                initialized,
+            },
+            bytes,
+        ))
+    }
+}
+
+// reads a legacy tick out of a pool's `ticks` dictionary, both share the `Any` CLType
+#[derive(Clone)]
+pub struct LegacyTickInfo(pub TickInfo);
+
+impl FromBytes for LegacyTickInfo {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tick, bytes) = TickInfo::from_legacy_bytes(bytes)?;
+        Ok((LegacyTickInfo(tick), bytes))
+    }
+}
+
+impl casper_types::CLTyped for LegacyTickInfo {
+    fn cl_type() -> CLType {
+        <TickInfo as casper_types::CLTyped>::cl_type()
+    }
+}
+
 #[derive(Clone, CLTyped, ToBytes, FromBytes, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Slot0 {
     pub sqrt_price_x96: U256,
     pub tick: i32,
@@ -49,6 +101,7 @@ pub struct Slot0 {
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProtocolFees {
     pub token0: U128,
     pub token1: U128,
@@ -95,6 +148,7 @@ pub struct StepComputations {
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Position {
     // the ID of the pool with which this token is connected
     pub pool_id: u64,
@@ -130,6 +184,7 @@ impl Default for Position {
 
 /// @notice The identifying key of the pool
 #[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PoolKey {
     // the ID of the pool with which this token is connected
     pub token0: Key,
@@ -148,6 +203,7 @@ impl Default for PoolKey {
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MintParams {
     // the ID of the pool with which this token is connected
     pub token0: Key,
@@ -164,6 +220,7 @@ pub struct MintParams {
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MintResult {
     pub token_id: U256,
     pub liquidity: U128,
@@ -172,6 +229,7 @@ pub struct MintResult {
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddLiquidityParams {
     // the ID of the pool with which this token is connected
     pub token0: Key,
@@ -187,12 +245,14 @@ pub struct AddLiquidityParams {
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MintCallbackData {
     pub pool_key: PoolKey,
     pub payer: Key,
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IncreaseLiquidityParams {
     // the ID of the pool with which this token is connected
     pub token_id: U256,
@@ -206,6 +266,7 @@ pub struct IncreaseLiquidityParams {
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DecreaseLiquidityParams {
     // the ID of the pool with which this token is connected
     pub token_id: U256,
@@ -216,6 +277,7 @@ pub struct DecreaseLiquidityParams {
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CollectParams {
     // the ID of the pool with which this token is connected
     pub token_id: U256,
@@ -225,12 +287,14 @@ pub struct CollectParams {
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SwapCallbackData {
     pub path: Bytes,
     pub payer: Key,
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExactOutputSingleParams {
     pub token_in: Key,
     pub token_out: Key,
@@ -240,18 +304,25 @@ pub struct ExactOutputSingleParams {
     pub amount_out: U256,
     pub amount_in_maximum: U256,
     pub sqrt_price_limit_x96: U256,
+    // optional partner fee, disabled when the recipient is null or the bips are zero
+    pub fee_recipient: Key,
+    pub fee_bips: u32,
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExactOutputParams {
     pub path: Bytes,
     pub recipient: Key,
     pub deadline: u64,
     pub amount_out: U256,
     pub amount_in_maximum: U256,
+    pub fee_recipient: Key,
+    pub fee_bips: u32,
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExactInputSingleParams {
     pub token_in: Key,
     pub token_out: Key,
@@ -261,18 +332,24 @@ pub struct ExactInputSingleParams {
     pub amount_in: U256,
     pub amount_out_minimum: U256,
     pub sqrt_price_limit_x96: U256,
+    pub fee_recipient: Key,
+    pub fee_bips: u32,
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExactInputParams {
     pub path: Bytes,
     pub recipient: Key,
     pub deadline: u64,
     pub amount_in: U256,
     pub amount_out_minimum: U256,
+    pub fee_recipient: Key,
+    pub fee_bips: u32,
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IncreaseLiquidityResult {
     pub liquidity: U128,
     pub amount0: U256,
@@ -280,15 +357,358 @@ pub struct IncreaseLiquidityResult {
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DecreaseLiquidityResult {
     pub amount0: U256,
     pub amount1: U256,
 }
 
 #[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CollectResult {
     pub token0: Key,
     pub token1: Key,
     pub amount0: U256,
     pub amount1: U256,
 }
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RebalanceParams {
+    pub token_id: U256,
+    // bounds on what the old range pays out
+    pub decrease_amount0_min: U256,
+    pub decrease_amount1_min: U256,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    // swap executed between the collect and the mint, skipped when amount_in is zero
+    pub zero_for_one: bool,
+    pub amount_in: U256,
+    pub amount_out_minimum: U256,
+    pub sqrt_price_limit_x96: U256,
+    pub amount0_min: U256,
+    pub amount1_min: U256,
+    // keep the same NFT, otherwise burn it and mint a new one to the recipient
+    pub reuse_token_id: bool,
+    pub recipient: Key,
+    pub deadline: u64,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZapMintParams {
+    pub token_in: Key,
+    pub token0: Key,
+    pub token1: Key,
+    pub fee: u32,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub amount_in: U256,
+    pub amount_out_minimum: U256,
+    pub amount0_min: U256,
+    pub amount1_min: U256,
+    pub recipient: Key,
+    pub deadline: u64,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExitPositionParams {
+    pub token_id: U256,
+    pub amount0_min: U256,
+    pub amount1_min: U256,
+    pub recipient: Key,
+    pub unwrap_cspr: bool,
+    pub deadline: u64,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExactInputLeg {
+    pub path: Bytes,
+    pub amount_in: U256,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExactInputSplitParams {
+    pub legs: Vec<ExactInputLeg>,
+    pub recipient: Key,
+    pub deadline: u64,
+    pub amount_out_minimum: U256,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExactOutputLeg {
+    pub path: Bytes,
+    pub amount_out: U256,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExactOutputSplitParams {
+    pub legs: Vec<ExactOutputLeg>,
+    pub recipient: Key,
+    pub deadline: u64,
+    pub amount_in_maximum: U256,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IncentiveKey {
+    pub reward_token: Key,
+    pub pool: Key,
+    pub start_time: u64,
+    pub end_time: u64,
+    // receives the unclaimed rewards once the incentive ended
+    pub refundee: Key,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Incentive {
+    pub total_reward_unclaimed: U256,
+    pub total_seconds_claimed_x128: U256,
+    pub number_of_stakes: u64,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Deposit {
+    pub owner: Key,
+    pub number_of_stakes: u64,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+}
+
+impl Default for Deposit {
+    fn default() -> Self {
+        Self {
+            owner: null_key(),
+            number_of_stakes: 0,
+            tick_lower: 0,
+            tick_upper: 0,
+        }
+    }
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stake {
+    pub seconds_per_liquidity_inside_initial_x128: U256,
+    pub liquidity: U128,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TriggerParams {
+    pub token_id: U256,
+    pub trigger_tick: i32,
+    // stop loss when true, take profit otherwise
+    pub trigger_below: bool,
+    pub twap_duration: u32,
+    // token0 or token1 to swap all proceeds into, null key to keep both
+    pub swap_to: Key,
+    pub max_slippage_bips: u32,
+    pub keeper_fee_bips: u32,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trigger {
+    pub owner: Key,
+    pub pool: Key,
+    pub token0: Key,
+    pub token1: Key,
+    pub fee: u32,
+    pub trigger_tick: i32,
+    pub trigger_below: bool,
+    pub twap_duration: u32,
+    pub swap_to: Key,
+    pub max_slippage_bips: u32,
+    pub keeper_fee_bips: u32,
+}
+
+impl Default for Trigger {
+    fn default() -> Self {
+        Self {
+            owner: null_key(),
+            pool: null_key(),
+            token0: null_key(),
+            token1: null_key(),
+            fee: 0,
+            trigger_tick: 0,
+            trigger_below: false,
+            twap_duration: 0,
+            swap_to: null_key(),
+            max_slippage_bips: 0,
+            keeper_fee_bips: 0,
+        }
+    }
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MigrateParams {
+    // constant product lp token, burned by the v2 router the migrator was installed with
+    pub pair: Key,
+    pub liquidity_to_migrate: U256,
+    // bounds on what burning the lp tokens pays out
+    pub amount0_v2_min: U256,
+    pub amount1_v2_min: U256,
+    // out of 100, the rest of the withdrawn amounts is refunded
+    pub percentage_to_migrate: u8,
+    pub token0: Key,
+    pub token1: Key,
+    pub fee: u32,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    // initial price of the pool if it does not exist yet
+    pub sqrt_price_x96: U256,
+    pub amount0_min: U256,
+    pub amount1_min: U256,
+    pub recipient: Key,
+    pub deadline: u64,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lock {
+    pub owner: Key,
+    // collects the fees and receives the position once unlocked
+    pub beneficiary: Key,
+    pub start_time: u64,
+    pub unlock_time: u64,
+    // liquidity vests linearly between start_time and unlock_time when set
+    pub vesting: bool,
+    pub initial_liquidity: U128,
+    pub withdrawn_liquidity: U128,
+}
+
+impl Default for Lock {
+    fn default() -> Self {
+        Self {
+            owner: null_key(),
+            beneficiary: null_key(),
+            start_time: 0,
+            unlock_time: 0,
+            vesting: false,
+            initial_liquidity: U128::zero(),
+            withdrawn_liquidity: U128::zero(),
+        }
+    }
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RangePosition {
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub liquidity: U128,
+    pub fee_growth_inside0_last_x128: U256,
+    pub fee_growth_inside1_last_x128: U256,
+    pub tokens_owed0: U128,
+    pub tokens_owed1: U128,
+}
+
+// several ranges of a single pool held under one token id
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompositePosition {
+    pub pool_id: u64,
+    pub token0: Key,
+    pub token1: Key,
+    pub fee: u32,
+    pub ranges: Vec<RangePosition>,
+}
+
+impl Default for CompositePosition {
+    fn default() -> Self {
+        Self {
+            pool_id: 0,
+            token0: null_key(),
+            token1: null_key(),
+            fee: 0,
+            ranges: Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompositeRangeParams {
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub amount0_desired: U256,
+    pub amount1_desired: U256,
+    pub amount0_min: U256,
+    pub amount1_min: U256,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompositeMintParams {
+    pub token0: Key,
+    pub token1: Key,
+    pub fee: u32,
+    pub ranges: Vec<CompositeRangeParams>,
+    pub recipient: Key,
+    pub deadline: u64,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompositeMintResult {
+    pub token_id: U256,
+    pub liquidities: Vec<U128>,
+    pub amount0: U256,
+    pub amount1: U256,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompositeAmounts {
+    pub amount0_desired: U256,
+    pub amount1_desired: U256,
+    pub amount0_min: U256,
+    pub amount1_min: U256,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompositeIncreaseLiquidityParams {
+    pub token_id: U256,
+    // one entry per range, ranges with nothing desired are skipped
+    pub amounts: Vec<CompositeAmounts>,
+    pub deadline: u64,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompositeIncreaseLiquidityResult {
+    pub liquidities: Vec<U128>,
+    pub amount0: U256,
+    pub amount1: U256,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompositeDecreaseLiquidityParams {
+    pub token_id: U256,
+    // one entry per range, zero leaves the range untouched
+    pub liquidities: Vec<U128>,
+    pub amount0_min: U256,
+    pub amount1_min: U256,
+    pub deadline: u64,
+}
+
+#[derive(Clone, CLTyped, ToBytes, FromBytes, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompositeTotals {
+    pub liquidity: U128,
+    pub amount0: U256,
+    pub amount1: U256,
+    pub tokens_owed0: U128,
+    pub tokens_owed1: U128,
+}
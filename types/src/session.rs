@@ -0,0 +1,326 @@
+use crate::{
+    CollectParams, CompositeDecreaseLiquidityParams, CompositeIncreaseLiquidityParams,
+    CompositeMintParams, DecreaseLiquidityParams, ExactInputParams, ExactInputSingleParams,
+    ExactInputSplitParams, ExactOutputParams, ExactOutputSingleParams, ExactOutputSplitParams,
+    ExitPositionParams, IncreaseLiquidityParams, MintParams, RebalanceParams, ZapMintParams,
+};
+use alloc::vec::Vec;
+use casper_types::{
+    bytesrepr::{Error, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
+    Key, U128, U256,
+};
+use casper_types_derive::{CLTyped, FromBytes, ToBytes};
+
+// bumped whenever the encoding of an existing command changes
+pub const SESSION_COMMAND_VERSION: u8 = 1;
+
+// declares the commands together with their tag, the encoding is the tag followed by the fields in order
+macro_rules! session_commands {
+    ($($tag: literal => $variant: ident { $($field: ident: $t: ty),* $(,)? }),* $(,)?) => {
+        #[derive(Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[allow(clippy::large_enum_variant)]
+        pub enum SessionCommand {
+            $($variant { $($field: $t),* }),*
+        }
+
+        impl SessionCommand {
+            pub fn tag(&self) -> u8 {
+                match self {
+                    $(SessionCommand::$variant { .. } => $tag,)*
+                }
+            }
+        }
+
+        impl ToBytes for SessionCommand {
+            fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+                let mut bytes = Vec::with_capacity(self.serialized_length());
+                match self {
+                    $(SessionCommand::$variant { $($field),* } => {
+                        bytes.push($tag);
+                        $(bytes.append(&mut $field.to_bytes()?);)*
+                    })*
+                }
+                Ok(bytes)
+            }
+
+            fn serialized_length(&self) -> usize {
+                U8_SERIALIZED_LENGTH
+                    + match self {
+                        $(SessionCommand::$variant { $($field),* } => {
+                            0 $(+ $field.serialized_length())*
+                        })*
+                    }
+            }
+        }
+
+        impl FromBytes for SessionCommand {
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+                let (tag, remainder) = u8::from_bytes(bytes)?;
+                match tag {
+                    $($tag => {
+                        $(let ($field, remainder) = <$t>::from_bytes(remainder)?;)*
+                        Ok((SessionCommand::$variant { $($field),* }, remainder))
+                    })*
+                    _ => Err(Error::Formatting),
+                }
+            }
+        }
+    };
+}
+
+session_commands! {
+    0 => CreateAndInitializePoolIfNecessary {
+        token0: Key,
+        token1: Key,
+        fee: u32,
+        sqrt_price_x96: U256,
+    },
+    1 => Mint { params: MintParams, wrap_cspr: bool },
+    2 => IncreaseLiquidity { params: IncreaseLiquidityParams, wrap_cspr: bool },
+    3 => DecreaseLiquidity { params: DecreaseLiquidityParams },
+    4 => Collect { params: CollectParams, unwrap_cspr: bool },
+    5 => Burn { token_id: U256 },
+    6 => Rebalance { params: RebalanceParams },
+    7 => ZapMint { params: ZapMintParams },
+    8 => CompositeMint { params: CompositeMintParams },
+    9 => CompositeIncreaseLiquidity { params: CompositeIncreaseLiquidityParams },
+    10 => CompositeDecreaseLiquidity { params: CompositeDecreaseLiquidityParams },
+    11 => CompositeCollect { params: CollectParams },
+    12 => Split { token_id: U256, liquidity: U128 },
+    13 => Merge { token_ids: Vec<U256> },
+    14 => ExitPosition { params: ExitPositionParams },
+    15 => ExactInputSingle { params: ExactInputSingleParams, wrap_cspr: bool },
+    16 => ExactInput { params: ExactInputParams, wrap_cspr: bool },
+    17 => ExactOutputSingle { params: ExactOutputSingleParams, wrap_cspr: bool },
+    18 => ExactOutput { params: ExactOutputParams, wrap_cspr: bool },
+    19 => ExactInputSplit { params: ExactInputSplitParams, wrap_cspr: bool },
+    20 => ExactOutputSplit { params: ExactOutputSplitParams, wrap_cspr: bool },
+    21 => SweepToken { token: Key, amount_minimum: U256, recipient: Key },
+    22 => RefundCspr {},
+    23 => UnwrapCspr { amount: U256, recipient: Key },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SessionCommandError {
+    UnsupportedVersion(u8),
+    InvalidCommand,
+}
+
+impl SessionCommand {
+    // the version byte followed by the command, one entry of the sessions' `datas` argument
+    pub fn to_versioned_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(U8_SERIALIZED_LENGTH + self.serialized_length());
+        bytes.push(SESSION_COMMAND_VERSION);
+        bytes.append(&mut self.to_bytes().unwrap());
+        bytes
+    }
+
+    pub fn from_versioned_bytes(bytes: &[u8]) -> Result<Self, SessionCommandError> {
+        let (version, remainder) =
+            u8::from_bytes(bytes).map_err(|_| SessionCommandError::InvalidCommand)?;
+        if version != SESSION_COMMAND_VERSION {
+            return Err(SessionCommandError::UnsupportedVersion(version));
+        }
+        match SessionCommand::from_bytes(remainder) {
+            Ok((command, remainder)) if remainder.is_empty() => Ok(command),
+            _ => Err(SessionCommandError::InvalidCommand),
+        }
+    }
+}
+
+// outcome of one command, fields the command does not produce are left at zero
+#[derive(Clone, CLTyped, ToBytes, FromBytes, Default, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SessionStepResult {
+    pub command: u8,
+    pub token_id: U256,
+    pub liquidity: U128,
+    // token0 and token1 amounts, swaps report the amount in and the amount out
+    pub amount0: U256,
+    pub amount1: U256,
+}
+
+impl SessionStepResult {
+    pub fn new(command: u8) -> Self {
+        Self {
+            command,
+            ..Default::default()
+        }
+    }
+}
+
+// stored under the `session_result` named key of the account running a session
+#[derive(Clone, CLTyped, ToBytes, FromBytes, Default, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SessionResult {
+    pub steps: Vec<SessionStepResult>,
+}
+
+#[cfg(feature = "std")]
+pub use builder::SessionBuilder;
+
+#[cfg(feature = "std")]
+pub mod builder {
+    use super::SessionCommand;
+    use crate::{
+        CollectParams, CompositeDecreaseLiquidityParams, CompositeIncreaseLiquidityParams,
+        CompositeMintParams, DecreaseLiquidityParams, ExactInputParams, ExactInputSingleParams,
+        ExactInputSplitParams, ExactOutputParams, ExactOutputSingleParams, ExactOutputSplitParams,
+        ExitPositionParams, IncreaseLiquidityParams, MintParams, RebalanceParams, ZapMintParams,
+    };
+    use alloc::vec::Vec;
+    use casper_types::{bytesrepr::Bytes, Key, U128, U256};
+
+    // collects the commands of a session and produces its `datas` argument
+    #[derive(Clone, Default)]
+    pub struct SessionBuilder {
+        commands: Vec<SessionCommand>,
+    }
+
+    impl SessionBuilder {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        pub fn command(mut self, command: SessionCommand) -> Self {
+            self.commands.push(command);
+            self
+        }
+
+        pub fn create_and_initialize_pool_if_necessary(
+            self,
+            token0: Key,
+            token1: Key,
+            fee: u32,
+            sqrt_price_x96: U256,
+        ) -> Self {
+            self.command(SessionCommand::CreateAndInitializePoolIfNecessary {
+                token0,
+                token1,
+                fee,
+                sqrt_price_x96,
+            })
+        }
+
+        pub fn mint(self, params: MintParams, wrap_cspr: bool) -> Self {
+            self.command(SessionCommand::Mint { params, wrap_cspr })
+        }
+
+        pub fn increase_liquidity(self, params: IncreaseLiquidityParams, wrap_cspr: bool) -> Self {
+            self.command(SessionCommand::IncreaseLiquidity { params, wrap_cspr })
+        }
+
+        pub fn decrease_liquidity(self, params: DecreaseLiquidityParams) -> Self {
+            self.command(SessionCommand::DecreaseLiquidity { params })
+        }
+
+        pub fn collect(self, params: CollectParams, unwrap_cspr: bool) -> Self {
+            self.command(SessionCommand::Collect {
+                params,
+                unwrap_cspr,
+            })
+        }
+
+        pub fn burn(self, token_id: U256) -> Self {
+            self.command(SessionCommand::Burn { token_id })
+        }
+
+        pub fn rebalance(self, params: RebalanceParams) -> Self {
+            self.command(SessionCommand::Rebalance { params })
+        }
+
+        pub fn zap_mint(self, params: ZapMintParams) -> Self {
+            self.command(SessionCommand::ZapMint { params })
+        }
+
+        pub fn composite_mint(self, params: CompositeMintParams) -> Self {
+            self.command(SessionCommand::CompositeMint { params })
+        }
+
+        pub fn composite_increase_liquidity(
+            self,
+            params: CompositeIncreaseLiquidityParams,
+        ) -> Self {
+            self.command(SessionCommand::CompositeIncreaseLiquidity { params })
+        }
+
+        pub fn composite_decrease_liquidity(
+            self,
+            params: CompositeDecreaseLiquidityParams,
+        ) -> Self {
+            self.command(SessionCommand::CompositeDecreaseLiquidity { params })
+        }
+
+        pub fn composite_collect(self, params: CollectParams) -> Self {
+            self.command(SessionCommand::CompositeCollect { params })
+        }
+
+        pub fn split(self, token_id: U256, liquidity: U128) -> Self {
+            self.command(SessionCommand::Split {
+                token_id,
+                liquidity,
+            })
+        }
+
+        pub fn merge(self, token_ids: Vec<U256>) -> Self {
+            self.command(SessionCommand::Merge { token_ids })
+        }
+
+        pub fn exit_position(self, params: ExitPositionParams) -> Self {
+            self.command(SessionCommand::ExitPosition { params })
+        }
+
+        pub fn exact_input_single(self, params: ExactInputSingleParams, wrap_cspr: bool) -> Self {
+            self.command(SessionCommand::ExactInputSingle { params, wrap_cspr })
+        }
+
+        pub fn exact_input(self, params: ExactInputParams, wrap_cspr: bool) -> Self {
+            self.command(SessionCommand::ExactInput { params, wrap_cspr })
+        }
+
+        pub fn exact_output_single(self, params: ExactOutputSingleParams, wrap_cspr: bool) -> Self {
+            self.command(SessionCommand::ExactOutputSingle { params, wrap_cspr })
+        }
+
+        pub fn exact_output(self, params: ExactOutputParams, wrap_cspr: bool) -> Self {
+            self.command(SessionCommand::ExactOutput { params, wrap_cspr })
+        }
+
+        pub fn exact_input_split(self, params: ExactInputSplitParams, wrap_cspr: bool) -> Self {
+            self.command(SessionCommand::ExactInputSplit { params, wrap_cspr })
+        }
+
+        pub fn exact_output_split(self, params: ExactOutputSplitParams, wrap_cspr: bool) -> Self {
+            self.command(SessionCommand::ExactOutputSplit { params, wrap_cspr })
+        }
+
+        pub fn sweep_token(self, token: Key, amount_minimum: U256, recipient: Key) -> Self {
+            self.command(SessionCommand::SweepToken {
+                token,
+                amount_minimum,
+                recipient,
+            })
+        }
+
+        pub fn refund_cspr(self) -> Self {
+            self.command(SessionCommand::RefundCspr {})
+        }
+
+        pub fn unwrap_cspr(self, amount: U256, recipient: Key) -> Self {
+            self.command(SessionCommand::UnwrapCspr { amount, recipient })
+        }
+
+        pub fn commands(&self) -> &[SessionCommand] {
+            &self.commands
+        }
+
+        // the `datas` runtime argument of the swap and liquidity sessions
+        pub fn build(&self) -> Vec<Bytes> {
+            self.commands
+                .iter()
+                .map(|command| Bytes::from(command.to_versioned_bytes()))
+                .collect()
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::store::{
+    read_allowance, read_balance, read_total_supply, save_allowance, save_balance,
+    save_total_supply,
+};
+use alloc::{string::ToString, vec};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{CLValue, Key, U256};
+use common::{
+    error::{require, Error},
+    vault_events,
+};
+use contract_utilities::helpers::{get_immediate_caller_key, get_named_args_2, get_named_args_3};
+
+pub fn mint_shares(recipient: Key, amount: U256) {
+    save_balance(&recipient, &(read_balance(&recipient) + amount));
+    save_total_supply(read_total_supply() + amount);
+    casper_event_standard::emit(vault_events::Mint::new(recipient, amount));
+}
+
+pub fn burn_shares(owner: Key, amount: U256) {
+    let balance = read_balance(&owner);
+    require(balance >= amount, Error::ErrInsufficientBalance);
+    save_balance(&owner, &(balance - amount));
+    save_total_supply(read_total_supply() - amount);
+    casper_event_standard::emit(vault_events::Burn::new(owner, amount));
+}
+
+fn transfer_balance(sender: Key, recipient: Key, amount: U256) {
+    let balance = read_balance(&sender);
+    require(balance >= amount, Error::ErrInsufficientBalance);
+    save_balance(&sender, &(balance - amount));
+    save_balance(&recipient, &(read_balance(&recipient) + amount));
+}
+
+#[no_mangle]
+pub extern "C" fn allowance() {
+    let (owner, spender): (Key, Key) = get_named_args_2(
+        vec!["owner", "spender"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    runtime::ret(CLValue::from_t(read_allowance(&owner, &spender)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let (spender, amount): (Key, U256) = get_named_args_2(
+        vec!["spender", "amount"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    let owner = get_immediate_caller_key();
+    save_allowance(&owner, &spender, amount);
+    casper_event_standard::emit(vault_events::SetAllowance::new(owner, spender, amount));
+}
+
+#[no_mangle]
+pub extern "C" fn increase_allowance() {
+    let (spender, amount): (Key, U256) = get_named_args_2(
+        vec!["spender", "amount"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    let owner = get_immediate_caller_key();
+    let allowance = read_allowance(&owner, &spender).saturating_add(amount);
+    save_allowance(&owner, &spender, allowance);
+    casper_event_standard::emit(vault_events::SetAllowance::new(owner, spender, allowance));
+}
+
+#[no_mangle]
+pub extern "C" fn decrease_allowance() {
+    let (spender, amount): (Key, U256) = get_named_args_2(
+        vec!["spender", "amount"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    let owner = get_immediate_caller_key();
+    let allowance = read_allowance(&owner, &spender).saturating_sub(amount);
+    save_allowance(&owner, &spender, allowance);
+    casper_event_standard::emit(vault_events::SetAllowance::new(owner, spender, allowance));
+}
+
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let (recipient, amount): (Key, U256) = get_named_args_2(
+        vec!["recipient", "amount"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    let sender = get_immediate_caller_key();
+    transfer_balance(sender, recipient, amount);
+    casper_event_standard::emit(vault_events::Transfer::new(sender, recipient, amount));
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let (owner, recipient, amount): (Key, Key, U256) = get_named_args_3(
+        vec!["owner", "recipient", "amount"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    let spender = get_immediate_caller_key();
+    if owner != spender {
+        let allowance = read_allowance(&owner, &spender);
+        require(allowance >= amount, Error::ErrInsufficientAllowance);
+        save_allowance(&owner, &spender, allowance - amount);
+    }
+    transfer_balance(owner, recipient, amount);
+    casper_event_standard::emit(vault_events::TransferFrom::new(
+        spender, owner, recipient, amount,
+    ));
+}
@@ -0,0 +1,156 @@
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use casper_types::{
+    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter, U256,
+};
+
+use common::owner;
+
+use crate::store::{
+    balance_of_ep, decimals_ep, get_liquidity_ep, get_manager_ep, get_max_total_supply_ep,
+    get_max_twap_deviation_ep, get_pool_ep, get_tick_lower_ep, get_tick_spacing_ep,
+    get_tick_upper_ep, get_token0_ep, get_token1_ep, get_twap_duration_ep, name_ep, symbol_ep,
+    total_supply_ep,
+};
+
+fn add_entry_points(entry_points: &mut EntryPoints, list: &Vec<EntryPoint>) {
+    for e in list {
+        entry_points.add_entry_point(e.clone());
+    }
+}
+
+fn public_entry_point(name: &str, params: Vec<Parameter>, ret: CLType) -> EntryPoint {
+    EntryPoint::new(
+        String::from(name),
+        params,
+        ret,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub(crate) fn default() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    add_entry_points(&mut entry_points, &owner::entry_points());
+
+    // share token
+    entry_points.add_entry_point(name_ep());
+    entry_points.add_entry_point(symbol_ep());
+    entry_points.add_entry_point(decimals_ep());
+    entry_points.add_entry_point(total_supply_ep());
+    entry_points.add_entry_point(balance_of_ep());
+    entry_points.add_entry_point(public_entry_point(
+        "allowance",
+        vec![
+            Parameter::new("owner", CLType::Key),
+            Parameter::new("spender", CLType::Key),
+        ],
+        CLType::U256,
+    ));
+    for name in ["approve", "increase_allowance", "decrease_allowance"] {
+        entry_points.add_entry_point(public_entry_point(
+            name,
+            vec![
+                Parameter::new("spender", CLType::Key),
+                Parameter::new("amount", CLType::U256),
+            ],
+            CLType::Unit,
+        ));
+    }
+    entry_points.add_entry_point(public_entry_point(
+        "transfer",
+        vec![
+            Parameter::new("recipient", CLType::Key),
+            Parameter::new("amount", CLType::U256),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "transfer_from",
+        vec![
+            Parameter::new("owner", CLType::Key),
+            Parameter::new("recipient", CLType::Key),
+            Parameter::new("amount", CLType::U256),
+        ],
+        CLType::Unit,
+    ));
+
+    // vault
+    entry_points.add_entry_point(get_pool_ep());
+    entry_points.add_entry_point(get_token0_ep());
+    entry_points.add_entry_point(get_token1_ep());
+    entry_points.add_entry_point(get_tick_spacing_ep());
+    entry_points.add_entry_point(get_tick_lower_ep());
+    entry_points.add_entry_point(get_tick_upper_ep());
+    entry_points.add_entry_point(get_liquidity_ep());
+    entry_points.add_entry_point(get_manager_ep());
+    entry_points.add_entry_point(get_max_twap_deviation_ep());
+    entry_points.add_entry_point(get_twap_duration_ep());
+    entry_points.add_entry_point(get_max_total_supply_ep());
+
+    entry_points.add_entry_point(public_entry_point("init_vault", vec![], CLType::Unit));
+    entry_points.add_entry_point(public_entry_point(
+        "deposit",
+        vec![
+            Parameter::new("amount0_desired", CLType::U256),
+            Parameter::new("amount1_desired", CLType::U256),
+            Parameter::new("amount0_min", CLType::U256),
+            Parameter::new("amount1_min", CLType::U256),
+            Parameter::new("to", CLType::Key),
+        ],
+        <(U256, U256, U256)>::cl_type(),
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "withdraw",
+        vec![
+            Parameter::new("shares", CLType::U256),
+            Parameter::new("amount0_min", CLType::U256),
+            Parameter::new("amount1_min", CLType::U256),
+            Parameter::new("to", CLType::Key),
+        ],
+        <(U256, U256)>::cl_type(),
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "rebalance",
+        vec![
+            Parameter::new("tick_lower", CLType::I32),
+            Parameter::new("tick_upper", CLType::I32),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "mint_callback",
+        vec![
+            Parameter::new("amount0_owed", CLType::U256),
+            Parameter::new("amount1_owed", CLType::U256),
+            Parameter::new("data", CLType::List(Box::new(CLType::U8))),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "get_total_amounts",
+        vec![],
+        <(U256, U256)>::cl_type(),
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "set_manager",
+        vec![Parameter::new("manager", CLType::Key)],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "set_max_twap_deviation",
+        vec![Parameter::new("max_twap_deviation", CLType::I32)],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "set_twap_duration",
+        vec![Parameter::new("twap_duration", CLType::U32)],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(public_entry_point(
+        "set_max_total_supply",
+        vec![Parameter::new("max_total_supply", CLType::U256)],
+        CLType::Unit,
+    ));
+
+    entry_points
+}
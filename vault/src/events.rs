@@ -0,0 +1,18 @@
+use casper_event_standard::Schemas;
+use common::vault_events::*;
+pub fn event_schemas() -> Schemas {
+    Schemas::new()
+        .with::<Deposit>()
+        .with::<Withdraw>()
+        .with::<CollectFees>()
+        .with::<Rebalance>()
+        .with::<Mint>()
+        .with::<Burn>()
+        .with::<SetAllowance>()
+        .with::<Transfer>()
+        .with::<TransferFrom>()
+}
+
+pub fn init_events() {
+    casper_event_standard::init(event_schemas());
+}
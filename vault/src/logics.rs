@@ -0,0 +1,380 @@
+use crate::{
+    cep18::{burn_shares, mint_shares},
+    store::{
+        read_liquidity, read_manager, read_max_total_supply, read_max_twap_deviation, read_pool,
+        read_tick_lower, read_tick_spacing, read_tick_upper, read_token0, read_token1,
+        read_total_supply, read_twap_duration, save_liquidity, save_manager, save_max_total_supply,
+        save_max_twap_deviation, save_tick_lower, save_tick_upper, save_twap_duration,
+    },
+};
+use alloc::{string::ToString, vec, vec::Vec};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{bytesrepr::Bytes, CLValue, Key, U128, U256};
+use common::{
+    erc20_helpers,
+    error::{require, Error},
+    intf, owner,
+    utils::position_key,
+    vault_events,
+};
+use contract_utilities::helpers::{
+    get_immediate_caller_key, get_named_args_3, get_named_args_4, get_named_args_5, get_self_key,
+    null_key,
+};
+use math::{
+    fullmath,
+    liquidity_amounts::{get_amounts_for_liquidity, get_liquidity_for_amounts},
+    oracle_library,
+    tickmath::{self, MAX_TICK, MIN_TICK},
+};
+
+// shares the first deposit locks for good, so that an almost empty vault cannot have its share
+// price inflated by a donation
+pub const MINIMUM_SHARES: u64 = 1000;
+
+fn only_manager() {
+    require(
+        get_immediate_caller_key() == read_manager(),
+        Error::ErrOnlyManager,
+    );
+}
+
+pub fn check_range(tick_lower: i32, tick_upper: i32, tick_spacing: i32) {
+    require(tick_lower < tick_upper, Error::ErrInvalidVaultParams);
+    require(tick_lower >= MIN_TICK, Error::ErrInvalidVaultParams);
+    require(tick_upper <= MAX_TICK, Error::ErrInvalidVaultParams);
+    require(
+        tick_lower % tick_spacing == 0 && tick_upper % tick_spacing == 0,
+        Error::ErrInvalidVaultParams,
+    );
+}
+
+// reverts when the spot tick moved too far from the time weighted average tick, which would
+// make the vault add liquidity at a manipulated price
+pub fn check_twap(pool: Key, tick: i32) {
+    let twap_duration = read_twap_duration();
+    let (tick_cumulatives, _) = intf::observe(pool, vec![twap_duration, 0]);
+    let twap_tick = oracle_library::get_arithmetic_mean_tick(&tick_cumulatives, twap_duration);
+    require(
+        (tick - twap_tick).abs() <= read_max_twap_deviation(),
+        Error::ErrPriceDeviation,
+    );
+}
+
+fn balance0() -> U256 {
+    erc20_helpers::get_balance(read_token0(), get_self_key())
+}
+
+fn balance1() -> U256 {
+    erc20_helpers::get_balance(read_token1(), get_self_key())
+}
+
+// accrues the fees earned by the position into its tokens owed
+fn poke(pool: Key) {
+    if !read_liquidity().is_zero() {
+        intf::burn(pool, read_tick_lower(), read_tick_upper(), U128::zero());
+    }
+}
+
+// idle balances plus the position's principal and uncollected fees
+pub fn get_total_amounts_internal() -> (U256, U256) {
+    let pool = read_pool();
+    let (tick_lower, tick_upper) = (read_tick_lower(), read_tick_upper());
+    let info = intf::get_pool_position(pool, position_key(get_self_key(), tick_lower, tick_upper));
+    let slot0 = intf::get_slot0(pool);
+    let (amount0, amount1) = get_amounts_for_liquidity(
+        &slot0.sqrt_price_x96,
+        &tickmath::get_sqrt_ratio_at_tick(tick_lower),
+        &tickmath::get_sqrt_ratio_at_tick(tick_upper),
+        info.liquidity.as_u128(),
+    );
+    (
+        balance0() + amount0 + U256::from(info.tokens_owed0.as_u128()),
+        balance1() + amount1 + U256::from(info.tokens_owed1.as_u128()),
+    )
+}
+
+// puts as much of the idle balances as possible into the current range
+fn add_idle_liquidity(pool: Key, sqrt_price_x96: U256) -> u128 {
+    let (tick_lower, tick_upper) = (read_tick_lower(), read_tick_upper());
+    let liquidity = get_liquidity_for_amounts(
+        &sqrt_price_x96,
+        &tickmath::get_sqrt_ratio_at_tick(tick_lower),
+        &tickmath::get_sqrt_ratio_at_tick(tick_upper),
+        &balance0(),
+        &balance1(),
+    );
+    if liquidity > 0 {
+        intf::mint(
+            pool,
+            get_self_key(),
+            tick_lower,
+            tick_upper,
+            U128::from(liquidity),
+            Vec::new(),
+        );
+        save_liquidity(U128::from(read_liquidity().as_u128() + liquidity));
+    }
+    liquidity
+}
+
+// burns the liquidity backing the shares and collects all the fees to the vault, returns the
+// principal plus the shares' part of the fees
+fn burn_liquidity_share(pool: Key, shares: U256, total_supply: U256) -> (U256, U256) {
+    let liquidity = read_liquidity().as_u128();
+    if liquidity == 0 {
+        return (U256::zero(), U256::zero());
+    }
+    let (tick_lower, tick_upper) = (read_tick_lower(), read_tick_upper());
+    let to_burn = fullmath::mul_div(&U256::from(liquidity), &shares, &total_supply).as_u128();
+    let (burned0, burned1) = intf::burn(pool, tick_lower, tick_upper, U128::from(to_burn));
+    save_liquidity(U128::from(liquidity - to_burn));
+
+    let (collected0, collected1) = intf::collect(
+        pool,
+        get_self_key(),
+        tick_lower,
+        tick_upper,
+        U128::MAX,
+        U128::MAX,
+    );
+    let fees0 = U256::from(collected0.as_u128()) - burned0;
+    let fees1 = U256::from(collected1.as_u128()) - burned1;
+    if !fees0.is_zero() || !fees1.is_zero() {
+        casper_event_standard::emit(vault_events::CollectFees::new(fees0, fees1));
+    }
+    (
+        burned0 + fullmath::mul_div(&fees0, &shares, &total_supply),
+        burned1 + fullmath::mul_div(&fees1, &shares, &total_supply),
+    )
+}
+
+// amounts to pull and shares to mint so the deposit keeps the vault's token ratio
+fn calc_shares_and_amounts(
+    amount0_desired: U256,
+    amount1_desired: U256,
+    total0: U256,
+    total1: U256,
+    total_supply: U256,
+) -> (U256, U256, U256) {
+    if total_supply.is_zero() {
+        let shares = amount0_desired.max(amount1_desired);
+        (shares, amount0_desired, amount1_desired)
+    } else if total0.is_zero() && total1.is_zero() {
+        runtime::revert(Error::ErrEmptyVault)
+    } else if total0.is_zero() {
+        let shares = fullmath::mul_div(&amount1_desired, &total_supply, &total1);
+        (shares, U256::zero(), amount1_desired)
+    } else if total1.is_zero() {
+        let shares = fullmath::mul_div(&amount0_desired, &total_supply, &total0);
+        (shares, amount0_desired, U256::zero())
+    } else {
+        let cross = (amount0_desired * total1).min(amount1_desired * total0);
+        require(cross > U256::zero(), Error::ErrInvalidVaultParams);
+        // round up the amounts and down the shares
+        let amount0 = (cross - 1) / total1 + 1;
+        let amount1 = (cross - 1) / total0 + 1;
+        let shares = fullmath::mul_div(&cross, &total_supply, &total0) / total1;
+        (shares, amount0, amount1)
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn deposit() {
+    let (amount0_desired, amount1_desired, amount0_min, amount1_min, to): (
+        U256,
+        U256,
+        U256,
+        U256,
+        Key,
+    ) = get_named_args_5(
+        vec![
+            "amount0_desired",
+            "amount1_desired",
+            "amount0_min",
+            "amount1_min",
+            "to",
+        ]
+        .into_iter()
+        .map(|x| x.to_string())
+        .collect(),
+    );
+    require(
+        !amount0_desired.is_zero() || !amount1_desired.is_zero(),
+        Error::ErrInvalidVaultParams,
+    );
+
+    let pool = read_pool();
+    poke(pool);
+    let (total0, total1) = get_total_amounts_internal();
+    let total_supply = read_total_supply();
+    let (shares, amount0, amount1) = calc_shares_and_amounts(
+        amount0_desired,
+        amount1_desired,
+        total0,
+        total1,
+        total_supply,
+    );
+    require(shares > U256::zero(), Error::ErrInvalidVaultParams);
+    require(
+        !total_supply.is_zero() || shares > U256::from(MINIMUM_SHARES),
+        Error::ErrInvalidVaultParams,
+    );
+    require(
+        amount0 >= amount0_min && amount1 >= amount1_min,
+        Error::ErrPriceSlippageCheck,
+    );
+    let max_total_supply = read_max_total_supply();
+    require(
+        max_total_supply.is_zero() || total_supply + shares <= max_total_supply,
+        Error::ErrMaxTotalSupply,
+    );
+
+    let sender = get_immediate_caller_key();
+    if !amount0.is_zero() {
+        erc20_helpers::transfer_from(read_token0(), sender, get_self_key(), amount0);
+    }
+    if !amount1.is_zero() {
+        erc20_helpers::transfer_from(read_token1(), sender, get_self_key(), amount1);
+    }
+    add_idle_liquidity(pool, intf::get_slot0(pool).sqrt_price_x96);
+
+    let shares = if total_supply.is_zero() {
+        mint_shares(null_key(), U256::from(MINIMUM_SHARES));
+        shares - U256::from(MINIMUM_SHARES)
+    } else {
+        shares
+    };
+    mint_shares(to, shares);
+    casper_event_standard::emit(vault_events::Deposit::new(
+        sender, to, shares, amount0, amount1,
+    ));
+    runtime::ret(CLValue::from_t((shares, amount0, amount1)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw() {
+    let (shares, amount0_min, amount1_min, to): (U256, U256, U256, Key) = get_named_args_4(
+        vec!["shares", "amount0_min", "amount1_min", "to"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    require(shares > U256::zero(), Error::ErrInvalidVaultParams);
+    let sender = get_immediate_caller_key();
+    let total_supply = read_total_supply();
+    // reverts unless the sender holds the shares, so the supply below is not zero
+    burn_shares(sender, shares);
+
+    // share of the idle balances, computed before anything is collected from the pool
+    let unused0 = fullmath::mul_div(&balance0(), &shares, &total_supply);
+    let unused1 = fullmath::mul_div(&balance1(), &shares, &total_supply);
+
+    let (position0, position1) = burn_liquidity_share(read_pool(), shares, total_supply);
+    let amount0 = unused0 + position0;
+    let amount1 = unused1 + position1;
+    require(
+        amount0 >= amount0_min && amount1 >= amount1_min,
+        Error::ErrPriceSlippageCheck,
+    );
+
+    if !amount0.is_zero() {
+        erc20_helpers::transfer(read_token0(), to, amount0);
+    }
+    if !amount1.is_zero() {
+        erc20_helpers::transfer(read_token1(), to, amount1);
+    }
+    casper_event_standard::emit(vault_events::Withdraw::new(
+        sender, to, shares, amount0, amount1,
+    ));
+    runtime::ret(CLValue::from_t((amount0, amount1)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn rebalance() {
+    only_manager();
+    let tick_lower: i32 = runtime::get_named_arg("tick_lower");
+    let tick_upper: i32 = runtime::get_named_arg("tick_upper");
+    check_range(tick_lower, tick_upper, read_tick_spacing());
+
+    let pool = read_pool();
+    let slot0 = intf::get_slot0(pool);
+    check_twap(pool, slot0.tick);
+
+    // withdraw the whole position, fees included
+    let total_supply = read_total_supply();
+    if !total_supply.is_zero() {
+        burn_liquidity_share(pool, total_supply, total_supply);
+    }
+
+    save_tick_lower(tick_lower);
+    save_tick_upper(tick_upper);
+    add_idle_liquidity(pool, slot0.sqrt_price_x96);
+
+    let (total0, total1) = get_total_amounts_internal();
+    casper_event_standard::emit(vault_events::Rebalance::new(
+        slot0.tick,
+        tick_lower,
+        tick_upper,
+        read_liquidity(),
+        total0,
+        total1,
+        total_supply,
+    ));
+}
+
+#[no_mangle]
+pub extern "C" fn mint_callback() {
+    let (amount0_owed, amount1_owed, _): (U256, U256, Bytes) = get_named_args_3(
+        vec!["amount0_owed", "amount1_owed", "data"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+    );
+    let pool = read_pool();
+    require(
+        get_immediate_caller_key() == pool,
+        Error::ErrInvalidMintCallback,
+    );
+    if amount0_owed > U256::zero() {
+        erc20_helpers::transfer(read_token0(), pool, amount0_owed);
+    }
+    if amount1_owed > U256::zero() {
+        erc20_helpers::transfer(read_token1(), pool, amount1_owed);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn get_total_amounts() {
+    runtime::ret(CLValue::from_t(get_total_amounts_internal()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_manager() {
+    owner::only_owner();
+    let manager: Key = runtime::get_named_arg("manager");
+    save_manager(manager);
+}
+
+#[no_mangle]
+pub extern "C" fn set_max_twap_deviation() {
+    owner::only_owner();
+    let max_twap_deviation: i32 = runtime::get_named_arg("max_twap_deviation");
+    require(max_twap_deviation >= 0, Error::ErrInvalidVaultParams);
+    save_max_twap_deviation(max_twap_deviation);
+}
+
+#[no_mangle]
+pub extern "C" fn set_twap_duration() {
+    owner::only_owner();
+    let twap_duration: u32 = runtime::get_named_arg("twap_duration");
+    require(twap_duration > 0, Error::ErrInvalidVaultParams);
+    save_twap_duration(twap_duration);
+}
+
+#[no_mangle]
+pub extern "C" fn set_max_total_supply() {
+    only_manager();
+    let max_total_supply: U256 = runtime::get_named_arg("max_total_supply");
+    save_max_total_supply(max_total_supply);
+}
@@ -0,0 +1,110 @@
+#![no_main]
+#![no_std]
+
+extern crate alloc;
+mod entry_points;
+
+pub mod cep18;
+pub mod events;
+pub mod logics;
+pub mod store;
+
+use alloc::{format, string::String};
+use casper_contract::contract_api::runtime;
+use casper_types::{contracts::NamedKeys, runtime_args, Key, RuntimeArgs, U256};
+use common::{
+    error::{require, Error},
+    intf, owner, upgrade,
+};
+use contract_utilities::helpers;
+use events::event_schemas;
+
+#[no_mangle]
+pub extern "C" fn init_vault() {
+    if runtime::has_key("contract_hash") {
+        runtime::revert(Error::ContractAlreadyInitialized);
+    }
+
+    let caller = helpers::get_immediate_caller_key();
+    let contract_hash: Key = runtime::get_named_arg("contract_hash");
+    let contract_package_hash: Key = runtime::get_named_arg("contract_package_hash");
+    let name: String = runtime::get_named_arg("name");
+    let symbol: String = runtime::get_named_arg("symbol");
+    let pool: Key = runtime::get_named_arg("pool");
+    let tick_lower: i32 = runtime::get_named_arg("tick_lower");
+    let tick_upper: i32 = runtime::get_named_arg("tick_upper");
+    let manager: Key = runtime::get_named_arg("manager");
+    let max_twap_deviation: i32 = runtime::get_named_arg("max_twap_deviation");
+    let twap_duration: u32 = runtime::get_named_arg("twap_duration");
+    let max_total_supply: U256 = runtime::get_named_arg("max_total_supply");
+
+    let tick_spacing = intf::get_tick_spacing(pool);
+    logics::check_range(tick_lower, tick_upper, tick_spacing);
+    require(
+        max_twap_deviation >= 0 && twap_duration > 0,
+        Error::ErrInvalidVaultParams,
+    );
+
+    helpers::set_key("contract_hash", contract_hash);
+    helpers::set_key("contract_package_hash", contract_package_hash);
+    owner::init(caller);
+    events::init_events();
+    store::initialize();
+    store::save_name(name);
+    store::save_symbol(symbol);
+    store::save_decimals(18);
+    store::save_pool(pool);
+    store::save_token0(runtime::call_versioned_contract(
+        pool.into_hash().unwrap().into(),
+        None,
+        "get_token0",
+        runtime_args! {},
+    ));
+    store::save_token1(runtime::call_versioned_contract(
+        pool.into_hash().unwrap().into(),
+        None,
+        "get_token1",
+        runtime_args! {},
+    ));
+    store::save_tick_spacing(tick_spacing);
+    store::save_tick_lower(tick_lower);
+    store::save_tick_upper(tick_upper);
+    store::save_manager(manager);
+    store::save_max_twap_deviation(max_twap_deviation);
+    store::save_twap_duration(twap_duration);
+    store::save_max_total_supply(max_total_supply);
+}
+
+#[no_mangle]
+fn call() {
+    let contract_name: String = runtime::get_named_arg("contract_name");
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        let (contract_hash, contract_package_hash) =
+            upgrade::install_contract(contract_name, entry_points::default(), NamedKeys::new());
+
+        runtime::call_contract::<()>(
+            contract_hash,
+            "init_vault",
+            runtime_args! {
+                "contract_hash" => Key::from(contract_hash),
+                "contract_package_hash" => Key::from(contract_package_hash),
+                "name" => runtime::get_named_arg::<String>("name"),
+                "symbol" => runtime::get_named_arg::<String>("symbol"),
+                "pool" => runtime::get_named_arg::<Key>("pool"),
+                "tick_lower" => runtime::get_named_arg::<i32>("tick_lower"),
+                "tick_upper" => runtime::get_named_arg::<i32>("tick_upper"),
+                "manager" => runtime::get_named_arg::<Key>("manager"),
+                "max_twap_deviation" => runtime::get_named_arg::<i32>("max_twap_deviation"),
+                "twap_duration" => runtime::get_named_arg::<u32>("twap_duration"),
+                "max_total_supply" => runtime::get_named_arg::<U256>("max_total_supply"),
+            },
+        );
+    } else {
+        upgrade::upgrade(
+            contract_name,
+            entry_points::default(),
+            NamedKeys::new(),
+            event_schemas(),
+        );
+    }
+}
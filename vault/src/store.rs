@@ -0,0 +1,226 @@
+use alloc::{string::String, vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    CLTyped, CLValue, EntryPoint, EntryPointAccess, EntryPointType, Key, Parameter, U128, U256,
+};
+use common::{error::Error, get_set_dict, get_set_no_set};
+use contract_utilities::helpers::{self, null_key};
+
+pub fn initialize() {
+    storage::new_dictionary("balances").unwrap_or_revert_with(Error::FailedToCreateDictionary);
+    storage::new_dictionary("allowances").unwrap_or_revert_with(Error::FailedToCreateDictionary);
+    save_total_supply(U256::zero());
+    save_liquidity(U128::zero());
+}
+
+get_set_no_set!(
+    name,
+    "name",
+    String,
+    String::new(),
+    save_name,
+    read_name,
+    name,
+    name_ep,
+    "name"
+);
+
+get_set_no_set!(
+    symbol,
+    "symbol",
+    String,
+    String::new(),
+    save_symbol,
+    read_symbol,
+    symbol,
+    symbol_ep,
+    "symbol"
+);
+
+get_set_no_set!(
+    decimals,
+    "decimals",
+    u8,
+    18,
+    save_decimals,
+    read_decimals,
+    decimals,
+    decimals_ep,
+    "decimals"
+);
+
+get_set_no_set!(
+    total_supply,
+    "total_supply",
+    U256,
+    U256::zero(),
+    save_total_supply,
+    read_total_supply,
+    total_supply,
+    total_supply_ep,
+    "total_supply"
+);
+
+get_set_dict!(
+    "balances",
+    "address",
+    Key,
+    U256,
+    U256::zero(),
+    save_balance,
+    read_balance,
+    balance_of,
+    balance_of_ep,
+    "balance_of"
+);
+
+pub fn allowance_key(owner: &Key, spender: &Key) -> String {
+    helpers::encode_key(&helpers::encode_2(owner, spender))
+}
+
+pub fn save_allowance(owner: &Key, spender: &Key, amount: U256) {
+    helpers::write_dictionary_value_from_key("allowances", &allowance_key(owner, spender), amount);
+}
+
+pub fn read_allowance(owner: &Key, spender: &Key) -> U256 {
+    helpers::get_dictionary_value_from_key("allowances", &allowance_key(owner, spender))
+        .unwrap_or_default()
+}
+
+get_set_no_set!(
+    pool,
+    "pool",
+    Key,
+    null_key(),
+    save_pool,
+    read_pool,
+    get_pool,
+    get_pool_ep,
+    "get_pool"
+);
+
+get_set_no_set!(
+    token0,
+    "token0",
+    Key,
+    null_key(),
+    save_token0,
+    read_token0,
+    get_token0,
+    get_token0_ep,
+    "get_token0"
+);
+
+get_set_no_set!(
+    token1,
+    "token1",
+    Key,
+    null_key(),
+    save_token1,
+    read_token1,
+    get_token1,
+    get_token1_ep,
+    "get_token1"
+);
+
+get_set_no_set!(
+    tick_spacing,
+    "tick_spacing",
+    i32,
+    0,
+    save_tick_spacing,
+    read_tick_spacing,
+    get_tick_spacing,
+    get_tick_spacing_ep,
+    "get_tick_spacing"
+);
+
+get_set_no_set!(
+    tick_lower,
+    "tick_lower",
+    i32,
+    0,
+    save_tick_lower,
+    read_tick_lower,
+    get_tick_lower,
+    get_tick_lower_ep,
+    "get_tick_lower"
+);
+
+get_set_no_set!(
+    tick_upper,
+    "tick_upper",
+    i32,
+    0,
+    save_tick_upper,
+    read_tick_upper,
+    get_tick_upper,
+    get_tick_upper_ep,
+    "get_tick_upper"
+);
+
+// liquidity of the position the vault owns between tick_lower and tick_upper
+get_set_no_set!(
+    liquidity,
+    "liquidity",
+    U128,
+    U128::zero(),
+    save_liquidity,
+    read_liquidity,
+    get_liquidity,
+    get_liquidity_ep,
+    "get_liquidity"
+);
+
+get_set_no_set!(
+    manager,
+    "manager",
+    Key,
+    null_key(),
+    save_manager,
+    read_manager,
+    get_manager,
+    get_manager_ep,
+    "get_manager"
+);
+
+// maximum distance in ticks between the spot and the twap tick when rebalancing
+get_set_no_set!(
+    max_twap_deviation,
+    "max_twap_deviation",
+    i32,
+    0,
+    save_max_twap_deviation,
+    read_max_twap_deviation,
+    get_max_twap_deviation,
+    get_max_twap_deviation_ep,
+    "get_max_twap_deviation"
+);
+
+get_set_no_set!(
+    twap_duration,
+    "twap_duration",
+    u32,
+    0,
+    save_twap_duration,
+    read_twap_duration,
+    get_twap_duration,
+    get_twap_duration_ep,
+    "get_twap_duration"
+);
+
+// zero means deposits are not capped
+get_set_no_set!(
+    max_total_supply,
+    "max_total_supply",
+    U256,
+    U256::zero(),
+    save_max_total_supply,
+    read_max_total_supply,
+    get_max_total_supply,
+    get_max_total_supply_ep,
+    "get_max_total_supply"
+);